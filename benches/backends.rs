@@ -0,0 +1,32 @@
+//! Compares the tree-walking interpreter with the bytecode VM. Run with `cargo bench`.
+
+extern crate criterion;
+extern crate type_inference;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use type_inference::bytecode::compile;
+use type_inference::{eval, parser, vm};
+
+const PROGRAMS: &[(&str, &str)] = &[
+    ("fib", "letrec fib = fun n -> if n < 2 then n else fib (n - 1) + fib (n - 2) in fib 20"),
+    ("map", "letrec map = fun f -> fun l -> if is_nil l then nil else pair (f (first l)) (map f (rest l)) in \
+             letrec range = fun n -> if n = 0 then nil else pair n (range (n - 1)) in \
+             letrec sum = fun acc -> fun l -> match l with | pair x xs -> sum (acc + x) xs | _ -> acc in \
+             sum 0 (map (fun x -> x * 2) (range 10000))"),
+];
+
+fn backends(c: &mut Criterion) {
+    for &(name, src) in PROGRAMS {
+        let expr = parser::parse_Expr(src).unwrap();
+        let program = compile(&expr);
+        let mut group = c.benchmark_group(name);
+        group.sample_size(20);
+        group.bench_function("eval", |b| b.iter(|| eval::eval(&expr).unwrap().to_string()));
+        group.bench_function("vm", |b| b.iter(|| vm::run(&program).unwrap().to_string()));
+        group.bench_function("compile and vm", |b| b.iter(|| vm::run(&compile(&expr)).unwrap().to_string()));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, backends);
+criterion_main!(benches);
@@ -0,0 +1,68 @@
+//! Times type inference on generated programs of growing size. Run with `cargo bench`.
+
+extern crate criterion;
+extern crate type_inference;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use type_inference::infer::{check, top_level_env, VarGenerator};
+use type_inference::parser;
+
+/// `1 + 1 + ...` with `n` terms
+fn operator_chain(n: usize) -> String {
+    vec!["1"; n].join(" + ")
+}
+
+/// `n` functions, each calling the previous one twice, and applied to values of several types
+fn function_chain(n: usize) -> String {
+    let mut src = "let f0 = fun x -> x in ".to_owned();
+    for i in 1..n {
+        src += &format!("let f{} = fun x -> f{} (f{} x) in ", i, i - 1, i - 1);
+    }
+    src + &format!("(f{0} 1, f{0} true, f{0} \"s\")", n - 1)
+}
+
+/// `n` nested `let`s, each binding a new name, so the enviroment grows with every one
+fn nested_lets(n: usize) -> String {
+    let mut src = "let x0 = 0 in ".to_owned();
+    for i in 1..n {
+        src += &format!("let x{} = x{} + 1 in ", i, i - 1);
+    }
+    src + &format!("x{}", n - 1)
+}
+
+/// A tuple of `n` list operations, all sharing one polymorphic function
+fn wide_tuple(n: usize) -> String {
+    let elems: Vec<String> = (0..n).map(|i| format!("map (fun x -> x + {}) (pair {} nil)", i, i)).collect();
+    format!("letrec map = fun f -> fun l -> if is_nil l then nil else pair (f (first l)) (map f (rest l)) in ({})",
+            elems.join(", "))
+}
+
+/// A kind of program: its name, how to generate one of a given size, and the sizes to time
+type Family = (&'static str, fn(usize) -> String, &'static [usize]);
+
+fn inference(c: &mut Criterion) {
+    let programs: [Family; 4] = [
+        ("operator chain", operator_chain, &[1000, 4000, 16000]),
+        ("function chain", function_chain, &[50, 100, 200]),
+        ("nested lets", nested_lets, &[1000, 4000, 16000]),
+        ("wide tuple", wide_tuple, &[100, 200, 400]),
+    ];
+    // Only inference is timed, so the predefined names are set up once. Every run numbers its
+    // type variables from where the enviroment left off
+    let mut var_gen = VarGenerator::new();
+    let env = top_level_env(&mut var_gen);
+    for &(name, generate, sizes) in &programs {
+        let mut group = c.benchmark_group(name);
+        group.sample_size(10);
+        for &n in sizes {
+            let expr = parser::parse_Expr(&generate(n)).unwrap();
+            group.bench_function(BenchmarkId::from_parameter(n), |b| b.iter(|| {
+                check(&expr, &env, &mut var_gen.clone()).unwrap()
+            }));
+        }
+        group.finish();
+    }
+}
+
+criterion_group!(benches, inference);
+criterion_main!(benches);
@@ -1,51 +1,263 @@
 use std::fmt::Display;
 use std::fmt;
 use std::collections::HashSet;
-use std::iter::FromIterator;
+use std::mem;
+use stack;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// A type. Types can nest as deeply as the programs they are inferred for, so the traits on
+/// them are written out to guard each level against overflowing the stack
 pub enum PrimitiveType {
     Var(u32),
     Fun(Box<PrimitiveType>, Box<PrimitiveType>),
     Con(String, Vec<PrimitiveType>),
+    /// A record type: its known fields sorted by name, and the row variable standing for
+    /// any further fields, or `None` if the record has exactly these fields
+    Row(Vec<(String, PrimitiveType)>, Option<Box<PrimitiveType>>),
+    /// A type alias applied to its arguments, along with the type it expands to. Unification
+    /// looks through it, but it is printed by name
+    Alias(String, Vec<PrimitiveType>, Box<PrimitiveType>),
 }
 impl PrimitiveType {
+    /// The type with any aliases at the top expanded
+    pub fn unalias(&self) -> &PrimitiveType {
+        let mut t = self;
+        while let PrimitiveType::Alias(_, _, ref u) = *t {
+            t = u;
+        }
+        t
+    }
     pub fn type_vars(&self) -> HashSet<u32> {
+        let mut vars = HashSet::new();
+        let mut pending = vec![self];
+        while let Some(t) = pending.pop() {
+            match *t {
+                PrimitiveType::Var(n) => {
+                    vars.insert(n);
+                },
+                _ => pending.extend(t.children()),
+            }
+        }
+        vars
+    }
+    /// The types directly inside this one
+    fn children(&self) -> Vec<&PrimitiveType> {
         match *self {
-            PrimitiveType::Var(n) => {
-                let mut h = HashSet::new();
-                h.insert(n);
-                h
-            },
-            PrimitiveType::Fun(ref a, ref b) => {
-                HashSet::from_iter(a.type_vars().union(&b.type_vars()).cloned())
-            },
-            PrimitiveType::Con(_, ref types) => {
-                let mut vars = HashSet::new();
-                for t in types {
-                    vars = vars.union(&t.type_vars()).cloned().collect();
+            PrimitiveType::Var(_) => vec![],
+            PrimitiveType::Fun(ref a, ref r) => vec![a, r],
+            PrimitiveType::Con(_, ref args) => args.iter().collect(),
+            PrimitiveType::Row(ref fields, ref rest) => fields.iter().map(|f| &f.1).chain(rest.as_ref().map(|r| &**r)).collect(),
+            PrimitiveType::Alias(_, ref args, ref t) => args.iter().chain(Some(&**t)).collect(),
+        }
+    }
+    /// Moves every type directly inside this one that has types inside it too into `out`,
+    /// leaving a variable in its place
+    fn take_children(&mut self, out: &mut Vec<PrimitiveType>) {
+        let mut take = |t: &mut PrimitiveType| match *t {
+            PrimitiveType::Var(_) => {},
+            PrimitiveType::Con(_, ref args) if args.is_empty() => {},
+            _ => out.push(mem::replace(t, PrimitiveType::Var(0))),
+        };
+        match *self {
+            PrimitiveType::Var(_) => {},
+            PrimitiveType::Fun(ref mut a, ref mut r) => {
+                take(a);
+                take(r);
+            },
+            PrimitiveType::Con(_, ref mut args) => args.iter_mut().for_each(take),
+            PrimitiveType::Row(ref mut fields, ref mut rest) => {
+                fields.iter_mut().for_each(|f| take(&mut f.1));
+                if let Some(ref mut r) = *rest {
+                    take(r);
                 }
-                vars
-            }
+            },
+            PrimitiveType::Alias(_, ref mut args, ref mut t) => {
+                args.iter_mut().for_each(&mut take);
+                take(t);
+            },
+        }
+    }
+}
+impl Clone for PrimitiveType {
+    fn clone(&self) -> PrimitiveType {
+        stack::guard(|| match *self {
+            PrimitiveType::Var(n) => PrimitiveType::Var(n),
+            PrimitiveType::Fun(ref a, ref r) => PrimitiveType::Fun(a.clone(), r.clone()),
+            PrimitiveType::Con(ref name, ref args) => PrimitiveType::Con(name.clone(), args.clone()),
+            PrimitiveType::Row(ref fields, ref rest) => PrimitiveType::Row(fields.clone(), rest.clone()),
+            PrimitiveType::Alias(ref name, ref args, ref t) => PrimitiveType::Alias(name.clone(), args.clone(), t.clone()),
+        })
+    }
+}
+impl PartialEq for PrimitiveType {
+    fn eq(&self, other: &PrimitiveType) -> bool {
+        stack::guard(|| match (self, other) {
+            (&PrimitiveType::Var(a), &PrimitiveType::Var(b)) => a == b,
+            (PrimitiveType::Fun(a1, r1), PrimitiveType::Fun(a2, r2)) => a1 == a2 && r1 == r2,
+            (PrimitiveType::Con(n1, args1), PrimitiveType::Con(n2, args2)) => n1 == n2 && args1 == args2,
+            (PrimitiveType::Row(f1, r1), PrimitiveType::Row(f2, r2)) => f1 == f2 && r1 == r2,
+            (PrimitiveType::Alias(n1, args1, t1), PrimitiveType::Alias(n2, args2, t2)) => n1 == n2 && args1 == args2 && t1 == t2,
+            _ => false,
+        })
+    }
+}
+impl Eq for PrimitiveType {}
+impl fmt::Debug for PrimitiveType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        stack::guard(|| match *self {
+            PrimitiveType::Var(ref n) => f.debug_tuple("Var").field(n).finish(),
+            PrimitiveType::Fun(ref a, ref r) => f.debug_tuple("Fun").field(a).field(r).finish(),
+            PrimitiveType::Con(ref name, ref args) => f.debug_tuple("Con").field(name).field(args).finish(),
+            PrimitiveType::Row(ref fields, ref rest) => f.debug_tuple("Row").field(fields).field(rest).finish(),
+            PrimitiveType::Alias(ref name, ref args, ref t) => f.debug_tuple("Alias").field(name).field(args).field(t).finish(),
+        })
+    }
+}
+impl Drop for PrimitiveType {
+    fn drop(&mut self) {
+        // Dropping the inner types directly would recurse once per level of nesting
+        let mut pending = vec![];
+        self.take_children(&mut pending);
+        while let Some(mut t) = pending.pop() {
+            t.take_children(&mut pending);
         }
     }
 }
 impl Display for PrimitiveType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", match *self {
-            PrimitiveType::Var(ref s) => format!("'{}", s),
-            PrimitiveType::Fun(ref a, ref r) => format!("({} -> {})", a, r),
-            PrimitiveType::Con(ref name, ref params) => {
-               match params.len() {
-                   0 => format!("{}", name),
-                   1 => format!("{}<{}>", name, params[0]),
-                   _ => format!("{}<{} {}>", name, params[1], params.iter().fold("".to_owned(), |acc, p| format!("{}, {}", acc, p)))
-               }
+        stack::guard(|| match *self {
+            PrimitiveType::Var(ref s) => write!(f, "'{}", s),
+            PrimitiveType::Fun(ref a, ref r) => write!(f, "({} -> {})", a, r),
+            PrimitiveType::Con(ref name, ref params) if name == "*" => {
+                write!(f, "(")?;
+                write_list(f, params, " * ")?;
+                write!(f, ")")
+            },
+            PrimitiveType::Con(ref name, ref params) | PrimitiveType::Alias(ref name, ref params, _) => {
+                write!(f, "{}", declared_name(name))?;
+                if !params.is_empty() {
+                    write!(f, "<")?;
+                    write_list(f, params, ", ")?;
+                    write!(f, ">")?;
+                }
+                Ok(())
+            },
+            PrimitiveType::Row(ref fields, ref rest) => {
+                write!(f, "{{")?;
+                for (i, (n, t)) in fields.iter().enumerate() {
+                    write!(f, "{} {} : {}", if i == 0 { "" } else { "," }, n, t)?;
+                }
+                match *rest {
+                    Some(ref r) => write!(f, " | {} }}", r),
+                    None if fields.is_empty() => write!(f, "}}"),
+                    None => write!(f, " }}"),
+                }
             }
         })
     }
 }
 
+/// Writes `types` separated by `sep`, without building a string for each
+fn write_list(f: &mut fmt::Formatter, types: &[PrimitiveType], sep: &str) -> fmt::Result {
+    for (i, t) in types.iter().enumerate() {
+        if i > 0 {
+            write!(f, "{}", sep)?;
+        }
+        write!(f, "{}", t)?;
+    }
+    Ok(())
+}
+
+/// The name a type constructor was declared with, without the stamp that tells apart
+/// declarations of the same name
+pub fn declared_name(con: &str) -> &str {
+    con.split('/').next().unwrap()
+}
+
+/// A type as written in the source, before its names are resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeExpr {
+    Var(String),
+    Con(String, Vec<TypeExpr>),
+    Fun(Box<TypeExpr>, Box<TypeExpr>),
+    Tuple(Vec<TypeExpr>),
+}
+impl Display for TypeExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TypeExpr::Var(ref v) => write!(f, "{}", v),
+            TypeExpr::Con(ref name, ref params) if params.is_empty() => write!(f, "{}", name),
+            TypeExpr::Con(ref name, ref params) => {
+                let params: Vec<String> = params.iter().map(|p| p.to_string()).collect();
+                write!(f, "{}<{}>", name, params.join(", "))
+            },
+            TypeExpr::Fun(ref a, ref r) => write!(f, "({} -> {})", a, r),
+            TypeExpr::Tuple(ref ts) => {
+                let ts: Vec<String> = ts.iter().map(|t| t.to_string()).collect();
+                write!(f, "({})", ts.join(" * "))
+            },
+        }
+    }
+}
+
+/// A `type name 'a = List<List<'a>>` alias declaration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeAlias {
+    pub name: String,
+    pub params: Vec<String>,
+    pub typ: TypeExpr,
+}
+impl Display for TypeAlias {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "type {}", self.name)?;
+        for p in &self.params {
+            write!(f, " {}", p)?;
+        }
+        write!(f, " = {}", self.typ)
+    }
+}
+
+/// A `type name 'a = A | B of 'a` declaration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeDecl {
+    pub name: String,
+    pub params: Vec<String>,
+    pub ctors: Vec<(String, Vec<TypeExpr>)>,
+}
+impl Display for TypeDecl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "type {}", self.name)?;
+        for p in &self.params {
+            write!(f, " {}", p)?;
+        }
+        let ctors: Vec<String> = self.ctors.iter().map(|(c, args)| {
+            if args.is_empty() {
+                c.clone()
+            } else {
+                let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+                format!("{} of {}", c, args.join(" * "))
+            }
+        }).collect();
+        write!(f, " = {}", ctors.join(" | "))
+    }
+}
+
+/// An `exception Failure of string` declaration, adding a constructor to `exn`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExceptionDecl {
+    pub name: String,
+    pub args: Vec<TypeExpr>,
+}
+impl Display for ExceptionDecl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "exception {}", self.name)?;
+        if !self.args.is_empty() {
+            let args: Vec<String> = self.args.iter().map(|a| a.to_string()).collect();
+            write!(f, " of {}", args.join(" * "))?;
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Copy)]
 pub enum Op {
     Add,
@@ -57,6 +269,12 @@ pub enum Op {
     Lt,
     And,
     Or,
+    /// String concatenation, `^`
+    Concat,
+    Le,
+    Ge,
+    NotEqual,
+    Mod,
 }
 impl Display for Op {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -70,34 +288,316 @@ impl Display for Op {
             Op::Sub => "-",
             Op::Div => "/",
             Op::Equal => "=",
+            Op::Concat => "^",
+            Op::Le => "<=",
+            Op::Ge => ">=",
+            Op::NotEqual => "!=",
+            Op::Mod => "mod",
         })
     }
 }
 
+/// How a chain of operators with the same precedence groups
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum Assoc {
+    Left,
+    Right,
+    /// Chaining is an error, as in `a == b == c`
+    NonAssoc,
+}
+
+/// An `infixl 6 ++` declaration, giving an operator a precedence from 0 to 9 and an
+/// associativity
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixityDecl {
+    pub assoc: Assoc,
+    pub prec: i32,
+    pub op: String,
+}
+impl Display for FixityDecl {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let keyword = match self.assoc {
+            Assoc::Left => "infixl",
+            Assoc::Right => "infixr",
+            Assoc::NonAssoc => "infix",
+        };
+        write!(f, "{} {} {}", keyword, self.prec, self.op)
+    }
+}
+
+/// Whether `name` is an operator like `++` rather than an identifier
+pub fn is_operator(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| !c.is_alphanumeric() && c != '_')
+}
+
+/// Writes a bound name the way it would be written in source, parenthesizing operators
+fn binder(name: &str) -> String {
+    if is_operator(name) { format!("({})", name) } else { name.to_owned() }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Copy)]
+pub enum UnOp {
+    /// Boolean negation, `not`
+    Not,
+    /// Arithmetic negation, `-`
+    Neg,
+}
+impl Display for UnOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", match *self {
+            UnOp::Not => "not ",
+            UnOp::Neg => "-",
+        })
+    }
+}
+
+/// Resolves the backslash escapes in the body of a string or char literal
+pub fn unescape(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('r') => out.push('\r'),
+            Some('0') => out.push('\0'),
+            Some(c) => out.push(c),
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// A byte range `(start, end)` into the source text
+pub type Span = (usize, usize);
+
+/// A node paired with the span of source text it was parsed from. Expressions and patterns
+/// nest through it, so its traits guard each level against overflowing the stack
+pub struct Spanned<T> {
+    pub node: T,
+    pub span: Span,
+}
+impl<T> Spanned<T> {
+    pub fn new(node: T, span: Span) -> Spanned<T> {
+        Spanned { node, span }
+    }
+}
+impl<T: Clone> Clone for Spanned<T> {
+    fn clone(&self) -> Spanned<T> {
+        stack::guard(|| Spanned::new(self.node.clone(), self.span))
+    }
+}
+impl<T: PartialEq> PartialEq for Spanned<T> {
+    fn eq(&self, other: &Spanned<T>) -> bool {
+        stack::guard(|| self.span == other.span && self.node == other.node)
+    }
+}
+impl<T: Eq> Eq for Spanned<T> {}
+impl<T: fmt::Debug> fmt::Debug for Spanned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        stack::guard(|| f.debug_struct("Spanned").field("node", &self.node).field("span", &self.span).finish())
+    }
+}
+impl<T: Display> Display for Spanned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        stack::guard(|| self.node.fmt(f))
+    }
+}
+
+/// A pattern in a `match` arm. A bare name is a variable unless a constructor with that name
+/// is in scope, in which case it matches that constructor
 #[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Pattern {
+    Wildcard,
+    Var(String),
+    Num(i32),
+    Bool(bool),
+    Tuple(Vec<Spanned<Pattern>>),
+    Con(String, Vec<Spanned<Pattern>>),
+}
+impl Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Pattern::Wildcard => write!(f, "_"),
+            Pattern::Var(ref x) => write!(f, "{}", x),
+            Pattern::Num(n) => write!(f, "{}", n),
+            Pattern::Bool(b) => write!(f, "{}", b),
+            Pattern::Tuple(ref ps) => {
+                let ps: Vec<String> = ps.iter().map(|p| p.to_string()).collect();
+                write!(f, "({})", ps.join(", "))
+            },
+            Pattern::Con(ref c, ref args) => {
+                write!(f, "{}", c)?;
+                for arg in args {
+                    match arg.node {
+                        Pattern::Con(_, ref a) if !a.is_empty() => write!(f, " ({})", arg)?,
+                        _ => write!(f, " {}", arg)?,
+                    }
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Expr {
     Num(i32),
+    Float(f64),
+    Str(String),
+    Char(char),
     Bool(bool),
+    /// The unit value, `()`
+    Unit,
     Var(String),
-    BinOp(Box<Expr>, Op, Box<Expr>),
-    Fun(String, Box<Expr>),
-    App(Box<Expr>,  Box<Expr>),
-    Let(String, Box<Expr>, Box<Expr>),
-    LetRec(String, Box<Expr>, Box<Expr>),
-    If(Box<Expr>, Box<Expr>, Box<Expr>),
+    BinOp(Box<Spanned<Expr>>, Op, Box<Spanned<Expr>>),
+    UnOp(UnOp, Box<Spanned<Expr>>),
+    Tuple(Vec<Spanned<Expr>>),
+    /// A lambda, optionally annotating its parameter: `fun (x : int) -> ...`
+    Fun(String, Option<Spanned<TypeExpr>>, Box<Spanned<Expr>>),
+    App(Box<Spanned<Expr>>,  Box<Spanned<Expr>>),
+    Let(String, Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    /// A let that destructures a tuple: `let (x, y) = ... in ...`
+    LetTuple(Vec<String>, Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    LetRec(String, Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    /// A group of mutually recursive bindings: `letrec f = ... and g = ... in ...`
+    LetRecGroup(Vec<(String, Spanned<Expr>)>, Box<Spanned<Expr>>),
+    If(Box<Spanned<Expr>>, Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    /// A type declaration scoped over an expression: `type ... in ...`
+    TypeDef(Spanned<TypeDecl>, Box<Spanned<Expr>>),
+    /// A type alias scoped over an expression: `type ... = List<int> in ...`
+    TypeAlias(Spanned<TypeAlias>, Box<Spanned<Expr>>),
+    Match(Box<Spanned<Expr>>, Vec<(Spanned<Pattern>, Spanned<Expr>)>),
+    /// A record literal: `{ name = 1, ok = true }`
+    Record(Vec<(String, Spanned<Expr>)>),
+    /// Field projection: `r.name`
+    Field(Box<Spanned<Expr>>, String),
+    /// Evaluates the first expression for its effects, then the second: `e1; e2`
+    Seq(Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    /// Reads a reference: `!r`
+    Deref(Box<Spanned<Expr>>),
+    /// Writes a reference: `r := v`
+    Assign(Box<Spanned<Expr>>, Box<Spanned<Expr>>),
+    /// Declares an exception for use in its body: `exception Empty in e`
+    Exception(Spanned<ExceptionDecl>, Box<Spanned<Expr>>),
+    /// Raises an exception: `raise Empty`
+    Raise(Box<Spanned<Expr>>),
+    /// Evaluates an expression, handling the exceptions it raises: `try e with | Empty -> 0`
+    Try(Box<Spanned<Expr>>, Vec<(Spanned<Pattern>, Spanned<Expr>)>),
+    /// Declares the fixity of an operator for use in its body: `infixl 6 ++ in e`
+    Fixity(Spanned<FixityDecl>, Box<Spanned<Expr>>),
+    /// Operands separated by operators whose grouping has not been resolved yet. The parser
+    /// resolves these into `BinOp`s and applications before returning
+    Infix(Vec<Spanned<Expr>>, Vec<Spanned<String>>),
+    /// A type annotation: `(e : int -> int)`
+    Annot(Box<Spanned<Expr>>, Spanned<TypeExpr>),
 }
 impl Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", match *self {
             Expr::Num(ref n) => format!("{}", n),
+            Expr::Float(ref n) => format!("{:?}", n),
+            Expr::Str(ref s) => format!("{:?}", s),
+            Expr::Char(ref c) => format!("{:?}", c),
             Expr::Bool(ref b) => format!("{}", b),
-            Expr::Var(ref v) => format!("{}", v),
+            Expr::Unit => "()".to_owned(),
+            Expr::Var(ref v) => binder(v),
             Expr::BinOp(ref l, ref op, ref r) => format!("({} {} {})", l, op, r),
-            Expr::Fun(ref id, ref body) => format!("(fun {} -> {})", id, body),
+            Expr::UnOp(ref op, ref e) => format!("({}{})", op, e),
+            Expr::Tuple(ref elems) => {
+                let elems: Vec<String> = elems.iter().map(|e| e.to_string()).collect();
+                format!("({})", elems.join(", "))
+            },
+            Expr::Fun(ref id, None, ref body) => format!("(fun {} -> {})", id, body),
+            Expr::Fun(ref id, Some(ref typ), ref body) => format!("(fun ({} : {}) -> {})", id, typ, body),
             Expr::App(ref func, ref op) => format!("({} {})", func, op),
-            Expr::Let(ref id, ref val, ref body) => format!("(let {} = {} in {})", id, val, body),
-            Expr::LetRec(ref id, ref val, ref body) => format!("(letrec {} = {} in {})", id, val, body),
+            Expr::Let(ref id, ref val, ref body) => format!("(let {} = {} in {})", binder(id), val, body),
+            Expr::LetTuple(ref ids, ref val, ref body) => format!("(let ({}) = {} in {})", ids.join(", "), val, body),
+            Expr::LetRec(ref id, ref val, ref body) => format!("(letrec {} = {} in {})", binder(id), val, body),
+            Expr::LetRecGroup(ref bindings, ref body) => {
+                let bindings: Vec<String> = bindings.iter().map(|(id, val)| format!("{} = {}", binder(id), val)).collect();
+                format!("(letrec {} in {})", bindings.join(" and "), body)
+            },
             Expr::If(ref pred, ref then, ref otherwise) => format!("(if {} then {} else {})", pred, then, otherwise),
+            Expr::TypeDef(ref decl, ref body) => format!("({} in {})", decl, body),
+            Expr::TypeAlias(ref alias, ref body) => format!("({} in {})", alias, body),
+            Expr::Match(ref e, ref arms) => {
+                let arms: Vec<String> = arms.iter().map(|(p, body)| format!(" | {} -> {}", p, body)).collect();
+                format!("(match {} with{})", e, arms.concat())
+            },
+            Expr::Record(ref fields) => {
+                let fields: Vec<String> = fields.iter().map(|(n, e)| format!("{} = {}", n, e)).collect();
+                format!("{{{}}}", fields.join(", "))
+            },
+            Expr::Field(ref e, ref name) => format!("{}.{}", e, name),
+            Expr::Seq(ref e1, ref e2) => format!("({}; {})", e1, e2),
+            Expr::Deref(ref e) => format!("!{}", e),
+            Expr::Assign(ref r, ref v) => format!("({} := {})", r, v),
+            Expr::Exception(ref decl, ref body) => format!("({} in {})", decl, body),
+            Expr::Raise(ref e) => format!("(raise {})", e),
+            Expr::Try(ref e, ref arms) => {
+                let arms: Vec<String> = arms.iter().map(|(p, body)| format!(" | {} -> {}", p, body)).collect();
+                format!("(try {} with{})", e, arms.concat())
+            },
+            Expr::Fixity(ref decl, ref body) => format!("({} in {})", decl, body),
+            Expr::Infix(ref operands, ref ops) => {
+                let mut out = format!("({}", operands[0]);
+                for (op, e) in ops.iter().zip(&operands[1..]) {
+                    out.push_str(&format!(" {} {}", op, e));
+                }
+                out + ")"
+            },
+            Expr::Annot(ref e, ref typ) => format!("({} : {})", e, typ),
     })
     }
-}
\ No newline at end of file
+}
+impl Expr {
+    /// Every direct subexpression, in the order they appear in the source
+    pub fn children_mut(&mut self) -> Vec<&mut Spanned<Expr>> {
+        match *self {
+            Expr::BinOp(ref mut e1, _, ref mut e2) | Expr::App(ref mut e1, ref mut e2) |
+            Expr::Let(_, ref mut e1, ref mut e2) | Expr::LetTuple(_, ref mut e1, ref mut e2) |
+            Expr::LetRec(_, ref mut e1, ref mut e2) | Expr::Seq(ref mut e1, ref mut e2) |
+            Expr::Assign(ref mut e1, ref mut e2) => vec![e1, e2],
+            Expr::UnOp(_, ref mut e) | Expr::Fun(_, _, ref mut e) | Expr::TypeDef(_, ref mut e) |
+            Expr::TypeAlias(_, ref mut e) | Expr::Exception(_, ref mut e) | Expr::Field(ref mut e, _) |
+            Expr::Deref(ref mut e) | Expr::Raise(ref mut e) | Expr::Fixity(_, ref mut e) |
+            Expr::Annot(ref mut e, _) => vec![e],
+            Expr::If(ref mut p, ref mut t, ref mut e) => vec![p, t, e],
+            Expr::Tuple(ref mut elems) | Expr::Infix(ref mut elems, _) => elems.iter_mut().collect(),
+            Expr::LetRecGroup(ref mut bindings, ref mut body) => {
+                let mut children: Vec<&mut Spanned<Expr>> = bindings.iter_mut().map(|b| &mut b.1).collect();
+                children.push(body);
+                children
+            },
+            Expr::Match(ref mut e, ref mut arms) | Expr::Try(ref mut e, ref mut arms) => {
+                let mut children: Vec<&mut Spanned<Expr>> = vec![e];
+                children.extend(arms.iter_mut().map(|arm| &mut arm.1));
+                children
+            },
+            Expr::Record(ref mut fields) => fields.iter_mut().map(|f| &mut f.1).collect(),
+            Expr::Num(_) | Expr::Float(_) | Expr::Str(_) | Expr::Char(_) | Expr::Bool(_) | Expr::Unit | Expr::Var(_) => vec![],
+        }
+    }
+
+    /// Moves every direct subexpression into `out`, leaving `()` in its place
+    fn take_children(&mut self, out: &mut Vec<Expr>) {
+        for e in self.children_mut() {
+            out.push(mem::replace(&mut e.node, Expr::Unit));
+        }
+    }
+}
+impl Drop for Expr {
+    fn drop(&mut self) {
+        // Dropping the subexpressions directly would recurse once per level of nesting
+        let mut pending = vec![];
+        self.take_children(&mut pending);
+        while let Some(mut e) = pending.pop() {
+            e.take_children(&mut pending);
+        }
+    }
+}
@@ -0,0 +1,526 @@
+//! Compiles expressions to bytecode for `vm`. Each function becomes a `Proto` whose
+//! instructions work on a stack: a call's argument and the variables its body binds sit in
+//! numbered slots at the bottom of the call's part of the stack, and the variables it uses
+//! from enclosing functions are copied into its closure when the closure is made.
+
+use std::rc::Rc;
+use ast::*;
+use eval;
+use eval::Value;
+use stack;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instr {
+    /// Pushes a constant from the program's table
+    Const(usize),
+    /// Pushes the value in a slot of the current call
+    Local(usize),
+    /// Pushes a variable captured by the current closure
+    Captured(usize),
+    /// Pushes a predefined value, numbered as in `eval::builtins`
+    Global(usize),
+    Pop,
+    /// Removes the `n` values under the top of the stack
+    Slide(usize),
+    /// Makes a closure for a proto, capturing the variables its `captures` list
+    Closure(usize),
+    /// Applies the function under the top of the stack to the top of the stack
+    Apply,
+    /// Applies a function in place of the current call, returning its result
+    TailApply,
+    Return,
+    Jump(usize),
+    /// Pops a bool and jumps if it is false
+    JumpIfFalse(usize),
+    BinOp(Op),
+    UnOp(UnOp),
+    MakeTuple(usize),
+    /// Replaces a tuple by its elements
+    Unpack(usize),
+    /// Builds a record from values pushed in the order of one of the program's field lists
+    MakeRecord(usize),
+    /// Replaces a record by the field with one of the program's names
+    Field(usize),
+    /// Pushes an empty cell, which a `letrec` fills in once its value is built. Only functions
+    /// are bound by `letrec`, so nothing reads the cell before then
+    MakeCell,
+    /// Pops a value into the cell in a slot
+    SetCell(usize),
+    /// Replaces a reference or cell by its contents
+    Deref,
+    /// Pops a value and a reference, stores the value and pushes `()`
+    Assign,
+    /// Tests the top of the stack against one of the program's patterns. On success, pushes
+    /// the values the pattern binds; otherwise jumps
+    Match(usize, usize),
+    /// Fails because no match arm matched
+    MatchFailure,
+    /// Installs a handler for the exceptions raised until the next `PopHandler`
+    PushHandler(usize),
+    PopHandler,
+    /// Raises the value on top of the stack
+    Raise,
+    /// Raises the exception on top of the stack again after no `try` arm matched it
+    Reraise,
+}
+
+/// A pattern with each bare name resolved to a variable or a constructor
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pat {
+    Wild,
+    Bind,
+    Int(i32),
+    Bool(bool),
+    Tuple(Vec<Pat>),
+    /// A constructor, with the tag that tells it apart from others of the same name
+    Con(String, usize, Vec<Pat>),
+}
+
+/// Where a closure finds a variable it captures when it is made
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Capture {
+    Local(usize),
+    Captured(usize),
+}
+
+/// The code of one function, or of the whole program
+#[derive(Debug, Clone, Default)]
+pub struct Proto {
+    pub code: Vec<Instr>,
+    /// The span of the expression each instruction was compiled from
+    pub spans: Vec<Span>,
+    pub captures: Vec<Capture>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Program {
+    /// The code of the program itself comes first
+    pub protos: Vec<Rc<Proto>>,
+    pub constants: Vec<Value<'static>>,
+    pub names: Vec<String>,
+    pub field_lists: Vec<Vec<String>>,
+    pub patterns: Vec<Pat>,
+}
+
+/// How a name is reached from the function being compiled
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Place {
+    Local(usize),
+    Captured(usize),
+    Global(usize),
+}
+
+#[derive(Debug, Clone)]
+struct Var {
+    name: String,
+    place: Place,
+    /// Bound by `letrec`, so the variable holds a cell
+    cell: bool,
+    /// The tag of the constructor the variable is bound to, if it is one. A bare name in a
+    /// pattern matches the constructor rather than binding the name
+    ctor: Option<usize>,
+}
+
+/// A function being compiled
+#[derive(Default)]
+struct Scope {
+    proto: Proto,
+    vars: Vec<Var>,
+    /// The number of values the function has on the stack at this point
+    depth: usize,
+}
+
+struct Compiler {
+    scopes: Vec<Scope>,
+    globals: Vec<(&'static str, Value<'static>)>,
+    program: Program,
+}
+
+/// Compiles a type checked expression
+pub fn compile(expr: &Spanned<Expr>) -> Program {
+    let mut compiler = Compiler {
+        scopes: vec![Scope::default()],
+        globals: eval::builtins(),
+        program: Program {
+            protos: vec![Rc::new(Proto::default())],
+            constants: vec![],
+            names: vec![],
+            field_lists: vec![],
+            patterns: vec![],
+        },
+    };
+    compiler.expr(expr, true);
+    compiler.program.protos[0] = Rc::new(compiler.scopes.pop().unwrap().proto);
+    compiler.program
+}
+
+impl Compiler {
+    fn scope(&mut self) -> &mut Scope {
+        self.scopes.last_mut().unwrap()
+    }
+
+    /// Appends an instruction, returning its position
+    fn emit(&mut self, instr: Instr, span: Span) -> usize {
+        let (pops, pushes) = match instr {
+            Instr::Const(_) | Instr::Local(_) | Instr::Captured(_) | Instr::Global(_) |
+            Instr::Closure(_) | Instr::MakeCell => (0, 1),
+            Instr::Pop | Instr::JumpIfFalse(_) | Instr::SetCell(_) => (1, 0),
+            Instr::Apply | Instr::TailApply | Instr::BinOp(_) | Instr::Assign => (2, 1),
+            Instr::Slide(n) => (n, 0),
+            Instr::MakeTuple(n) => (n, 1),
+            Instr::Unpack(n) => (1, n),
+            Instr::MakeRecord(k) => (self.program.field_lists[k].len(), 1),
+            _ => (0, 0),
+        };
+        let scope = self.scope();
+        scope.depth = scope.depth - pops + pushes;
+        scope.proto.code.push(instr);
+        scope.proto.spans.push(span);
+        scope.proto.code.len() - 1
+    }
+
+    /// Points the jump at `at` to the next instruction
+    fn patch(&mut self, at: usize) {
+        let code = &mut self.scope().proto.code;
+        let target = code.len();
+        match code[at] {
+            Instr::Jump(ref mut t) | Instr::JumpIfFalse(ref mut t) | Instr::PushHandler(ref mut t) |
+            Instr::Match(_, ref mut t) => *t = target,
+            ref instr => panic!("cannot patch {:?}", instr),
+        }
+    }
+
+    fn push_constant(&mut self, v: Value<'static>, span: Span) {
+        self.program.constants.push(v);
+        let k = self.program.constants.len() - 1;
+        self.emit(Instr::Const(k), span);
+    }
+
+    fn name(&mut self, name: &str) -> usize {
+        match self.program.names.iter().position(|n| n == name) {
+            Some(i) => i,
+            None => {
+                self.program.names.push(name.to_owned());
+                self.program.names.len() - 1
+            },
+        }
+    }
+
+    /// Binds `name` to the `n`th value from the top of the stack
+    fn bind(&mut self, name: &str, n: usize, cell: bool, ctor: Option<usize>) {
+        let scope = self.scope();
+        let place = Place::Local(scope.depth - n);
+        scope.vars.push(Var { name: name.to_owned(), place, cell, ctor });
+    }
+
+    fn unbind(&mut self, n: usize) {
+        let scope = self.scope();
+        let len = scope.vars.len();
+        scope.vars.truncate(len - n);
+    }
+
+    /// The innermost variable named `name` in any enclosing function
+    fn lookup(&self, name: &str) -> Option<&Var> {
+        self.scopes.iter().rev().filter_map(|s| s.vars.iter().rev().find(|v| v.name == name)).next()
+    }
+
+    /// The tag of the constructor a bare name in a pattern matches, if it names one
+    fn bare_ctor(&self, name: &str) -> Option<usize> {
+        match self.lookup(name) {
+            Some(var) => var.ctor,
+            None => self.global_ctor(name),
+        }
+    }
+
+    /// The tag of the innermost constructor named `name`
+    fn ctor(&self, name: &str) -> usize {
+        let local = self.scopes.iter().rev().filter_map(|s| {
+            s.vars.iter().rev().filter(|v| v.name == name).find_map(|v| v.ctor)
+        }).next();
+        local.or_else(|| self.global_ctor(name)).unwrap_or_else(|| panic!("unbound constructor {}", name))
+    }
+
+    fn global_ctor(&self, name: &str) -> Option<usize> {
+        self.globals.iter().rev().find_map(|g| eval::constructor_tag(&g.1, name))
+    }
+
+    /// Finds `name` from the function at `level`, capturing it from the enclosing functions
+    /// if it is theirs
+    fn resolve(&mut self, name: &str, level: usize) -> Var {
+        if let Some(var) = self.scopes[level].vars.iter().rev().find(|v| v.name == name) {
+            return var.clone();
+        }
+        if level == 0 {
+            let i = self.globals.iter().position(|g| g.0 == name).unwrap_or_else(|| panic!("unbound variable {}", name));
+            let ctor = eval::constructor_tag(&self.globals[i].1, name);
+            return Var { name: name.to_owned(), place: Place::Global(i), cell: false, ctor };
+        }
+        let outer = self.resolve(name, level - 1);
+        let capture = match outer.place {
+            Place::Global(_) => return outer,
+            Place::Local(slot) => Capture::Local(slot),
+            Place::Captured(i) => Capture::Captured(i),
+        };
+        let scope = &mut self.scopes[level];
+        scope.proto.captures.push(capture);
+        let var = Var { place: Place::Captured(scope.proto.captures.len() - 1), ..outer };
+        // Captures go under the function's own variables, which shadow them
+        scope.vars.insert(0, var.clone());
+        var
+    }
+
+    fn load(&mut self, name: &str, span: Span) {
+        let level = self.scopes.len() - 1;
+        let var = self.resolve(name, level);
+        self.emit(match var.place {
+            Place::Local(slot) => Instr::Local(slot),
+            Place::Captured(i) => Instr::Captured(i),
+            Place::Global(i) => Instr::Global(i),
+        }, span);
+        if var.cell {
+            self.emit(Instr::Deref, span);
+        }
+    }
+
+    /// Compiles `e` to code that pushes its value. In tail position the value is returned
+    /// from the current function instead, so that calls there can reuse its stack
+    fn expr(&mut self, e: &Spanned<Expr>, tail: bool) {
+        stack::guard(|| self.expr_node(e, tail))
+    }
+
+    fn expr_node(&mut self, e: &Spanned<Expr>, tail: bool) {
+        let span = e.span;
+        match e.node {
+            Expr::Num(n) => self.push_constant(Value::Int(n), span),
+            Expr::Float(n) => self.push_constant(Value::Float(n), span),
+            Expr::Str(ref s) => self.push_constant(Value::Str(s.clone()), span),
+            Expr::Char(c) => self.push_constant(Value::Char(c), span),
+            Expr::Bool(b) => self.push_constant(Value::Bool(b), span),
+            Expr::Unit => self.push_constant(Value::Unit, span),
+            Expr::Var(ref x) => self.load(x, span),
+            Expr::BinOp(ref l, op @ Op::And, ref r) | Expr::BinOp(ref l, op @ Op::Or, ref r) => {
+                self.expr(l, false);
+                if op == Op::Or {
+                    self.emit(Instr::UnOp(UnOp::Not), span);
+                }
+                let short = self.emit(Instr::JumpIfFalse(0), span);
+                self.expr(r, false);
+                let end = self.emit(Instr::Jump(0), span);
+                self.scope().depth -= 1;
+                self.patch(short);
+                self.push_constant(Value::Bool(op == Op::Or), span);
+                self.patch(end);
+            },
+            Expr::BinOp(ref l, op, ref r) => {
+                self.expr(l, false);
+                self.expr(r, false);
+                self.emit(Instr::BinOp(op), span);
+            },
+            Expr::UnOp(op, ref e) => {
+                self.expr(e, false);
+                self.emit(Instr::UnOp(op), span);
+            },
+            Expr::Tuple(ref elems) => {
+                for e in elems {
+                    self.expr(e, false);
+                }
+                self.emit(Instr::MakeTuple(elems.len()), span);
+            },
+            Expr::Fun(ref x, _, ref body) => {
+                let param = Var { name: x.clone(), place: Place::Local(0), cell: false, ctor: None };
+                self.scopes.push(Scope { proto: Proto::default(), vars: vec![param], depth: 1 });
+                self.expr(body, true);
+                let proto = self.scopes.pop().unwrap().proto;
+                self.program.protos.push(Rc::new(proto));
+                let index = self.program.protos.len() - 1;
+                self.emit(Instr::Closure(index), span);
+            },
+            Expr::App(ref f, ref arg) => {
+                self.expr(f, false);
+                self.expr(arg, false);
+                self.emit(if tail { Instr::TailApply } else { Instr::Apply }, span);
+                return;
+            },
+            Expr::Let(ref x, ref e1, ref e2) => {
+                self.expr(e1, false);
+                self.bind(x, 1, false, None);
+                return self.body(e2, 1, tail);
+            },
+            Expr::LetTuple(ref xs, ref e1, ref e2) => {
+                self.expr(e1, false);
+                self.emit(Instr::Unpack(xs.len()), span);
+                for (i, x) in xs.iter().enumerate() {
+                    self.bind(x, xs.len() - i, false, None);
+                }
+                return self.body(e2, xs.len(), tail);
+            },
+            Expr::LetRec(ref x, ref e1, ref e2) => {
+                self.letrec(&[(x, e1)], span);
+                return self.body(e2, 1, tail);
+            },
+            Expr::LetRecGroup(ref bindings, ref e2) => {
+                let bindings: Vec<(&String, &Spanned<Expr>)> = bindings.iter().map(|(x, e)| (x, e)).collect();
+                self.letrec(&bindings, span);
+                return self.body(e2, bindings.len(), tail);
+            },
+            Expr::If(ref pred, ref then, ref otherwise) => {
+                self.expr(pred, false);
+                let skip = self.emit(Instr::JumpIfFalse(0), span);
+                self.expr(then, tail);
+                let end = if tail { None } else { Some(self.emit(Instr::Jump(0), span)) };
+                self.scope().depth -= 1;
+                self.patch(skip);
+                self.expr(otherwise, tail);
+                if let Some(end) = end {
+                    self.patch(end);
+                }
+                return;
+            },
+            Expr::TypeDef(ref decl, ref e) => {
+                for (tag, (c, args)) in decl.node.ctors.iter().enumerate() {
+                    self.push_constant(eval::constructor(c, tag, args.len()), span);
+                    self.bind(c, 1, false, Some(tag));
+                }
+                return self.body(e, decl.node.ctors.len(), tail);
+            },
+            Expr::Exception(ref decl, ref e) => {
+                let ExceptionDecl { ref name, ref args } = decl.node;
+                // As in `eval`, an exception's tag is where it is declared
+                self.push_constant(eval::constructor(name, decl.span.0, args.len()), span);
+                self.bind(name, 1, false, Some(decl.span.0));
+                return self.body(e, 1, tail);
+            },
+            Expr::Match(ref e, ref arms) => {
+                self.expr(e, false);
+                let ends = self.arms(arms, tail);
+                self.emit(Instr::MatchFailure, span);
+                for end in ends {
+                    self.patch(end);
+                }
+                return;
+            },
+            Expr::Try(ref e, ref arms) => {
+                let handler = self.emit(Instr::PushHandler(0), span);
+                self.expr(e, false);
+                self.emit(Instr::PopHandler, span);
+                let end = self.emit(Instr::Jump(0), span);
+                // The handler finds the exception in place of the value of the body
+                self.patch(handler);
+                let ends = self.arms(arms, false);
+                self.emit(Instr::Reraise, span);
+                self.patch(end);
+                for end in ends {
+                    self.patch(end);
+                }
+            },
+            Expr::Raise(ref e) => {
+                self.expr(e, false);
+                self.emit(Instr::Raise, span);
+            },
+            Expr::Record(ref fields) => {
+                for (_, e) in fields {
+                    self.expr(e, false);
+                }
+                self.program.field_lists.push(fields.iter().map(|f| f.0.clone()).collect());
+                let k = self.program.field_lists.len() - 1;
+                self.emit(Instr::MakeRecord(k), span);
+            },
+            Expr::Field(ref e, ref name) => {
+                self.expr(e, false);
+                let k = self.name(name);
+                self.emit(Instr::Field(k), span);
+            },
+            Expr::Seq(ref e1, ref e2) => {
+                self.expr(e1, false);
+                self.emit(Instr::Pop, span);
+                return self.expr(e2, tail);
+            },
+            Expr::Deref(ref e) => {
+                self.expr(e, false);
+                self.emit(Instr::Deref, span);
+            },
+            Expr::Assign(ref r, ref v) => {
+                self.expr(r, false);
+                self.expr(v, false);
+                self.emit(Instr::Assign, span);
+            },
+            // The parser turned the aliases that declare a constructor into type declarations
+            Expr::TypeAlias(_, ref e) | Expr::Fixity(_, ref e) | Expr::Annot(ref e, _) => return self.expr(e, tail),
+            Expr::Infix(..) => unreachable!("operator chains are resolved by the parser"),
+        }
+        if tail {
+            self.emit(Instr::Return, span);
+        }
+    }
+
+    /// Compiles the body of a binding of `n` variables, then drops them from under its value
+    fn body(&mut self, e: &Spanned<Expr>, n: usize, tail: bool) {
+        self.expr(e, tail);
+        self.unbind(n);
+        if tail {
+            // The body returned, which dropped everything
+            self.scope().depth -= n;
+        } else {
+            self.emit(Instr::Slide(n), e.span);
+        }
+    }
+
+    /// Binds each name to a cell, then fills the cells in, so that every binding can refer
+    /// to all of them
+    fn letrec(&mut self, bindings: &[(&String, &Spanned<Expr>)], span: Span) {
+        let base = self.scope().depth;
+        for &(x, _) in bindings {
+            self.emit(Instr::MakeCell, span);
+            self.bind(x, 1, true, None);
+        }
+        for (i, &(_, e)) in bindings.iter().enumerate() {
+            self.expr(e, false);
+            self.emit(Instr::SetCell(base + i), e.span);
+        }
+    }
+
+    /// Compiles match arms testing the value on top of the stack. The arm that matches
+    /// replaces the value by its result, then returns or jumps to one of the positions this
+    /// returns, and the code falls through if none does
+    fn arms(&mut self, arms: &[(Spanned<Pattern>, Spanned<Expr>)], tail: bool) -> Vec<usize> {
+        let mut ends = vec![];
+        let depth = self.scope().depth;
+        for (pat, body) in arms {
+            let mut names = vec![];
+            let pat = self.pattern(&pat.node, &mut names);
+            self.program.patterns.push(pat);
+            let next = self.emit(Instr::Match(self.program.patterns.len() - 1, 0), body.span);
+            self.scope().depth += names.len();
+            for (i, x) in names.iter().enumerate() {
+                self.bind(x, names.len() - i, false, None);
+            }
+            self.expr(body, tail);
+            self.unbind(names.len());
+            if !tail {
+                self.emit(Instr::Slide(names.len() + 1), body.span);
+                ends.push(self.emit(Instr::Jump(0), body.span));
+            }
+            self.scope().depth = depth;
+            self.patch(next);
+        }
+        ends
+    }
+
+    /// Resolves the names in a pattern, collecting the variables it binds in order
+    fn pattern(&self, pat: &Pattern, names: &mut Vec<String>) -> Pat {
+        match *pat {
+            Pattern::Wildcard => Pat::Wild,
+            Pattern::Var(ref x) => match self.bare_ctor(x) {
+                Some(tag) => Pat::Con(x.clone(), tag, vec![]),
+                None => {
+                    names.push(x.clone());
+                    Pat::Bind
+                },
+            },
+            Pattern::Num(n) => Pat::Int(n),
+            Pattern::Bool(b) => Pat::Bool(b),
+            Pattern::Tuple(ref ps) => Pat::Tuple(ps.iter().map(|p| self.pattern(&p.node, names)).collect()),
+            Pattern::Con(ref c, ref ps) => Pat::Con(c.clone(), self.ctor(c), ps.iter().map(|p| self.pattern(&p.node, names)).collect()),
+        }
+    }
+}
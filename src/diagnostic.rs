@@ -0,0 +1,248 @@
+use std::fmt::Write;
+use lalrpop_util::ParseError;
+use ast::Span;
+use infer;
+use eval;
+use fixity;
+
+/// A message attached to a span of source text
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub span: Span,
+    pub message: String,
+}
+impl Label {
+    pub fn new(span: Span, message: String) -> Label {
+        Label { span, message }
+    }
+}
+
+/// Whether a diagnostic stops the program from type checking
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A located error ready to be rendered against the source text it came from
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub primary: Label,
+    pub secondary: Vec<Label>,
+    pub notes: Vec<String>,
+}
+impl Diagnostic {
+    pub fn new(message: String, primary: Label) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            message,
+            primary,
+            secondary: vec![],
+            notes: vec![],
+        }
+    }
+
+    /// Builds a diagnostic from a parse failure on `source`
+    pub fn from_parse_error(source: &str, err: &ParseError<usize, (usize, &str), fixity::Error>) -> Diagnostic {
+        match *err {
+            ParseError::InvalidToken { location } => {
+                // The offending character may take up several bytes
+                let width = source.get(location..).and_then(|rest| rest.chars().next()).map_or(1, char::len_utf8);
+                Diagnostic::new("invalid token".to_owned(), Label::new((location, location + width), "not recognized".to_owned()))
+            },
+            ParseError::UnrecognizedToken { token: Some((l, (_, tok), r)), ref expected } => {
+                let mut diag = Diagnostic::new(format!("unexpected token `{}`", tok), Label::new((l, r), "unexpected here".to_owned()));
+                if !expected.is_empty() {
+                    diag.notes.push(expected_note(expected));
+                }
+                diag
+            },
+            ParseError::UnrecognizedToken { token: None, ref expected } => {
+                let end = source.trim_end().len();
+                let mut diag = Diagnostic::new("unexpected end of input".to_owned(), Label::new((end, end + 1), "input ends here".to_owned()));
+                if !expected.is_empty() {
+                    diag.notes.push(expected_note(expected));
+                }
+                diag
+            },
+            ParseError::ExtraToken { token: (l, (_, tok), r) } => {
+                Diagnostic::new(format!("extra token `{}`", tok), Label::new((l, r), "unexpected here".to_owned()))
+            },
+            ParseError::User { ref error } => {
+                let label = match *error {
+                    fixity::Error::Ambiguous { .. } => "add parentheses to group these operators",
+                    fixity::Error::BadPrecedence { .. } => "this must be between 0 and 9",
+                    fixity::Error::IntOutOfRange { .. } => "ints must be between -2147483648 and 2147483647",
+                };
+                Diagnostic::new(error.to_string(), Label::new(error.span(), label.to_owned()))
+            },
+        }
+    }
+
+    /// Builds a diagnostic from an inference failure
+    pub fn from_type_error(err: &infer::Error) -> Diagnostic {
+        use infer::Error::*;
+        let mut notes = vec![];
+        let (message, label) = match *err.primary() {
+            Mismatch { ref expected, ref found, ref path, .. } => {
+                if !path.is_empty() {
+                    let parts: Vec<String> = path.iter().rev().map(|part| format!("in {}", part)).collect();
+                    notes.push(format!("the mismatch is {}", parts.join(", ")));
+                }
+                ("type mismatch".to_owned(), format!("expected {}, found {}", expected, found))
+            },
+            OccursCheck { var, ref typ, .. } => {
+                (format!("cannot construct infinite type '{} = {}", var, typ), "the type of this expression would contain itself".to_owned())
+            },
+            ArityMismatch { ref con, expected, found, .. } => {
+                (format!("wrong number of type arguments to {}", con), format!("expected {}, found {}", expected, found))
+            },
+            UndefinedName { ref name, ref suggestions, .. } => {
+                if !suggestions.is_empty() {
+                    notes.push(format!("did you mean {}?", suggestions.join(", ")));
+                }
+                (format!("`{}` is undefined", name), "not found in scope".to_owned())
+            },
+            NotAFunction { ref typ, .. } => {
+                ("cannot apply a non-function".to_owned(), format!("this has type {}, which is not a function", typ))
+            },
+            UndefinedType { ref name, .. } => {
+                (format!("type `{}` is undefined", name), "not found in scope".to_owned())
+            },
+            MissingField { ref field, ref record, .. } => {
+                (format!("no field `{}` on record type {}", field, record), format!("this needs a record with a field `{}`", field))
+            },
+            DuplicateField { ref field, .. } => {
+                (format!("field `{}` specified more than once", field), "repeated here".to_owned())
+            },
+            DuplicateConstructor { ref name, .. } => {
+                (format!("constructor `{}` specified more than once", name), "in this declaration".to_owned())
+            },
+            RecursiveValue { ref name, .. } => {
+                ("`letrec` can only define functions".to_owned(), format!("`{}` is not bound to a function", name))
+            },
+            AnnotationTooGeneral { ref annotation, ref inferred, .. } => {
+                ("annotation is more general than the inferred type".to_owned(),
+                 format!("annotated as {}, but inferred as {}", annotation, inferred))
+            },
+            NoInstance { ref class, ref typ, .. } if infer::is_function(typ) && class != "Num" => {
+                ("cannot compare functions".to_owned(), format!("this compares values of type {}", typ))
+            },
+            NoInstance { ref class, ref typ, .. } => {
+                (format!("{} is not an instance of {}", typ, class), format!("{} is required here", class))
+            },
+            Labeled { .. } => unreachable!(),
+        };
+        let mut diag = Diagnostic::new(message, Label::new(err.span(), label));
+        diag.code = Some(err.code());
+        diag.secondary = err.labels().into_iter().map(|(span, message)| Label::new(span, message)).collect();
+        diag.notes = notes;
+        diag
+    }
+
+    /// Builds a diagnostic from an inference warning
+    pub fn from_warning(warning: &infer::Warning) -> Diagnostic {
+        use infer::Warning::*;
+        let (message, label) = match *warning {
+            NonExhaustive { ref missing, .. } => {
+                ("non-exhaustive match".to_owned(), format!("{} is not covered", missing))
+            },
+            Redundant { .. } => {
+                ("unreachable match arm".to_owned(), "earlier arms already match everything this one does".to_owned())
+            },
+            DiscardedValue { ref typ, .. } => {
+                ("value discarded by `;`".to_owned(), format!("this has type {}, not unit", typ))
+            },
+        };
+        let mut diag = Diagnostic::new(message, Label::new(warning.span(), label));
+        if let DiscardedValue { .. } = *warning {
+            diag.notes.push("use `let _ = ... in` to discard it deliberately".to_owned());
+        }
+        diag.severity = Severity::Warning;
+        diag.code = Some(warning.code());
+        diag
+    }
+
+    /// Builds a diagnostic from a failure while running a program
+    pub fn from_eval_error(err: &eval::Error) -> Diagnostic {
+        use eval::Error::*;
+        let label = match *err {
+            Raised { .. } => "raised here",
+            DivisionByZero { .. } => "the divisor is zero",
+            EmptyList { .. } => "the list is empty",
+            MatchFailure { .. } => "no arm matches",
+        };
+        Diagnostic::new(err.to_string(), Label::new(err.span(), label.to_owned()))
+    }
+
+    /// Renders the diagnostic with the offending lines of `source` and carets under each label
+    pub fn render(&self, source: &str) -> String {
+        let mut out = String::new();
+        let (line, col) = line_col(source, self.primary.span.0);
+        let gutter = format!("{}", line_col(source, source.len()).0).len();
+        let pad = " ".repeat(gutter);
+        let kind = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        match self.code {
+            Some(code) => writeln!(out, "{}[{}]: {}", kind, code, self.message).unwrap(),
+            None => writeln!(out, "{}: {}", kind, self.message).unwrap(),
+        }
+        writeln!(out, "{}--> {}:{}", pad, line, col).unwrap();
+        writeln!(out, "{} |", pad).unwrap();
+        let mut labels = vec![(&self.primary, '^')];
+        labels.extend(self.secondary.iter().map(|l| (l, '-')));
+        let mut shown_line = None;
+        for (label, mark) in labels {
+            let (line, col) = line_col(source, label.span.0);
+            if shown_line != Some(line) {
+                writeln!(out, "{:>width$} | {}", line, source_line(source, label.span.0), width = gutter).unwrap();
+                shown_line = Some(line);
+            }
+            let text = source_line(source, label.span.0);
+            let len = source.get(label.span.0..label.span.1.min(source.len())).unwrap_or("")
+                .chars().take_while(|&c| c != '\n').count().max(1);
+            let len = len.min(text.chars().count().saturating_sub(col - 1).max(1));
+            writeln!(out, "{} | {}{} {}", pad, " ".repeat(col - 1), mark.to_string().repeat(len), label.message).unwrap();
+        }
+        for note in &self.notes {
+            writeln!(out, "{} = note: {}", pad, note).unwrap();
+        }
+        out
+    }
+}
+
+/// Describes the tokens the parser would have accepted, naming regex terminals by what they match
+fn expected_note(expected: &[String]) -> String {
+    let names: Vec<&str> = expected.iter().map(|tok| match tok.as_str() {
+        r####"r#"[0-9]+"#"#### => "a number",
+        r####"r#"[a-zA-Z_][a-zA-Z0-9_]*"#"#### => "an identifier",
+        r####"r#"'[a-z][a-zA-Z0-9_]*"#"#### => "a type variable",
+        r####"r#"[0-9]+\\.[0-9]+"#"#### => "a float",
+        r####"r#"\"(\\\\.|[^\"\\\\])*\""#"#### => "a string",
+        r####"r#"'(\\\\.|[^'\\\\])'"#"#### => "a char",
+        r####"r#"[-+*/=<|&^@$%~?][-+*/=<>|&^@$%~?!.:]*"#"#### => "an operator",
+        tok => tok,
+    }).collect();
+    format!("expected one of {}", names.join(", "))
+}
+
+/// The 1-based line and column of byte offset `pos` in `source`
+fn line_col(source: &str, pos: usize) -> (usize, usize) {
+    let pos = pos.min(source.len());
+    let line_start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+    (source[..pos].matches('\n').count() + 1, source[line_start..pos].chars().count() + 1)
+}
+
+/// The full line of `source` containing byte offset `pos`
+fn source_line(source: &str, pos: usize) -> &str {
+    let pos = pos.min(source.len());
+    let start = source[..pos].rfind('\n').map_or(0, |i| i + 1);
+    let end = source[pos..].find('\n').map_or(source.len(), |i| pos + i);
+    &source[start..end]
+}
@@ -0,0 +1,640 @@
+//! A tree-walking interpreter for well-typed expressions. Programs are assumed to have passed
+//! `infer::check`, so mismatched values are a bug in the type checker and panic.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::Display;
+use std::mem;
+use std::rc::Rc;
+use ast::*;
+use stack;
+
+/// The result of evaluating an expression. Functions refer to the syntax tree they were
+/// defined in, so values cannot outlive it. Values inside other values are shared, so copying
+/// one is cheap, and the traits that walk them guard each level against overflowing the stack
+#[derive(Clone)]
+pub enum Value<'a> {
+    Int(i32),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Char(char),
+    Unit,
+    Tuple(Rc<Vec<Value<'a>>>),
+    /// A constructor applied to all of its arguments, along with its tag: its position in its
+    /// type's declaration, which orders values of the same type. Exceptions all have type
+    /// `exn`, so theirs is where they were declared instead, which tells apart exceptions
+    /// of the same name
+    Con(Rc<str>, usize, Rc<Vec<Value<'a>>>),
+    /// Fields sorted by name
+    Record(Rc<Vec<(String, Value<'a>)>>),
+    Ref(Rc<RefCell<Value<'a>>>),
+    Fun(Rc<Function<'a>>),
+}
+impl<'a> Value<'a> {
+    /// Moves the values directly inside this one into `out`, if nothing else shares them
+    fn take_children(&mut self, out: &mut Vec<Value<'a>>) {
+        match *self {
+            Value::Tuple(ref mut elems) | Value::Con(_, _, ref mut elems) => if let Some(elems) = Rc::get_mut(elems) {
+                out.append(elems);
+            },
+            Value::Record(ref mut fields) => if let Some(fields) = Rc::get_mut(fields) {
+                out.extend(fields.drain(..).map(|f| f.1));
+            },
+            Value::Ref(ref mut cell) => if let Some(cell) = Rc::get_mut(cell) {
+                out.push(mem::replace(cell.get_mut(), Value::Unit));
+            },
+            Value::Fun(ref mut f) => if let Some(f) = Rc::get_mut(f) {
+                match *f {
+                    Function::Closure { ref mut env, .. } => env.take_values(out),
+                    Function::Builtin(_, ref mut args) | Function::Compiled { captured: ref mut args, .. } => out.append(args),
+                }
+            },
+            _ => {},
+        }
+    }
+}
+impl<'a> Drop for Value<'a> {
+    fn drop(&mut self) {
+        // Dropping the inner values directly would recurse once per element of a list
+        let mut pending = vec![];
+        self.take_children(&mut pending);
+        while let Some(mut v) = pending.pop() {
+            v.take_children(&mut pending);
+        }
+    }
+}
+impl<'a> fmt::Debug for Value<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        stack::guard(|| match *self {
+            Value::Int(ref n) => f.debug_tuple("Int").field(n).finish(),
+            Value::Float(ref n) => f.debug_tuple("Float").field(n).finish(),
+            Value::Bool(ref b) => f.debug_tuple("Bool").field(b).finish(),
+            Value::Str(ref s) => f.debug_tuple("Str").field(s).finish(),
+            Value::Char(ref c) => f.debug_tuple("Char").field(c).finish(),
+            Value::Unit => write!(f, "Unit"),
+            Value::Tuple(ref elems) => f.debug_tuple("Tuple").field(elems).finish(),
+            Value::Con(ref c, ref tag, ref args) => f.debug_tuple("Con").field(c).field(tag).field(args).finish(),
+            Value::Record(ref fields) => f.debug_tuple("Record").field(fields).finish(),
+            Value::Ref(ref cell) => f.debug_tuple("Ref").field(cell).finish(),
+            Value::Fun(ref fun) => f.debug_tuple("Fun").field(fun).finish(),
+        })
+    }
+}
+impl<'a> Display for Value<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        stack::guard(|| match *self {
+            Value::Int(n) => write!(f, "{}", n),
+            Value::Float(n) => write!(f, "{:?}", n),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Str(ref s) => write!(f, "{:?}", s),
+            Value::Char(c) => write!(f, "{:?}", c),
+            Value::Unit => write!(f, "()"),
+            Value::Tuple(ref elems) => {
+                write!(f, "(")?;
+                write_list(f, elems.iter())?;
+                write!(f, ")")
+            },
+            Value::Con(ref c, _, _) if &**c == "nil" || &**c == "pair" => {
+                write!(f, "[")?;
+                write_list(f, list_elements(self).into_iter())?;
+                write!(f, "]")
+            },
+            Value::Con(ref c, _, ref args) => {
+                write!(f, "{}", c)?;
+                for arg in args.iter() {
+                    match *arg {
+                        Value::Con(ref c, _, ref a) if !a.is_empty() && &**c != "pair" => write!(f, " ({})", arg)?,
+                        Value::Int(n) if n < 0 => write!(f, " ({})", arg)?,
+                        Value::Float(n) if n < 0.0 => write!(f, " ({})", arg)?,
+                        _ => write!(f, " {}", arg)?,
+                    }
+                }
+                Ok(())
+            },
+            Value::Record(ref fields) => {
+                write!(f, "{{ ")?;
+                for (i, (n, v)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{} = {}", n, v)?;
+                }
+                write!(f, " }}")
+            },
+            Value::Ref(ref cell) => write!(f, "ref {}", cell.borrow()),
+            Value::Fun(_) => write!(f, "<fun>"),
+        })
+    }
+}
+
+/// Writes values separated by commas
+fn write_list<'v, 'a: 'v, I: Iterator<Item = &'v Value<'a>>>(f: &mut fmt::Formatter, values: I) -> fmt::Result {
+    for (i, v) in values.enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}", v)?;
+    }
+    Ok(())
+}
+
+/// A function value: a closure, or a builtin waiting for the rest of its arguments
+#[derive(Debug)]
+pub enum Function<'a> {
+    Closure { param: &'a str, body: &'a Spanned<Expr>, env: Env<'a> },
+    Builtin(Builtin, Vec<Value<'a>>),
+    /// A closure made by the bytecode in `vm`: the index of its code in the program, and the
+    /// values of the variables it captured
+    Compiled { proto: usize, captured: Vec<Value<'a>> },
+}
+
+/// The functions built into the interpreter. Constructors that take arguments are builtins too
+#[derive(Debug, Clone, PartialEq)]
+pub enum Builtin {
+    First,
+    Rest,
+    IsNil,
+    Fst,
+    Snd,
+    Length,
+    ToString,
+    Ref,
+    /// A constructor with its name, tag and number of arguments
+    Con(Rc<str>, usize, usize),
+}
+impl Builtin {
+    fn arity(&self) -> usize {
+        match *self {
+            Builtin::Con(_, _, arity) => arity,
+            _ => 1,
+        }
+    }
+}
+
+/// A mapping from names to values. Each binding sits in a cell so that recursive functions
+/// can be put in the enviroment their closures capture
+#[derive(Clone)]
+pub struct Env<'a>(Option<Rc<Frame<'a>>>);
+
+struct Frame<'a> {
+    name: &'a str,
+    value: RefCell<Value<'a>>,
+    parent: Env<'a>,
+}
+
+impl<'a> fmt::Debug for Env<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // The frames are listed innermost first rather than nested, however many there are
+        let mut list = f.debug_list();
+        let mut env = &self.0;
+        while let Some(ref frame) = *env {
+            list.entry(&(frame.name, &frame.value));
+            env = &frame.parent.0;
+        }
+        list.finish()
+    }
+}
+impl<'a> Drop for Env<'a> {
+    fn drop(&mut self) {
+        // Frames and the values in them are dropped a level at a time, as for `Value`
+        let mut values = vec![];
+        self.take_values(&mut values);
+    }
+}
+
+impl<'a> Env<'a> {
+    pub fn empty() -> Env<'a> {
+        Env(None)
+    }
+    pub fn extend(&self, name: &'a str, value: Value<'a>) -> Env<'a> {
+        Env(Some(Rc::new(Frame { name, value: RefCell::new(value), parent: self.clone() })))
+    }
+    pub fn lookup(&self, name: &str) -> Option<Value<'a>> {
+        let mut env = &self.0;
+        while let Some(ref frame) = *env {
+            if frame.name == name {
+                return Some(frame.value.borrow().clone());
+            }
+            env = &frame.parent.0;
+        }
+        None
+    }
+    /// The tag of the innermost constructor named `name`, which a pattern naming it matches
+    fn constructor(&self, name: &str) -> Option<usize> {
+        let mut env = &self.0;
+        while let Some(ref frame) = *env {
+            if let Some(tag) = constructor_tag(&frame.value.borrow(), name) {
+                return Some(tag);
+            }
+            env = &frame.parent.0;
+        }
+        None
+    }
+    /// Moves the values of the frames nothing else shares into `out`, leaving the
+    /// enviroment empty
+    fn take_values(&mut self, out: &mut Vec<Value<'a>>) {
+        let mut next = self.0.take();
+        while let Some(frame) = next {
+            next = match Rc::try_unwrap(frame) {
+                Ok(Frame { value, mut parent, .. }) => {
+                    out.push(value.into_inner());
+                    parent.0.take()
+                },
+                Err(_) => None,
+            };
+        }
+    }
+    /// Replaces the value of the innermost binding, which must be `name`
+    fn set(&self, name: &str, value: Value<'a>) {
+        let frame = self.0.as_ref().unwrap();
+        debug_assert_eq!(frame.name, name);
+        *frame.value.borrow_mut() = value;
+    }
+}
+
+/// A failure while running a program: an exception nothing handled, or an operation on a
+/// value it is not defined for
+#[derive(Debug, Clone)]
+pub enum Error<'a> {
+    Raised { exn: Value<'a>, span: Span },
+    DivisionByZero { span: Span },
+    /// `first` or `rest` of an empty list
+    EmptyList { function: &'static str, span: Span },
+    /// No arm of a `match` matched the value
+    MatchFailure { span: Span },
+}
+impl<'a> Error<'a> {
+    pub fn span(&self) -> Span {
+        match *self {
+            Error::Raised { span, .. } | Error::DivisionByZero { span } |
+            Error::EmptyList { span, .. } | Error::MatchFailure { span } => span,
+        }
+    }
+}
+impl<'a> Display for Error<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Raised { ref exn, .. } => write!(f, "uncaught exception {}", exn),
+            Error::DivisionByZero { .. } => write!(f, "division by zero"),
+            Error::EmptyList { function, .. } => write!(f, "{} of an empty list", function),
+            Error::MatchFailure { .. } => write!(f, "no match arm matches the value"),
+        }
+    }
+}
+
+pub type Result<'a> = ::std::result::Result<Value<'a>, Error<'a>>;
+
+/// The values of the predefined names in `infer::top_level_env`
+pub fn top_level_env<'a>() -> Env<'a> {
+    builtins().into_iter().fold(Env::empty(), |env, (name, v)| env.extend(name, v))
+}
+
+/// The predefined names and their values
+pub fn builtins<'a>() -> Vec<(&'static str, Value<'a>)> {
+    let builtins = [
+        ("first", Builtin::First),
+        ("rest", Builtin::Rest),
+        ("is_nil", Builtin::IsNil),
+        ("fst", Builtin::Fst),
+        ("snd", Builtin::Snd),
+        ("length", Builtin::Length),
+        ("to_string", Builtin::ToString),
+        ("ref", Builtin::Ref),
+        ("pair", Builtin::Con("pair".into(), 1, 2)),
+    ];
+    let mut values = vec![("nil", constructor("nil", 0, 0))];
+    values.extend(builtins.iter().map(|&(name, ref b)| (name, Value::Fun(Rc::new(Function::Builtin(b.clone(), vec![]))))));
+    values
+}
+
+/// The elements of a list value
+fn list_elements<'v, 'a>(list: &'v Value<'a>) -> Vec<&'v Value<'a>> {
+    let mut elems = vec![];
+    let mut list = list;
+    while let Value::Con(_, _, ref args) = *list {
+        match args.len() {
+            2 => {
+                elems.push(&args[0]);
+                list = &args[1];
+            },
+            _ => break,
+        }
+    }
+    elems
+}
+
+/// Evaluates `expr` with the predefined names in scope
+pub fn eval<'a>(expr: &'a Spanned<Expr>) -> Result<'a> {
+    eval_in(expr, &top_level_env())
+}
+
+pub fn eval_in<'a>(expr: &'a Spanned<Expr>, env: &Env<'a>) -> Result<'a> {
+    stack::guard(|| eval_node(expr, env))
+}
+
+fn eval_node<'a>(expr: &'a Spanned<Expr>, env: &Env<'a>) -> Result<'a> {
+    let span = expr.span;
+    match expr.node {
+        Expr::Num(n) => Ok(Value::Int(n)),
+        Expr::Float(n) => Ok(Value::Float(n)),
+        Expr::Str(ref s) => Ok(Value::Str(s.clone())),
+        Expr::Char(c) => Ok(Value::Char(c)),
+        Expr::Bool(b) => Ok(Value::Bool(b)),
+        Expr::Unit => Ok(Value::Unit),
+        Expr::Var(ref x) => Ok(env.lookup(x).unwrap_or_else(|| panic!("unbound variable {}", x))),
+        Expr::BinOp(ref l, Op::And, ref r) => match eval_in(l, env)? {
+            Value::Bool(true) => eval_in(r, env),
+            v => Ok(v),
+        },
+        Expr::BinOp(ref l, Op::Or, ref r) => match eval_in(l, env)? {
+            Value::Bool(false) => eval_in(r, env),
+            v => Ok(v),
+        },
+        Expr::BinOp(ref l, op, ref r) => {
+            let a = eval_in(l, env)?;
+            let b = eval_in(r, env)?;
+            binop(op, a, b, span)
+        },
+        Expr::UnOp(UnOp::Not, ref e) => match eval_in(e, env)? {
+            Value::Bool(b) => Ok(Value::Bool(!b)),
+            v => panic!("not applied to {}", v),
+        },
+        Expr::UnOp(UnOp::Neg, ref e) => match eval_in(e, env)? {
+            Value::Int(n) => Ok(Value::Int(n.wrapping_neg())),
+            Value::Float(n) => Ok(Value::Float(-n)),
+            v => panic!("cannot negate {}", v),
+        },
+        Expr::Tuple(ref elems) => Ok(Value::Tuple(Rc::new(elems.iter().map(|e| eval_in(e, env)).collect::<::std::result::Result<_, _>>()?))),
+        Expr::Fun(ref x, _, ref body) => Ok(Value::Fun(Rc::new(Function::Closure { param: x, body, env: env.clone() }))),
+        Expr::App(ref f, ref arg) => {
+            let f = eval_in(f, env)?;
+            let arg = eval_in(arg, env)?;
+            apply(f, arg, span)
+        },
+        Expr::Let(ref x, ref e1, ref e2) => {
+            let v = eval_in(e1, env)?;
+            eval_in(e2, &env.extend(x, v))
+        },
+        Expr::LetTuple(ref xs, ref e1, ref e2) => match eval_in(e1, env)? {
+            Value::Tuple(ref vs) => {
+                let env1 = xs.iter().zip(vs.iter().cloned()).fold(env.clone(), |env, (x, v)| env.extend(x, v));
+                eval_in(e2, &env1)
+            },
+            v => panic!("cannot destructure {}", v),
+        },
+        Expr::LetRec(ref x, ref e1, ref e2) => {
+            // Inference only lets `letrec` bind functions, which cannot read the placeholder
+            // before it is set
+            let env1 = env.extend(x, Value::Unit);
+            let v = eval_in(e1, &env1)?;
+            env1.set(x, v);
+            eval_in(e2, &env1)
+        },
+        Expr::LetRecGroup(ref bindings, ref e2) => {
+            // Every function in the group sees the final enviroment, so each binding is set
+            // in the frame that holds it
+            let envs: Vec<Env> = bindings.iter().scan(env.clone(), |env, (x, _)| {
+                *env = env.extend(x, Value::Unit);
+                Some(env.clone())
+            }).collect();
+            let env1 = envs.last().unwrap().clone();
+            for ((x, e1), frame) in bindings.iter().zip(&envs) {
+                let v = eval_in(e1, &env1)?;
+                frame.set(x, v);
+            }
+            eval_in(e2, &env1)
+        },
+        Expr::If(ref pred, ref then, ref otherwise) => match eval_in(pred, env)? {
+            Value::Bool(true) => eval_in(then, env),
+            Value::Bool(false) => eval_in(otherwise, env),
+            v => panic!("if on {}", v),
+        },
+        Expr::TypeDef(ref decl, ref e) => {
+            let env1 = decl.node.ctors.iter().enumerate().fold(env.clone(), |env, (tag, (c, args))| {
+                env.extend(c, constructor(c, tag, args.len()))
+            });
+            eval_in(e, &env1)
+        },
+        Expr::Exception(ref decl, ref e) => {
+            let exn = constructor(&decl.node.name, decl.span.0, decl.node.args.len());
+            eval_in(e, &env.extend(&decl.node.name, exn))
+        },
+        Expr::Match(ref e, ref arms) => {
+            let v = eval_in(e, env)?;
+            for (pat, body) in arms {
+                let mut bindings = vec![];
+                if matches(pat, &v, env, &mut bindings) {
+                    let env1 = bindings.into_iter().fold(env.clone(), |env, (x, v)| env.extend(x, v));
+                    return eval_in(body, &env1);
+                }
+            }
+            Err(Error::MatchFailure { span })
+        },
+        Expr::Try(ref e, ref arms) => match eval_in(e, env) {
+            Err(Error::Raised { exn, span }) => {
+                for (pat, body) in arms {
+                    let mut bindings = vec![];
+                    if matches(pat, &exn, env, &mut bindings) {
+                        let env1 = bindings.into_iter().fold(env.clone(), |env, (x, v)| env.extend(x, v));
+                        return eval_in(body, &env1);
+                    }
+                }
+                Err(Error::Raised { exn, span })
+            },
+            result => result,
+        },
+        Expr::Raise(ref e) => Err(Error::Raised { exn: eval_in(e, env)?, span }),
+        Expr::Record(ref fields) => {
+            let mut values = fields.iter().map(|(x, e)| Ok((x.clone(), eval_in(e, env)?))).collect::<::std::result::Result<Vec<_>, _>>()?;
+            values.sort_by(|a, b| a.0.cmp(&b.0));
+            Ok(Value::Record(Rc::new(values)))
+        },
+        Expr::Field(ref e, ref name) => match eval_in(e, env)? {
+            Value::Record(ref fields) => Ok(fields.iter().find(|f| f.0 == *name).unwrap().1.clone()),
+            v => panic!("field {} of {}", name, v),
+        },
+        Expr::Seq(ref e1, ref e2) => {
+            eval_in(e1, env)?;
+            eval_in(e2, env)
+        },
+        Expr::Deref(ref e) => match eval_in(e, env)? {
+            Value::Ref(ref cell) => Ok(cell.borrow().clone()),
+            v => panic!("cannot dereference {}", v),
+        },
+        Expr::Assign(ref r, ref v) => match eval_in(r, env)? {
+            Value::Ref(ref cell) => {
+                *cell.borrow_mut() = eval_in(v, env)?;
+                Ok(Value::Unit)
+            },
+            v => panic!("cannot assign to {}", v),
+        },
+        // The parser turned the aliases that declare a constructor into type declarations
+        Expr::TypeAlias(_, ref e) | Expr::Fixity(_, ref e) | Expr::Annot(ref e, _) => eval_in(e, env),
+        Expr::Infix(..) => unreachable!("operator chains are resolved by the parser"),
+    }
+}
+
+/// The value bound to a constructor: itself if it takes no arguments, otherwise a function
+/// collecting them
+pub fn constructor<'a>(name: &str, tag: usize, arity: usize) -> Value<'a> {
+    if arity == 0 {
+        Value::Con(name.into(), tag, Rc::new(vec![]))
+    } else {
+        Value::Fun(Rc::new(Function::Builtin(Builtin::Con(name.into(), tag, arity), vec![])))
+    }
+}
+
+/// The tag of `v` if it is the constructor named `name`, before any arguments are applied
+pub fn constructor_tag(v: &Value, name: &str) -> Option<usize> {
+    match *v {
+        Value::Con(ref c, tag, ref args) if &**c == name && args.is_empty() => Some(tag),
+        Value::Fun(ref f) => match **f {
+            Function::Builtin(Builtin::Con(ref c, tag, _), ref args) if &**c == name && args.is_empty() => Some(tag),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Whether `pat` matches `v`, collecting the values bound to its variables
+fn matches<'p, 'a>(pat: &'p Spanned<Pattern>, v: &Value<'a>, env: &Env<'a>, bindings: &mut Vec<(&'p str, Value<'a>)>) -> bool {
+    match (&pat.node, v) {
+        (Pattern::Wildcard, _) => true,
+        (Pattern::Var(x), _) => match env.lookup(x).and_then(|c| constructor_tag(&c, x)) {
+            Some(tag) => match *v {
+                Value::Con(ref d, t, _) => **x == **d && tag == t,
+                _ => false,
+            },
+            _ => {
+                bindings.push((x, v.clone()));
+                true
+            },
+        },
+        (&Pattern::Num(n), &Value::Int(m)) => n == m,
+        (&Pattern::Bool(b), &Value::Bool(c)) => b == c,
+        (Pattern::Tuple(ps), Value::Tuple(vs)) => ps.iter().zip(vs.iter()).all(|(p, v)| matches(p, v, env, bindings)),
+        (Pattern::Con(c, ps), Value::Con(d, t, vs)) => {
+            **c == **d && env.constructor(c) == Some(*t) && ps.iter().zip(vs.iter()).all(|(p, v)| matches(p, v, env, bindings))
+        },
+        _ => false,
+    }
+}
+
+/// Applies a function value to an argument
+pub fn apply<'a>(f: Value<'a>, arg: Value<'a>, span: Span) -> Result<'a> {
+    let f = match f {
+        Value::Fun(ref f) => f.clone(),
+        v => panic!("cannot apply {}", v),
+    };
+    match *f {
+        Function::Closure { param, body, ref env } => eval_in(body, &env.extend(param, arg)),
+        Function::Compiled { .. } => panic!("compiled closures can only be run by the vm"),
+        Function::Builtin(ref b, ref args) => {
+            let mut args = args.clone();
+            args.push(arg);
+            if args.len() < b.arity() {
+                return Ok(Value::Fun(Rc::new(Function::Builtin(b.clone(), args))));
+            }
+            call_builtin(b, args, span)
+        },
+    }
+}
+
+fn call_builtin<'a>(b: &Builtin, mut args: Vec<Value<'a>>, span: Span) -> Result<'a> {
+    let arg = args.pop().unwrap();
+    Ok(match (b, &arg) {
+        (&Builtin::Con(ref c, tag, _), _) => {
+            args.push(arg);
+            Value::Con(c.clone(), tag, Rc::new(args))
+        },
+        (&Builtin::First, Value::Con(_, _, list)) => match list.len() {
+            2 => list[0].clone(),
+            _ => return Err(Error::EmptyList { function: "first", span }),
+        },
+        (&Builtin::Rest, Value::Con(_, _, list)) => match list.len() {
+            2 => list[1].clone(),
+            _ => return Err(Error::EmptyList { function: "rest", span }),
+        },
+        (&Builtin::IsNil, Value::Con(_, _, list)) => Value::Bool(list.is_empty()),
+        (&Builtin::Fst, Value::Tuple(elems)) => elems[0].clone(),
+        (&Builtin::Snd, Value::Tuple(elems)) => elems[1].clone(),
+        (&Builtin::Length, Value::Str(s)) => Value::Int(s.chars().count() as i32),
+        (&Builtin::ToString, Value::Str(_)) => arg,
+        (&Builtin::ToString, v) => Value::Str(v.to_string()),
+        (&Builtin::Ref, _) => Value::Ref(Rc::new(RefCell::new(arg))),
+        (b, v) => panic!("{:?} applied to {}", b, v),
+    })
+}
+
+/// Applies a binary operator other than `&&` and `||`, which only evaluate their right
+/// operand when they need it
+pub fn binop<'a>(op: Op, a: Value<'a>, b: Value<'a>, span: Span) -> Result<'a> {
+    Ok(match (op, &a, &b) {
+        (Op::Add, &Value::Int(a), &Value::Int(b)) => Value::Int(a.wrapping_add(b)),
+        (Op::Sub, &Value::Int(a), &Value::Int(b)) => Value::Int(a.wrapping_sub(b)),
+        (Op::Mul, &Value::Int(a), &Value::Int(b)) => Value::Int(a.wrapping_mul(b)),
+        (Op::Div, &Value::Int(_), &Value::Int(0)) | (Op::Mod, &Value::Int(_), &Value::Int(0)) => {
+            return Err(Error::DivisionByZero { span })
+        },
+        (Op::Div, &Value::Int(a), &Value::Int(b)) => Value::Int(a.wrapping_div(b)),
+        (Op::Mod, &Value::Int(a), &Value::Int(b)) => Value::Int(a.wrapping_rem(b)),
+        (Op::Add, &Value::Float(a), &Value::Float(b)) => Value::Float(a + b),
+        (Op::Sub, &Value::Float(a), &Value::Float(b)) => Value::Float(a - b),
+        (Op::Mul, &Value::Float(a), &Value::Float(b)) => Value::Float(a * b),
+        (Op::Div, &Value::Float(a), &Value::Float(b)) => Value::Float(a / b),
+        (Op::Mod, &Value::Float(a), &Value::Float(b)) => Value::Float(a % b),
+        (Op::Concat, Value::Str(a), Value::Str(b)) => Value::Str(a.clone() + b),
+        (Op::Equal, a, b) => Value::Bool(equal(a, b)),
+        (Op::NotEqual, a, b) => Value::Bool(!equal(a, b)),
+        (Op::Lt, a, b) => Value::Bool(compare(a, b) == Some(Ordering::Less)),
+        (Op::Gt, a, b) => Value::Bool(compare(a, b) == Some(Ordering::Greater)),
+        (Op::Le, a, b) => Value::Bool(matches!(compare(a, b), Some(Ordering::Less) | Some(Ordering::Equal))),
+        (Op::Ge, a, b) => Value::Bool(matches!(compare(a, b), Some(Ordering::Greater) | Some(Ordering::Equal))),
+        (op, a, b) => panic!("{} applied to {} and {}", op, a, b),
+    })
+}
+
+/// Whether two values of a type in the `Eq` class are equal. Floats are compared as IEEE
+/// numbers, so NaN is not equal to anything, itself included
+pub fn equal(a: &Value, b: &Value) -> bool {
+    stack::guard(|| match (a, b) {
+        (&Value::Float(a), &Value::Float(b)) => a == b,
+        (Value::Tuple(a), Value::Tuple(b)) => a.iter().zip(b.iter()).all(|(a, b)| equal(a, b)),
+        (Value::Con(_, t1, a), Value::Con(_, t2, b)) => t1 == t2 && a.iter().zip(b.iter()).all(|(a, b)| equal(a, b)),
+        (a, b) => compare(a, b) == Some(Ordering::Equal),
+    })
+}
+
+/// Orders two values of a type in the `Ord` class. Constructors are ordered by their
+/// position in their type's declaration, and then by their arguments. Floats are ordered as
+/// IEEE numbers, so NaN is unordered with everything, as is anything containing it where
+/// the comparison reaches it
+pub fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    stack::guard(|| match (a, b) {
+        (&Value::Int(a), &Value::Int(b)) => Some(a.cmp(&b)),
+        (&Value::Float(a), &Value::Float(b)) => a.partial_cmp(&b),
+        (&Value::Bool(a), &Value::Bool(b)) => Some(a.cmp(&b)),
+        (Value::Str(a), Value::Str(b)) => Some(a.cmp(b)),
+        (&Value::Char(a), &Value::Char(b)) => Some(a.cmp(&b)),
+        (&Value::Unit, &Value::Unit) => Some(Ordering::Equal),
+        (Value::Tuple(a), Value::Tuple(b)) => compare_all(a, b),
+        (Value::Con(_, t1, a), Value::Con(_, t2, b)) => match t1.cmp(t2) {
+            Ordering::Equal => compare_all(a, b),
+            o => Some(o),
+        },
+        (a, b) => panic!("cannot compare {} and {}", a, b),
+    })
+}
+
+/// Orders two sequences of values by the first pair that is not equal
+fn compare_all(a: &[Value], b: &[Value]) -> Option<Ordering> {
+    for (a, b) in a.iter().zip(b) {
+        match compare(a, b) {
+            Some(Ordering::Equal) => {},
+            o => return o,
+        }
+    }
+    Some(Ordering::Equal)
+}
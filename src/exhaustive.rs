@@ -0,0 +1,183 @@
+//! Exhaustiveness and redundancy checking for `match` arms, using the usefulness
+//! algorithm from Maranget's "Warnings for pattern matching".
+
+use std::fmt;
+use std::fmt::Display;
+use ast::{Pattern, Span, Spanned};
+use infer::{Enviroment, Warning, EXN};
+
+#[derive(Debug, Clone, PartialEq)]
+enum Ctor {
+    Named(String),
+    Tuple(usize),
+    Bool(bool),
+    Num(i32),
+}
+
+/// A pattern reduced to constructors and wildcards
+#[derive(Debug, Clone)]
+enum Pat {
+    Wild,
+    Ctor(Ctor, Vec<Pat>),
+}
+impl Display for Pat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Pat::Wild => write!(f, "_"),
+            Pat::Ctor(Ctor::Named(ref c), ref args) => {
+                write!(f, "{}", c)?;
+                for arg in args {
+                    match *arg {
+                        Pat::Ctor(Ctor::Named(_), ref a) if !a.is_empty() => write!(f, " ({})", arg)?,
+                        _ => write!(f, " {}", arg)?,
+                    }
+                }
+                Ok(())
+            },
+            Pat::Ctor(Ctor::Tuple(_), ref args) => {
+                let args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+                write!(f, "({})", args.join(", "))
+            },
+            Pat::Ctor(Ctor::Bool(b), _) => write!(f, "{}", b),
+            Pat::Ctor(Ctor::Num(n), _) => write!(f, "{}", n),
+        }
+    }
+}
+
+type Row = Vec<Pat>;
+
+/// Warns about arms that can never match and about values no arm matches
+pub fn check_match(pats: &[&Spanned<Pattern>], env: &Enviroment, span: Span) -> Vec<Warning> {
+    let mut warnings = vec![];
+    let mut rows: Vec<Row> = vec![];
+    for pat in pats {
+        let row = vec![lower(&pat.node, env)];
+        if !useful(&rows, &row, env) {
+            warnings.push(Warning::Redundant { span: pat.span });
+        }
+        rows.push(row);
+    }
+    if let Some(missing) = witness(&rows, 1, env) {
+        warnings.push(Warning::NonExhaustive { missing: missing[0].to_string(), span });
+    }
+    warnings
+}
+
+fn lower(pat: &Pattern, env: &Enviroment) -> Pat {
+    match *pat {
+        Pattern::Wildcard => Pat::Wild,
+        Pattern::Var(ref x) if env.lookup_constructor(x).is_some() => Pat::Ctor(Ctor::Named(x.clone()), vec![]),
+        Pattern::Var(_) => Pat::Wild,
+        Pattern::Num(n) => Pat::Ctor(Ctor::Num(n), vec![]),
+        Pattern::Bool(b) => Pat::Ctor(Ctor::Bool(b), vec![]),
+        Pattern::Tuple(ref ps) => Pat::Ctor(Ctor::Tuple(ps.len()), ps.iter().map(|p| lower(&p.node, env)).collect()),
+        Pattern::Con(ref c, ref ps) => Pat::Ctor(Ctor::Named(c.clone()), ps.iter().map(|p| lower(&p.node, env)).collect()),
+    }
+}
+
+/// Every constructor of the type `c` belongs to with its arity, or `None` for types like
+/// `int` whose values cannot be listed. Later declarations can add to `exn`, so its
+/// constructors never cover it
+fn siblings(c: &Ctor, env: &Enviroment) -> Option<Vec<(Ctor, usize)>> {
+    match *c {
+        Ctor::Named(ref name) => env.lookup_constructor(name).filter(|(t, _)| t != EXN).map(|(_, def)| {
+            def.ctors.iter().map(|(c, args)| (Ctor::Named(c.clone()), args.len())).collect()
+        }),
+        Ctor::Tuple(n) => Some(vec![(Ctor::Tuple(n), n)]),
+        Ctor::Bool(_) => Some(vec![(Ctor::Bool(true), 0), (Ctor::Bool(false), 0)]),
+        Ctor::Num(_) => None,
+    }
+}
+
+/// The distinct constructors heading the rows of a matrix
+fn heads(rows: &[Row]) -> Vec<Ctor> {
+    let mut heads = vec![];
+    for row in rows {
+        if let Pat::Ctor(ref c, _) = row[0] {
+            if !heads.contains(c) {
+                heads.push(c.clone());
+            }
+        }
+    }
+    heads
+}
+
+/// The rows that match constructor `c` first, with its arguments spliced in its place
+fn specialize(rows: &[Row], c: &Ctor, arity: usize) -> Vec<Row> {
+    rows.iter().filter_map(|row| {
+        let mut new_row = match row[0] {
+            Pat::Ctor(ref d, ref args) if d == c => args.clone(),
+            Pat::Ctor(..) => return None,
+            Pat::Wild => vec![Pat::Wild; arity],
+        };
+        new_row.extend(row[1..].iter().cloned());
+        Some(new_row)
+    }).collect()
+}
+
+/// The rows that match anything first, with the first column removed
+fn default(rows: &[Row]) -> Vec<Row> {
+    rows.iter().filter(|row| match row[0] {
+        Pat::Wild => true,
+        Pat::Ctor(..) => false,
+    }).map(|row| row[1..].to_vec()).collect()
+}
+
+/// The constructors of the first column's type if `heads` mentions all of them
+fn complete_signature(heads: &[Ctor], env: &Enviroment) -> Option<Vec<(Ctor, usize)>> {
+    heads.first()
+        .and_then(|c| siblings(c, env))
+        .filter(|all| all.iter().all(|(c, _)| heads.contains(c)))
+}
+
+/// Whether some value matched by `q` is matched by none of `rows`
+fn useful(rows: &[Row], q: &[Pat], env: &Enviroment) -> bool {
+    if q.is_empty() {
+        return rows.is_empty();
+    }
+    match q[0] {
+        Pat::Ctor(ref c, ref args) => {
+            let mut q1 = args.clone();
+            q1.extend(q[1..].iter().cloned());
+            useful(&specialize(rows, c, args.len()), &q1, env)
+        },
+        Pat::Wild => match complete_signature(&heads(rows), env) {
+            Some(all) => all.iter().any(|&(ref c, arity)| {
+                let mut q1 = vec![Pat::Wild; arity];
+                q1.extend(q[1..].iter().cloned());
+                useful(&specialize(rows, c, arity), &q1, env)
+            }),
+            None => useful(&default(rows), &q[1..], env),
+        },
+    }
+}
+
+/// An example vector of `n` patterns matched by none of `rows`, if there is one
+fn witness(rows: &[Row], n: usize, env: &Enviroment) -> Option<Row> {
+    if n == 0 {
+        return if rows.is_empty() { Some(vec![]) } else { None };
+    }
+    let heads = heads(rows);
+    if let Some(all) = complete_signature(&heads, env) {
+        for (c, arity) in all {
+            if let Some(mut w) = witness(&specialize(rows, &c, arity), arity + n - 1, env) {
+                let rest = w.split_off(arity);
+                let mut row = vec![Pat::Ctor(c, w)];
+                row.extend(rest);
+                return Some(row);
+            }
+        }
+        return None;
+    }
+    let mut w = witness(&default(rows), n - 1, env)?;
+    // Name a constructor nothing matches when there is one, rather than a bare wildcard
+    let missing = heads.first().and_then(|c| siblings(c, env)).and_then(|all| {
+        all.into_iter().find(|(c, _)| !heads.contains(c))
+    });
+    let head = match missing {
+        Some((c, arity)) => Pat::Ctor(c, vec![Pat::Wild; arity]),
+        None => Pat::Wild,
+    };
+    w.insert(0, head);
+    Some(w)
+}
@@ -0,0 +1,256 @@
+//! Resolves chains of infix operators into a tree, using the precedence and associativity
+//! of each operator. The parser cannot do this itself because `infixl` declarations can
+//! change the fixity of an operator for the rest of an expression. For the same reason, this
+//! is also where `type t = A` is decided to be a declaration or an alias, which depends on
+//! the types declared around it.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Display;
+use std::iter::Peekable;
+use std::mem;
+use std::vec;
+use ast::{Assoc, Expr, FixityDecl, Op, Span, Spanned, TypeAlias, TypeDecl, TypeExpr};
+use im_rc;
+use infer::BUILTIN_TYPES;
+use stack;
+
+/// An operator chain that cannot be grouped, or a malformed fixity declaration. These are
+/// the errors the parser reports itself, so this also covers int literals out of range
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// Two operators of the same precedence that cannot be chained, such as two
+    /// non-associative ones, or a left and a right associative one
+    Ambiguous { left: String, right: String, span: Span },
+    /// A precedence outside of 0 to 9
+    BadPrecedence { prec: i32, span: Span },
+    /// An int literal that does not fit in an int
+    IntOutOfRange { span: Span },
+}
+impl Error {
+    pub fn span(&self) -> Span {
+        match *self {
+            Error::Ambiguous { span, .. } | Error::BadPrecedence { span, .. } | Error::IntOutOfRange { span } => span,
+        }
+    }
+}
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Ambiguous { ref left, ref right, .. } if left == right => {
+                write!(f, "`{}` cannot be chained without parentheses", left)
+            },
+            Error::Ambiguous { ref left, ref right, .. } => {
+                write!(f, "cannot mix `{}` and `{}` without parentheses", left, right)
+            },
+            Error::BadPrecedence { prec, .. } => write!(f, "precedence {} is not between 0 and 9", prec),
+            Error::IntOutOfRange { .. } => write!(f, "int literal out of range"),
+        }
+    }
+}
+
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// The precedence and associativity of each operator in scope
+type Table = HashMap<String, (Assoc, i32)>;
+
+/// Operators no declaration mentions are left associative and bind tighter than any
+/// builtin one
+const DEFAULT_FIXITY: (Assoc, i32) = (Assoc::Left, 9);
+
+fn builtin_table() -> Table {
+    let fixities = [
+        ("||", 2), ("&&", 2),
+        ("=", 4), ("<", 4), (">", 4), ("<=", 4), (">=", 4), ("!=", 4),
+        ("+", 6), ("-", 6), ("^", 6),
+        ("*", 7), ("/", 7), ("mod", 7),
+    ];
+    fixities.iter().map(|&(op, prec)| (op.to_owned(), (Assoc::Left, prec))).collect()
+}
+
+/// The builtin operator named `op`, if it is one
+fn builtin_op(op: &str) -> Option<Op> {
+    Some(match op {
+        "+" => Op::Add,
+        "-" => Op::Sub,
+        "*" => Op::Mul,
+        "/" => Op::Div,
+        "mod" => Op::Mod,
+        "^" => Op::Concat,
+        "=" => Op::Equal,
+        "!=" => Op::NotEqual,
+        "<" => Op::Lt,
+        ">" => Op::Gt,
+        "<=" => Op::Le,
+        ">=" => Op::Ge,
+        "&&" => Op::And,
+        "||" => Op::Or,
+        _ => return None,
+    })
+}
+
+/// What is in scope at a point in the program: the fixity of each operator, and the names of
+/// the types declared around it
+#[derive(Clone)]
+struct Scope {
+    table: Table,
+    types: im_rc::HashSet<String>,
+}
+impl Scope {
+    fn with_type(&self, name: &str) -> Scope {
+        Scope { table: self.table.clone(), types: self.types.update(name.to_owned()) }
+    }
+}
+
+/// Replaces every operator chain in `expr` by the tree its fixities imply. Builtin operators
+/// become `BinOp`s and all others applications of the function they name. Each alias to a
+/// bare name that is not a type in scope becomes the declaration of a type with a single
+/// constructor of that name, since the two are written the same way
+pub fn resolve(mut expr: Spanned<Expr>) -> Result<Spanned<Expr>> {
+    let scope = Scope { table: builtin_table(), types: BUILTIN_TYPES.iter().map(|t| t.to_string()).collect() };
+    resolve_in(&mut expr, &scope)?;
+    Ok(expr)
+}
+
+fn resolve_in(expr: &mut Spanned<Expr>, scope: &Scope) -> Result<()> {
+    stack::guard(|| resolve_node(expr, scope))
+}
+
+/// The declaration `alias` stands for if it names a constructor rather than a type
+fn declared_constructor(alias: &Spanned<TypeAlias>, types: &im_rc::HashSet<String>) -> Option<Spanned<TypeDecl>> {
+    match alias.node.typ {
+        TypeExpr::Con(ref c, ref args) if args.is_empty() && !types.contains(c) => {
+            let TypeAlias { ref name, ref params, .. } = alias.node;
+            let ctors = vec![(c.clone(), vec![])];
+            Some(Spanned::new(TypeDecl { name: name.clone(), params: params.clone(), ctors }, alias.span))
+        },
+        _ => None,
+    }
+}
+
+fn resolve_node(expr: &mut Spanned<Expr>, scope: &Scope) -> Result<()> {
+    let decl = match expr.node {
+        Expr::TypeAlias(ref alias, _) => declared_constructor(alias, &scope.types),
+        _ => None,
+    };
+    if let Some(decl) = decl {
+        let body = match expr.node {
+            Expr::TypeAlias(_, ref mut body) => mem::replace(body, Box::new(Spanned::new(Expr::Unit, (0, 0)))),
+            _ => unreachable!(),
+        };
+        expr.node = Expr::TypeDef(decl, body);
+    }
+    match expr.node {
+        Expr::Infix(ref mut operands, ref mut ops) => {
+            for e in operands.iter_mut() {
+                resolve_in(e, scope)?;
+            }
+            let operands = mem::take(operands).into_iter();
+            let mut chain = Chain { operands, ops: mem::take(ops).into_iter().peekable(), table: &scope.table };
+            *expr = chain.climb(0, None)?;
+        },
+        Expr::Fixity(ref decl, ref mut body) => {
+            if decl.node.prec < 0 || decl.node.prec > 9 {
+                return Err(Error::BadPrecedence { prec: decl.node.prec, span: decl.span });
+            }
+            let mut scope = scope.clone();
+            let FixityDecl { assoc, prec, ref op } = decl.node;
+            scope.table.insert(op.clone(), (assoc, prec));
+            resolve_in(body, &scope)?;
+        },
+        Expr::TypeDef(ref decl, ref mut body) => resolve_in(body, &scope.with_type(&decl.node.name))?,
+        Expr::TypeAlias(ref alias, ref mut body) => resolve_in(body, &scope.with_type(&alias.node.name))?,
+        Expr::BinOp(ref mut e1, _, ref mut e2) | Expr::App(ref mut e1, ref mut e2) |
+        Expr::Let(_, ref mut e1, ref mut e2) | Expr::LetTuple(_, ref mut e1, ref mut e2) |
+        Expr::LetRec(_, ref mut e1, ref mut e2) | Expr::Seq(ref mut e1, ref mut e2) |
+        Expr::Assign(ref mut e1, ref mut e2) => {
+            resolve_in(e1, scope)?;
+            resolve_in(e2, scope)?;
+        },
+        Expr::UnOp(_, ref mut e) | Expr::Fun(_, _, ref mut e) | Expr::Exception(_, ref mut e) |
+        Expr::Field(ref mut e, _) | Expr::Deref(ref mut e) | Expr::Raise(ref mut e) |
+        Expr::Annot(ref mut e, _) => resolve_in(e, scope)?,
+        Expr::Tuple(ref mut elems) => {
+            for e in elems {
+                resolve_in(e, scope)?;
+            }
+        },
+        Expr::LetRecGroup(ref mut bindings, ref mut body) => {
+            for binding in bindings {
+                resolve_in(&mut binding.1, scope)?;
+            }
+            resolve_in(body, scope)?;
+        },
+        Expr::If(ref mut p, ref mut t, ref mut e) => {
+            resolve_in(p, scope)?;
+            resolve_in(t, scope)?;
+            resolve_in(e, scope)?;
+        },
+        Expr::Match(ref mut e, ref mut arms) | Expr::Try(ref mut e, ref mut arms) => {
+            for arm in arms {
+                resolve_in(&mut arm.1, scope)?;
+            }
+            resolve_in(e, scope)?;
+        },
+        Expr::Record(ref mut fields) => {
+            for field in fields {
+                resolve_in(&mut field.1, scope)?;
+            }
+        },
+        Expr::Num(_) | Expr::Float(_) | Expr::Str(_) | Expr::Char(_) | Expr::Bool(_) | Expr::Unit | Expr::Var(_) => {},
+    }
+    Ok(())
+}
+
+/// The resolved operands and operators of a chain, consumed left to right
+struct Chain<'a> {
+    operands: vec::IntoIter<Spanned<Expr>>,
+    ops: Peekable<vec::IntoIter<Spanned<String>>>,
+    table: &'a Table,
+}
+impl<'a> Chain<'a> {
+    /// The fixity of the next operator, if there is one
+    fn fixity(&mut self) -> Option<(Assoc, i32)> {
+        let table = self.table;
+        self.ops.peek().map(|op| table.get(&op.node).cloned().unwrap_or(DEFAULT_FIXITY))
+    }
+
+    /// Groups the rest of the chain by precedence climbing, stopping at the first operator
+    /// that binds looser than `min`. `prev` is the operator to the left, which any operator
+    /// of the same precedence must associate the same way as
+    fn climb(&mut self, min: i32, prev: Option<(String, (Assoc, i32))>) -> Result<Spanned<Expr>> {
+        let mut lhs = self.operands.next().unwrap();
+        let mut prev = prev;
+        loop {
+            let (assoc, prec) = match self.fixity() {
+                Some(fixity) => fixity,
+                None => return Ok(lhs),
+            };
+            if prec < min {
+                return Ok(lhs);
+            }
+            let op = self.ops.next().unwrap();
+            if let Some((ref left, (prev_assoc, prev_prec))) = prev {
+                if prev_prec == prec && (prev_assoc != assoc || assoc == Assoc::NonAssoc) {
+                    return Err(Error::Ambiguous { left: left.clone(), right: op.node, span: op.span });
+                }
+            }
+            let next_min = if assoc == Assoc::Right { prec } else { prec + 1 };
+            let rhs = self.climb(next_min, Some((op.node.clone(), (assoc, prec))))?;
+            prev = Some((op.node.clone(), (assoc, prec)));
+            lhs = combine(lhs, op, rhs);
+        }
+    }
+}
+
+fn combine(lhs: Spanned<Expr>, op: Spanned<String>, rhs: Spanned<Expr>) -> Spanned<Expr> {
+    let span = (lhs.span.0, rhs.span.1);
+    match builtin_op(&op.node) {
+        Some(bin) => Spanned::new(Expr::BinOp(Box::new(lhs), bin, Box::new(rhs)), span),
+        None => {
+            let f = Spanned::new(Expr::Var(op.node), op.span);
+            let partial = Spanned::new(Expr::App(Box::new(f), Box::new(lhs)), span);
+            Spanned::new(Expr::App(Box::new(partial), Box::new(rhs)), span)
+        },
+    }
+}
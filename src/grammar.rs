@@ -1,395 +1,2051 @@
 use std::str::FromStr;
-use ast::{Expr, Op};
+use lalrpop_util::ParseError;
+use ast::{unescape, Assoc, Expr, ExceptionDecl, FixityDecl, UnOp, Pattern, Spanned, TypeExpr, TypeDecl, TypeAlias};
+use fixity;
 extern crate lalrpop_util as __lalrpop_util;
 
 mod __parse__Expr {
     #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports)]
 
     use std::str::FromStr;
-    use ast::{Expr, Op};
+    use lalrpop_util::ParseError;
+    use ast::{unescape, Assoc, Expr, ExceptionDecl, FixityDecl, UnOp, Pattern, Spanned, TypeExpr, TypeDecl, TypeAlias};
+    use fixity;
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(dead_code)]
     pub enum __Symbol<'input> {
+        Term_22_21_22(&'input str),
+        Term_22_21_3d_22(&'input str),
         Term_22_26_26_22(&'input str),
         Term_22_28_22(&'input str),
         Term_22_29_22(&'input str),
         Term_22_2a_22(&'input str),
         Term_22_2b_22(&'input str),
+        Term_22_2c_22(&'input str),
         Term_22_2d_22(&'input str),
         Term_22_2d_3e_22(&'input str),
+        Term_22_2e_22(&'input str),
         Term_22_2f_22(&'input str),
+        Term_22_3a_22(&'input str),
+        Term_22_3a_3d_22(&'input str),
+        Term_22_3b_22(&'input str),
         Term_22_3c_22(&'input str),
+        Term_22_3c_3d_22(&'input str),
         Term_22_3d_22(&'input str),
         Term_22_3e_22(&'input str),
+        Term_22_3e_3d_22(&'input str),
+        Term_22_5e_22(&'input str),
+        Term_22and_22(&'input str),
         Term_22else_22(&'input str),
+        Term_22exception_22(&'input str),
         Term_22false_22(&'input str),
         Term_22fun_22(&'input str),
         Term_22if_22(&'input str),
         Term_22in_22(&'input str),
+        Term_22infix_22(&'input str),
+        Term_22infixl_22(&'input str),
+        Term_22infixr_22(&'input str),
         Term_22let_22(&'input str),
         Term_22letrec_22(&'input str),
+        Term_22match_22(&'input str),
+        Term_22mod_22(&'input str),
+        Term_22not_22(&'input str),
+        Term_22of_22(&'input str),
+        Term_22raise_22(&'input str),
         Term_22then_22(&'input str),
         Term_22true_22(&'input str),
+        Term_22try_22(&'input str),
+        Term_22type_22(&'input str),
+        Term_22with_22(&'input str),
+        Term_22_7b_22(&'input str),
+        Term_22_7c_22(&'input str),
         Term_22_7c_7c_22(&'input str),
+        Term_22_7d_22(&'input str),
+        Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23(&'input str),
+        Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23(&'input str),
+        Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(&'input str),
+        Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23(&'input str),
         Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23(&'input str),
         Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(&'input str),
-        NtAddExpr(Box<Expr>),
-        NtAddOp(Op),
-        NtApp(Box<Expr>),
+        Nt_22_7c_22_3f(::std::option::Option<&'input str>),
+        Nt_28_22_2a_22_20_3cAppType_3e_29(TypeExpr),
+        Nt_28_22_2a_22_20_3cAppType_3e_29_2a(::std::vec::Vec<TypeExpr>),
+        Nt_28_22_2a_22_20_3cAppType_3e_29_2b(::std::vec::Vec<TypeExpr>),
+        Nt_28_22_2c_22_20_3cFieldInit_3e_29((String, Spanned<Expr>)),
+        Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(::std::vec::Vec<(String, Spanned<Expr>)>),
+        Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(::std::vec::Vec<(String, Spanned<Expr>)>),
+        Nt_28_22_2c_22_20_3cId_3e_29(String),
+        Nt_28_22_2c_22_20_3cId_3e_29_2b(::std::vec::Vec<String>),
+        Nt_28_22_2c_22_20_3cPattern_3e_29(Spanned<Pattern>),
+        Nt_28_22_2c_22_20_3cPattern_3e_29_2b(::std::vec::Vec<Spanned<Pattern>>),
+        Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29(Box<Spanned<Expr>>),
+        Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(::std::vec::Vec<Box<Spanned<Expr>>>),
+        Nt_28_22_2c_22_20_3cType_3e_29(TypeExpr),
+        Nt_28_22_2c_22_20_3cType_3e_29_2a(::std::vec::Vec<TypeExpr>),
+        Nt_28_22_2c_22_20_3cType_3e_29_2b(::std::vec::Vec<TypeExpr>),
+        Nt_28_22and_22_20_3cRecBinding_3e_29((String, Spanned<Expr>)),
+        Nt_28_22and_22_20_3cRecBinding_3e_29_2b(::std::vec::Vec<(String, Spanned<Expr>)>),
+        Nt_28_22_7c_22_20_3cArm_3e_29((Spanned<Pattern>, Spanned<Expr>)),
+        Nt_28_22_7c_22_20_3cArm_3e_29_2a(::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>),
+        Nt_28_22_7c_22_20_3cArm_3e_29_2b(::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>),
+        Nt_28_22_7c_22_20_3cCtorDecl_3e_29((String, Vec<TypeExpr>)),
+        Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(::std::vec::Vec<(String, Vec<TypeExpr>)>),
+        Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(::std::vec::Vec<(String, Vec<TypeExpr>)>),
+        Nt_28Operator_20Unary_3c_22Any_22_3e_29((Spanned<String>, Box<Spanned<Expr>>)),
+        Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtAccess_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtAccess_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        NtApp_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtApp_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        NtAppType(TypeExpr),
+        NtArm((Spanned<Pattern>, Spanned<Expr>)),
+        NtAssignExpr(Box<Spanned<Expr>>),
+        NtAssoc(Assoc),
+        NtAtomPattern(Spanned<Pattern>),
+        NtAtomPattern_2b(::std::vec::Vec<Spanned<Pattern>>),
         NtBool(bool),
-        NtExpr(Box<Expr>),
-        NtFactor(Box<Expr>),
-        NtFactorOp(Op),
+        NtBuiltinOp(&'static str),
+        NtChar(char),
+        NtCtorDecl((String, Vec<TypeExpr>)),
+        NtDigits(&'input str),
+        NtExpr(Box<Spanned<Expr>>),
+        NtFieldInit((String, Spanned<Expr>)),
+        NtFixityDecl(FixityDecl),
+        NtFloat(f64),
         NtId(String),
-        NtIf(Box<Expr>),
-        NtLambda(Box<Expr>),
-        NtLet(Box<Expr>),
-        NtLogicExpr(Box<Expr>),
-        NtLogicOp(Op),
+        NtIf_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtIf_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtInfixExpr(Box<Spanned<Expr>>),
+        NtLambda_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtLambda_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtLet_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtLet_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtName(String),
         NtNum(i32),
-        NtRelExpr(Box<Expr>),
-        NtRelOp(Op),
-        NtTerm(Box<Expr>),
-        Nt____Expr(Box<Expr>),
+        NtOperator(Spanned<String>),
+        NtPattern(Spanned<Pattern>),
+        NtRecBinding((String, Spanned<Expr>)),
+        NtSeq_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtSeq_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtSpannedType(Spanned<TypeExpr>),
+        NtStr(String),
+        NtSym(String),
+        NtTerm_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtTerm_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        NtTupleType(TypeExpr),
+        NtType(TypeExpr),
+        NtTypeAlias(TypeAlias),
+        NtTypeDecl(TypeDecl),
+        NtTypeVar(String),
+        NtTypeVar_2a(::std::vec::Vec<String>),
+        NtTypeVar_2b(::std::vec::Vec<String>),
+        NtUnary_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtUnary_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        Nt____Expr(Box<Spanned<Expr>>),
+        Nt____Type(TypeExpr),
     }
     const __ACTION: &'static [i32] = &[
         // State 0
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 1
-        -28, 0, -28, 0, 25, 26, 0, 0, -28, -28, -28, -28, 0, 0, 0, -28, 0, 0, -28, 0, -28, 0, 0,
+        -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, 46, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, 0, -51, 0, 0, -51, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, -51, -51, 0, 0, -51, -51, -51, -51, -51, -51, -51, 0, -51, -51, -51, -51,
         // State 2
-        -11, 15, -11, -11, -11, -11, 0, -11, -11, -11, -11, -11, 16, 0, 0, -11, 0, 0, -11, 21, -11, 22, 23,
+        22, -206, -206, 23, -206, -206, -206, -206, -206, 0, 0, -206, -206, -206, -206, -206, -206, -206, -206, -206, -206, -206, -206, 0, 26, 0, 0, -206, 0, 0, 0, 0, 0, 0, -206, 0, 0, 0, -206, 37, 0, 0, -206, 40, -206, -206, -206, 41, 42, 0, -206, 43, 44, 45,
         // State 3
-        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34,
+        0, 0, 0, 0, -111, 0, 0, -111, 0, 0, 0, 0, -111, 0, 48, 0, 0, 0, 0, 0, 0, -111, -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36, -36,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0,
         // State 5
-        -2, 0, -2, 29, -2, -2, 0, 30, -2, -2, -2, -2, 0, 0, 0, -2, 0, 0, -2, 0, -2, 0, 0,
+        -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158, -158,
         // State 6
-        -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33,
+        -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156,
         // State 7
-        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
+        -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140,
         // State 8
-        -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16,
+        -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211, -211,
         // State 9
-        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        32, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, 0, 0, -18, 0, 0, -18, 0, 33, 0, 0,
+        -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154, -154,
         // State 11
-        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
+        -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157,
         // State 12
-        -23, 0, -23, 0, 0, 0, 0, 0, 35, 36, 37, -23, 0, 0, 0, -23, 0, 0, -23, 0, -23, 0, 0,
+        -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137,
         // State 13
-        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        0, 0, 0, 0, -62, 0, 0, -62, 0, 0, 0, 0, -62, 51, -62, 0, 0, 0, 0, 0, 0, -62, -62, 0, 0, 0, 0, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, 0, 0, 0, -62, 0, -62, 0, -62, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103,
         // State 15
-        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149, -149,
         // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23,
+        -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153, -153,
         // State 17
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95, -95,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23,
+        -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155, -155,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23,
+        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
         // State 20
-        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        0, 56, 57, 0, -105, 58, 59, -105, 60, 0, 0, 61, -105, -105, -105, 62, 63, 64, 65, 66, 67, -105, -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, -105, 0, 0, 0, -105, 0, -105, 69, -105, 0, 0, 0, 70, 0, 0, 0,
         // State 21
-        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        22, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 22
-        -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14,
+        22, 0, 0, 23, 74, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 70, 43, 44, 45,
         // State 23
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 21, 0, 22, 23,
+        85, 0, 0, 86, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 89, 0, 37, 0, 0, 0, 90, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 24
-        -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
         // State 25
-        -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,
+        -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75, -75,
         // State 26
-        -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5,
+        0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
         // State 27
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 21, 0, 22, 23,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 28
-        -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
+        -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65,
         // State 29
-        -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13,
+        -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63,
         // State 30
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 21, 0, 22, 23,
+        -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64,
         // State 31
-        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
+        0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
         // State 32
-        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
         // State 33
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 21, 0, 22, 23,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 34
-        -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 36, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 35
-        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
+        22, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 36
-        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74, -74,
         // State 37
-        0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 38
-        0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 45,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151, -151,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90, -90,
         // State 42
-        -1, 0, -1, 29, -1, -1, 0, 30, -1, -1, -1, -1, 0, 0, 0, -1, 0, 0, -1, 0, -1, 0, 0,
+        -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94, -94,
         // State 43
-        -10, 15, -10, -10, -10, -10, 0, -10, -10, -10, -10, -10, 16, 0, 0, -10, 0, 0, -10, 21, -10, 22, 23,
+        -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98,
         // State 44
-        -22, 0, -22, 0, 0, 0, 0, 0, 35, 36, 37, -22, 0, 0, 0, -22, 0, 0, -22, 0, -22, 0, 0,
+        -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99,
         // State 45
-        -27, 0, -27, 0, 25, 26, 0, 0, -27, -27, -27, -27, 0, 0, 0, -27, 0, 0, -27, 0, -27, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
         // State 46
-        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35,
+        -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, 46, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, 0, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, -49, 0, 0, 0, -49, -49, 0, 0, -49, -49, -49, -49, -49, -49, -49, 0, -49, -49, -49, -49,
         // State 47
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 48
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        0, 56, 57, 0, 0, 58, 59, 0, 60, 0, 0, 61, 0, 0, 0, 62, 63, 64, 65, 66, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 70, 0, 0, 0,
         // State 49
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 50
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 36, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 51
-        -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17,
+        0, 56, 57, 0, -104, 58, 59, -104, 60, 0, 0, 61, -104, -104, -104, 62, 63, 64, 65, 66, 67, -104, -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, -104, 0, 0, 0, -104, 0, -104, 69, -104, 0, 0, 0, 70, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0,
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 36, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0,
+        -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142,
         // State 55
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83,
         // State 56
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77, -77,
         // State 57
-        0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 17, 18, 0, 19, 20, 0, 21, 0, 22, 23,
+        -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87, -87,
         // State 58
-        -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15,
+        -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84,
         // State 59
-        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
+        -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85, -85,
         // State 60
-        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88, -88,
+        // State 61
+        -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79, -79,
+        // State 62
+        -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82,
+        // State 63
+        -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80,
+        // State 64
+        -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78, -78,
+        // State 65
+        -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81,
+        // State 66
+        -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86, -86,
+        // State 67
+        -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89, -89,
+        // State 68
+        -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76, -76,
+        // State 69
+        -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152, -152,
+        // State 70
+        -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165,
+        // State 71
+        0, 0, 0, 0, 120, 0, 0, 121, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164,
+        // State 74
+        -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, 124, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, 0, -54, 0, 0, -54, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, -54, -54, 0, 0, -54, -54, -54, -54, -54, -54, -54, 0, -54, -54, -54, -54,
+        // State 75
+        22, -210, -210, 23, -210, -210, -210, -210, -210, 0, 0, -210, -210, -210, -210, -210, -210, -210, -210, -210, -210, -210, -210, 0, 26, 0, 0, -210, 0, 0, 0, 0, 0, 0, -210, 0, 0, 0, -210, 37, 0, 0, -210, 40, -210, -210, -210, 41, 42, 0, -210, 43, 44, 45,
+        // State 76
+        -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172,
+        // State 77
+        -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170,
+        // State 78
+        -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203, -203,
+        // State 79
+        -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168, -168,
+        // State 80
+        -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171,
+        // State 81
+        -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169,
+        // State 82
+        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
+        // State 83
+        -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204, -204,
+        // State 84
+        22, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 85
+        22, 0, 0, 23, 129, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 70, 43, 44, 45,
+        // State 86
+        85, 0, 0, 86, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 89, 0, 37, 0, 0, 0, 90, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 87
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 36, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 88
+        22, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 45,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 45,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205, -205,
+        // State 103
+        -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, 46, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, 0, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, -50, -50, 0, 0, -50, -50, -50, -50, -50, -50, -50, 0, -50, -50, -50, -50,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162, -162,
+        // State 111
+        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
+        // State 112
+        -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148, -148,
+        // State 113
+        -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97,
+        // State 114
+        -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136,
+        // State 115
+        -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61, -61,
+        // State 116
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 36, 0, 37, 0, 0, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 117
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        // State 118
+        0, 0, 0, 0, 160, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167, -167,
+        // State 120
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 121
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 122
+        -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 124
+        -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, 46, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, 0, -52, 0, 0, -52, 0, 0, 0, 0, 0, 0, -52, 0, 0, 0, -52, -52, 0, 0, -52, -52, -52, -52, -52, -52, -52, 0, -52, -52, -52, -52,
+        // State 125
+        -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179, -179,
+        // State 126
+        0, 0, 0, 0, 172, 0, 0, 121, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178, -178,
+        // State 129
+        -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207, -207,
+        // State 130
+        -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208, -208,
+        // State 131
+        -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209, -209,
+        // State 132
+        -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, 46, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, 0, -53, 0, 0, -53, 0, 0, 0, 0, 0, 0, -53, 0, 0, 0, -53, -53, 0, 0, -53, -53, -53, -53, -53, -53, -53, 0, -53, -53, -53, -53,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176, -176,
+        // State 135
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 136
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 137
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 140
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 146
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 199, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 147
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 148
+        -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201, -201,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 207, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 151
+        -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198,
+        // State 152
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 153
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 156
+        -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160, -160,
+        // State 157
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 158
+        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
+        // State 159
+        -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159, -159,
+        // State 160
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 161
+        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        // State 162
+        0, 0, 0, 0, -183, 216, 0, -183, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, -58, -58, 0, -58, 0, -58, 0, 0, 0, 0, 0, 217, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, -185, 0, 0, -185, 0, 219, 0, 0, 0, 0, 0, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150, -150,
+        // State 167
+        -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57,
+        // State 168
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 169
+        -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
+        // State 170
+        0, 0, 0, 0, 221, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181, -181,
+        // State 172
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 173
+        -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180, -180,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174, -174,
+        // State 176
+        -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127,
+        // State 177
+        0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109,
+        // State 179
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 228, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 184
+        -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 187
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
+        // State 190
+        0, 0, 0, 0, -130, 0, 0, -130, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, -130, 0, 236, 0, -130, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144,
+        // State 192
+        -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69,
+        // State 193
+        0, 0, 0, 197, -66, 0, 0, -66, 198, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 194
+        -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 197
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0,
+        // State 198
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 199
+        0, 0, 0, 0, -134, 0, 0, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, 0, 0, 0, -134, 0, 236, 0, -134, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 201
+        -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202, -202,
+        // State 202
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 203
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, -58, 0, 0, 0, -58, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186, -186,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 207
+        -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126,
+        // State 208
+        -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125,
+        // State 209
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 210
+        -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161, -161,
+        // State 211
+        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
+        // State 212
+        -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96, -96,
+        // State 213
+        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
+        // State 214
+        0, 0, 0, 0, -182, 255, 0, -182, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 216
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 217
+        -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163, -163,
+        // State 218
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 219
+        0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 220
+        -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173, -173,
+        // State 221
+        0, 0, 0, 0, 260, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175, -175,
+        // State 223
+        0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 261, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 226
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 227
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 229
+        -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14,
+        // State 230
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 231
+        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        // State 232
+        -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124,
+        // State 233
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 234
+        0, 0, 0, 0, -131, 0, 0, -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, -131, 0, 268, 0, -131, 0, 0, 0, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 236
+        -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72, -72,
+        // State 237
+        0, 0, 0, 197, -143, 0, 0, -143, 198, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 238
+        -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66,
+        // State 239
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 240
+        0, 0, 0, 0, 284, 0, 0, 285, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 241
+        -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68,
+        // State 242
+        0, 0, 0, 0, -128, 0, 0, -128, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, -128, 0, 236, 0, -128, 0, 0, 0, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, 0, -135, 0, 0, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, 0, 0, 0, -135, 0, 268, 0, -135, 0, 0, 0, 0, 0, 0, 0,
+        // State 244
+        0, 0, 0, 0, -132, 0, 0, -132, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, -132, 0, 236, 0, -132, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 246
+        0, 0, 0, 0, 0, -58, 0, 0, 0, -58, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 289, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 247
+        -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187, -187,
+        // State 248
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 249
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 291, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 250
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 251
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 253
+        -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
+        // State 254
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 255
+        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        // State 256
+        0, 0, 0, 0, 0, 0, 0, 297, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 298, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 257
+        -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184,
+        // State 258
+        -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59,
+        // State 259
+        -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177, -177,
+        // State 260
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 299, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 261
+        -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102,
+        // State 262
+        -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121,
+        // State 263
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 264
+        -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15,
+        // State 265
+        -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123,
+        // State 266
+        -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145,
+        // State 267
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 268
+        -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33,
+        // State 269
+        -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73, -73,
+        // State 270
+        0, 0, 0, 0, -108, 0, 0, -108, 0, 0, 0, 0, -108, 0, 302, 0, 0, 0, 0, 0, 0, -108, -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, -108, 0, -108, 0, -108, 0, 0, 0, 0, 0, 0, 0,
+        // State 271
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 303, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 272
+        -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120,
+        // State 273
+        -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101,
+        // State 274
+        -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147, -147,
+        // State 275
+        -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60,
+        // State 276
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 277
+        0, 0, 0, 306, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 278
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 279
+        0, 0, 0, 309, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 280
+        0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 281
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 282
+        0, 0, 0, 0, 314, 0, 0, 315, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 283
+        -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71, -71,
+        // State 284
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 285
+        0, 0, 0, 0, -129, 0, 0, -129, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, -129, -129, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, -129, 0, 268, 0, -129, 0, 0, 0, 0, 0, 0, 0,
+        // State 286
+        0, 0, 0, 0, -133, 0, 0, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, 0, 0, 0, -133, 0, 268, 0, -133, 0, 0, 0, 0, 0, 0, 0,
+        // State 287
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 291, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 288
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 289
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 290
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 291
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        // State 292
+        0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 293
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 291, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 294
+        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        // State 295
+        0, 0, 0, 0, 0, 0, 0, 321, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 322, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 296
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 297
+        -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55,
+        // State 298
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 299
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 325, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 300
+        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34,
+        // State 301
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 302
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 303
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 328, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 304
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 329, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 305
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45,
+        // State 306
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 331, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 307
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 332, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 308
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 45,
+        // State 309
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 334, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 310
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 311
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 336, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 312
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 337, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 313
+        -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70,
+        // State 314
+        0, 0, 0, 197, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 45,
+        // State 315
+        -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17,
+        // State 316
+        0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 317
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 291, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 318
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        // State 319
+        0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 320
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 321
+        -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56,
+        // State 322
+        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        // State 323
+        -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110,
+        // State 324
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 325
+        -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146, -146,
+        // State 326
+        -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119,
+        // State 327
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 328
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 329
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 344, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 330
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 331
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 332
+        0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 333
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 334
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 349, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 335
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 336
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 337
+        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
+        // State 338
+        0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 339
+        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        // State 340
+        -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122,
+        // State 341
+        -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118,
+        // State 342
+        -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106,
+        // State 343
+        0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 45,
+        // State 344
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 353, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 345
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 354, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 346
+        0, 0, 0, 0, 355, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 347
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 356, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 348
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 349
+        -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117,
+        // State 350
+        -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116,
+        // State 351
+        0, 0, 0, 0, 358, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 352
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 353
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 354
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 361, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 355
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 356
+        -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115,
+        // State 357
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 363, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 358
+        -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100,
+        // State 359
+        -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112,
+        // State 360
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 28, 0, 29, 30, 31, 32, 33, 34, 0, 35, 0, 36, 0, 37, 38, 39, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 361
+        -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114,
+        // State 362
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 363
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 366, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 364
+        -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107,
+        // State 365
+        22, 0, 0, 23, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 277, 26, 278, 279, 0, 29, 30, 31, 280, 281, 0, 0, 35, 0, 36, 0, 37, 0, 282, 0, 40, 0, 0, 0, 41, 42, 0, 0, 43, 44, 45,
+        // State 366
+        -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113,
     ];
     const __EOF_ACTION: &'static [i32] = &[
+        0,
+        -51,
+        -206,
+        -111,
+        0,
+        -158,
+        -156,
+        -140,
+        -211,
+        0,
+        -154,
+        -157,
+        -137,
+        -62,
+        -103,
+        -149,
+        -153,
+        -95,
+        -155,
+        -46,
+        -105,
+        0,
+        0,
+        0,
+        0,
+        -75,
+        0,
+        0,
+        -65,
+        -63,
+        -64,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -74,
+        0,
+        0,
+        0,
+        -151,
+        -90,
+        -94,
+        -98,
+        -99,
+        0,
+        -49,
+        0,
+        0,
+        0,
+        0,
+        -104,
+        -141,
+        0,
+        -142,
+        -83,
+        -77,
+        -87,
+        -84,
+        -85,
+        -88,
+        -79,
+        -82,
+        -80,
+        -78,
+        -81,
+        -86,
+        -89,
+        -76,
+        -152,
+        -165,
+        0,
+        0,
+        -164,
+        -54,
+        -210,
+        -172,
+        -170,
+        -203,
+        -168,
+        -171,
+        -169,
+        -48,
+        -204,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -138,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -205,
+        -50,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -162,
+        -45,
+        -148,
+        -97,
+        -136,
+        -61,
+        0,
+        -41,
+        0,
+        -167,
+        0,
+        0,
+        -166,
+        0,
+        -52,
+        -179,
+        0,
+        0,
+        -178,
+        -207,
+        -208,
+        -209,
+        -53,
+        0,
+        -176,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -201,
+        0,
+        0,
+        -198,
+        0,
+        0,
+        0,
+        0,
+        -160,
+        0,
+        -42,
+        -159,
+        0,
+        -20,
+        0,
+        0,
+        0,
+        0,
+        -150,
+        -57,
+        0,
+        -47,
+        0,
+        -181,
+        0,
+        -180,
+        0,
+        -174,
+        -127,
+        0,
+        -109,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -139,
+        0,
+        0,
+        0,
         0,
         -28,
+        -130,
+        -144,
+        -69,
+        0,
+        -67,
+        0,
+        0,
+        0,
+        0,
+        -134,
+        0,
+        -202,
+        0,
+        0,
+        0,
+        -186,
+        0,
+        -126,
+        -125,
+        0,
+        -161,
         -11,
-        -34,
-        -36,
-        -2,
-        -33,
+        -96,
         -21,
-        -16,
-        -9,
-        -18,
-        -32,
-        -23,
-        -6,
         0,
-        -8,
+        0,
+        0,
+        -163,
+        0,
+        0,
+        -173,
+        0,
+        -175,
+        0,
+        0,
         0,
         0,
         0,
         0,
-        -7,
-        -26,
         -14,
         0,
-        -3,
-        -4,
-        -5,
+        -29,
+        -124,
+        0,
+        -131,
+        0,
+        -72,
+        0,
+        -66,
+        0,
+        0,
+        -68,
+        -128,
+        -135,
+        -132,
+        0,
+        0,
+        -187,
+        0,
+        0,
+        0,
+        0,
         0,
         -12,
-        -13,
         0,
-        -24,
-        -25,
+        -6,
         0,
-        -30,
-        -31,
-        -29,
+        -184,
+        -59,
+        -177,
+        0,
+        -102,
+        -121,
+        0,
+        -15,
+        -123,
+        -145,
+        0,
+        -33,
+        -73,
+        -108,
+        0,
+        -120,
+        -101,
+        -147,
+        -60,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -71,
+        0,
+        -129,
+        -133,
+        0,
+        0,
+        0,
+        0,
+        -38,
+        0,
+        0,
+        -7,
+        0,
+        0,
+        -55,
+        0,
+        0,
+        -34,
         0,
         0,
         0,
         0,
         0,
-        -1,
-        -10,
-        -22,
-        -27,
-        -35,
         0,
         0,
         0,
         0,
+        0,
+        0,
+        0,
+        -70,
+        0,
         -17,
         0,
         0,
+        -39,
         0,
         0,
+        -56,
+        -25,
+        -110,
         0,
+        -146,
+        -119,
         0,
-        -15,
-        -19,
-        -20,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -18,
+        0,
+        -26,
+        -122,
+        -118,
+        -106,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -117,
+        -116,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -115,
+        0,
+        -100,
+        -112,
+        0,
+        -114,
+        0,
+        0,
+        -107,
+        0,
+        -113,
     ];
     const __GOTO: &'static [i32] = &[
         // State 0
-        2, 0, 3, 4, 5, 6, 0, 7, 8, 9, 10, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 9, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 18, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 1
-        0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 4, 0, 0, 0, 7, 0, 0, 0, 0, 0, 12, 0, 0, 27, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        2, 0, 3, 4, 38, 6, 0, 7, 8, 9, 10, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 40, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 72, 0, 19, 73, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 23
-        0, 0, 3, 4, 0, 43, 0, 7, 0, 0, 0, 0, 0, 12, 0, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 76, 0, 0, 0, 0, 0, 0, 77, 0, 78, 0, 79, 0, 0, 0, 80, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 44, 4, 0, 0, 0, 7, 0, 0, 0, 0, 0, 12, 0, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 95, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        2, 0, 3, 4, 0, 6, 0, 7, 0, 0, 0, 0, 0, 12, 45, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        46, 0, 3, 4, 0, 6, 0, 7, 0, 0, 0, 0, 0, 12, 0, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 102, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 105, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 108, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 4, 0, 0, 0, 7, 0, 0, 0, 0, 0, 12, 0, 0, 27, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        2, 0, 3, 4, 52, 6, 0, 7, 8, 9, 10, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 113, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 48
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 53, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 54, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 115, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 50
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 55, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 116, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 14, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0,
         // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 59, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 60, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        2, 0, 3, 4, 0, 6, 0, 7, 8, 9, 61, 11, 0, 12, 13, 0, 14, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 127, 0, 19, 128, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 76, 0, 0, 0, 0, 0, 0, 77, 0, 78, 0, 130, 0, 0, 0, 80, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 150, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 8, 0, 0, 0, 11, 12, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 162, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165, 0, 0, 0, 0, 166, 167, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 177, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 179, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 181, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 182, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 186, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 200, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 202, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 204, 0, 0, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 206, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 208, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 209, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 213, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 214, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 220, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 222, 0, 0, 0, 0, 166, 167, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 225, 0, 0, 0, 0, 166, 167, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 233, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 237, 238, 193, 0, 0, 0, 8, 0, 0, 0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 243, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 245, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 202
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 246, 0, 0, 0, 0, 0, 247, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 248, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 203
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 253, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 207
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 209
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 210
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 211
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 213
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 256, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 257, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 258, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 220
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 262, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 226
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 263, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 227
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 229
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 230
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 266, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 231
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 232
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 267, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 269, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 236
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 270, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 238
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 239
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 276, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 240
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 283, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 241
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 242
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 286, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 287, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 288, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 246
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 247
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 290, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 249
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 250
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 292, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 251
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 293, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 294, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 253
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 254
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 295, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 255
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 256
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 296, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 257
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 258
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 259
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 260
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 261
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 262
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 263
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 300, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 264
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 265
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 266
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 267
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 301, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 268
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 269
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 270
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 271
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 272
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 273
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 274
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 275
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 276
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 304, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 277
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 305, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 278
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 307, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 279
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 308, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 280
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 310, 0, 0, 0, 311, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 281
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 312, 313, 0, 0, 0, 0, 0, 0, 0,
+        // State 282
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 283
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 284
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 316, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 285
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 286
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 287
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 288
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 317, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 289
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 318, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 290
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 319, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 291
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 292
+        0, 0, 0, 320, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 293
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 294
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 295
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 296
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 323, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 297
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 298
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 324, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 299
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 300
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 301
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 326, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 302
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 327, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 303
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 304
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 305
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 330, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 306
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 307
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 308
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 333, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 309
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 310
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 311
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 312
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 313
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 314
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 193, 0, 0, 0, 8, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 338, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 315
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 316
+        0, 0, 0, 339, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 317
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 318
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 319
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 320
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 340, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 321
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 322
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 323
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 324
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 341, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 325
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 326
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 327
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 342, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 328
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 343, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 329
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 330
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 345, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 331
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 346, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 332
+        0, 0, 0, 0, 0, 0, 0, 0, 347, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 333
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 348, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 334
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 335
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 350, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 336
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 351, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 337
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 338
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 339
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 340
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 341
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 342
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 343
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 352, 0, 0, 0, 0, 166, 167, 0, 0, 168, 0, 0, 0, 0, 0, 0,
+        // State 344
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 345
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 346
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 347
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 348
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 357, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 349
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 350
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 351
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 352
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 359, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 353
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 360, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 354
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 355
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 362, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 356
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 357
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 358
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 359
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 360
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 4, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 10, 11, 12, 0, 13, 14, 0, 15, 0, 16, 0, 17, 0, 0, 0, 0, 364, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 361
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 362
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 365, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 363
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 364
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 365
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 0, 0, 271, 5, 0, 0, 6, 0, 7, 0, 8, 0, 0, 272, 11, 12, 273, 0, 14, 274, 0, 275, 0, 0, 17, 0, 0, 0, 367, 0, 0, 19, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0,
+        // State 366
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
         const __TERMINAL: &'static [&'static str] = &[
+            r###""!""###,
+            r###""!=""###,
             r###""&&""###,
             r###""(""###,
             r###"")""###,
             r###""*""###,
             r###""+""###,
+            r###"",""###,
             r###""-""###,
             r###""->""###,
+            r###"".""###,
             r###""/""###,
+            r###"":""###,
+            r###"":=""###,
+            r###"";""###,
             r###""<""###,
+            r###""<=""###,
             r###""=""###,
             r###"">""###,
+            r###"">=""###,
+            r###""^""###,
+            r###""and""###,
             r###""else""###,
+            r###""exception""###,
             r###""false""###,
             r###""fun""###,
             r###""if""###,
             r###""in""###,
+            r###""infix""###,
+            r###""infixl""###,
+            r###""infixr""###,
             r###""let""###,
             r###""letrec""###,
+            r###""match""###,
+            r###""mod""###,
+            r###""not""###,
+            r###""of""###,
+            r###""raise""###,
             r###""then""###,
             r###""true""###,
+            r###""try""###,
+            r###""type""###,
+            r###""with""###,
+            r###""{""###,
+            r###""|""###,
             r###""||""###,
+            r###""}""###,
+            r###"r#"\"(\\\\.|[^\"\\\\])*\""#"###,
+            r###"r#"'(\\\\.|[^'\\\\])'"#"###,
+            r###"r#"'[a-z][a-zA-Z0-9_]*"#"###,
+            r###"r#"[-+*/=<|&^@$%~?][-+*/=<>|&^@$%~?!.:]*"#"###,
             r###"r#"[0-9]+"#"###,
+            r###"r#"[0-9]+\\.[0-9]+"#"###,
             r###"r#"[a-zA-Z_][a-zA-Z0-9_]*"#"###,
         ];
-        __ACTION[(__state * 23)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+        __ACTION[(__state * 54)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
             if state == 0 {
                 None
             } else {
@@ -401,7 +2057,7 @@ mod __parse__Expr {
         'input,
     >(
         input: &'input str,
-    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), ()>>
+    ) -> Result<Box<Spanned<Expr>>, __lalrpop_util::ParseError<usize, (usize, &'input str), fixity::Error>>
     {
         let mut __tokens = super::__intern_token::__Matcher::new(input);
         let mut __states = vec![0_i32];
@@ -417,29 +2073,60 @@ mod __parse__Expr {
             };
             __last_location = __lookahead.2.clone();
             __integer = match __lookahead.1 {
-                (2, _) if true => 0,
-                (3, _) if true => 1,
-                (4, _) if true => 2,
-                (5, _) if true => 3,
-                (6, _) if true => 4,
-                (7, _) if true => 5,
-                (8, _) if true => 6,
-                (9, _) if true => 7,
-                (10, _) if true => 8,
-                (11, _) if true => 9,
-                (12, _) if true => 10,
-                (13, _) if true => 11,
-                (14, _) if true => 12,
-                (15, _) if true => 13,
-                (16, _) if true => 14,
-                (17, _) if true => 15,
-                (18, _) if true => 16,
-                (19, _) if true => 17,
-                (20, _) if true => 18,
-                (21, _) if true => 19,
-                (22, _) if true => 20,
-                (0, _) if true => 21,
-                (1, _) if true => 22,
+                (7, _) if true => 0,
+                (8, _) if true => 1,
+                (9, _) if true => 2,
+                (10, _) if true => 3,
+                (11, _) if true => 4,
+                (12, _) if true => 5,
+                (13, _) if true => 6,
+                (14, _) if true => 7,
+                (15, _) if true => 8,
+                (16, _) if true => 9,
+                (17, _) if true => 10,
+                (18, _) if true => 11,
+                (19, _) if true => 12,
+                (20, _) if true => 13,
+                (21, _) if true => 14,
+                (22, _) if true => 15,
+                (23, _) if true => 16,
+                (24, _) if true => 17,
+                (25, _) if true => 18,
+                (26, _) if true => 19,
+                (27, _) if true => 20,
+                (28, _) if true => 21,
+                (29, _) if true => 22,
+                (30, _) if true => 23,
+                (31, _) if true => 24,
+                (32, _) if true => 25,
+                (33, _) if true => 26,
+                (34, _) if true => 27,
+                (35, _) if true => 28,
+                (36, _) if true => 29,
+                (37, _) if true => 30,
+                (38, _) if true => 31,
+                (39, _) if true => 32,
+                (40, _) if true => 33,
+                (41, _) if true => 34,
+                (42, _) if true => 35,
+                (43, _) if true => 36,
+                (44, _) if true => 37,
+                (45, _) if true => 38,
+                (46, _) if true => 39,
+                (47, _) if true => 40,
+                (48, _) if true => 41,
+                (49, _) if true => 42,
+                (50, _) if true => 43,
+                (51, _) if true => 44,
+                (52, _) if true => 45,
+                (53, _) if true => 46,
+                (0, _) if true => 47,
+                (1, _) if true => 48,
+                (2, _) if true => 49,
+                (3, _) if true => 50,
+                (4, _) if true => 51,
+                (5, _) if true => 52,
+                (6, _) if true => 53,
                 _ => {
                     let __state = *__states.last().unwrap() as usize;
                     let __error = __lalrpop_util::ParseError::UnrecognizedToken {
@@ -451,1530 +2138,18171 @@ mod __parse__Expr {
             };
             '__inner: loop {
                 let __state = *__states.last().unwrap() as usize;
-                let __action = __ACTION[__state * 23 + __integer];
+                let __action = __ACTION[__state * 54 + __integer];
                 if __action > 0 {
                     let __symbol = match __integer {
                         0 => match __lookahead.1 {
-                            (2, __tok0) => __Symbol::Term_22_26_26_22((__tok0)),
+                            (7, __tok0) => __Symbol::Term_22_21_22((__tok0)),
                             _ => unreachable!(),
                         },
                         1 => match __lookahead.1 {
-                            (3, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            (8, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         2 => match __lookahead.1 {
-                            (4, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            (9, __tok0) => __Symbol::Term_22_26_26_22((__tok0)),
                             _ => unreachable!(),
                         },
                         3 => match __lookahead.1 {
-                            (5, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            (10, __tok0) => __Symbol::Term_22_28_22((__tok0)),
                             _ => unreachable!(),
                         },
                         4 => match __lookahead.1 {
-                            (6, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            (11, __tok0) => __Symbol::Term_22_29_22((__tok0)),
                             _ => unreachable!(),
                         },
                         5 => match __lookahead.1 {
-                            (7, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            (12, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
                             _ => unreachable!(),
                         },
                         6 => match __lookahead.1 {
-                            (8, __tok0) => __Symbol::Term_22_2d_3e_22((__tok0)),
+                            (13, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
                             _ => unreachable!(),
                         },
                         7 => match __lookahead.1 {
-                            (9, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            (14, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
                             _ => unreachable!(),
                         },
                         8 => match __lookahead.1 {
-                            (10, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            (15, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         9 => match __lookahead.1 {
-                            (11, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                            (16, __tok0) => __Symbol::Term_22_2d_3e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         10 => match __lookahead.1 {
-                            (12, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            (17, __tok0) => __Symbol::Term_22_2e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         11 => match __lookahead.1 {
-                            (13, __tok0) => __Symbol::Term_22else_22((__tok0)),
+                            (18, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
                             _ => unreachable!(),
                         },
                         12 => match __lookahead.1 {
-                            (14, __tok0) => __Symbol::Term_22false_22((__tok0)),
+                            (19, __tok0) => __Symbol::Term_22_3a_22((__tok0)),
                             _ => unreachable!(),
                         },
                         13 => match __lookahead.1 {
-                            (15, __tok0) => __Symbol::Term_22fun_22((__tok0)),
+                            (20, __tok0) => __Symbol::Term_22_3a_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         14 => match __lookahead.1 {
-                            (16, __tok0) => __Symbol::Term_22if_22((__tok0)),
+                            (21, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
                             _ => unreachable!(),
                         },
                         15 => match __lookahead.1 {
-                            (17, __tok0) => __Symbol::Term_22in_22((__tok0)),
+                            (22, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
                             _ => unreachable!(),
                         },
                         16 => match __lookahead.1 {
-                            (18, __tok0) => __Symbol::Term_22let_22((__tok0)),
+                            (23, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         17 => match __lookahead.1 {
-                            (19, __tok0) => __Symbol::Term_22letrec_22((__tok0)),
+                            (24, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         18 => match __lookahead.1 {
-                            (20, __tok0) => __Symbol::Term_22then_22((__tok0)),
+                            (25, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         19 => match __lookahead.1 {
-                            (21, __tok0) => __Symbol::Term_22true_22((__tok0)),
+                            (26, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         20 => match __lookahead.1 {
-                            (22, __tok0) => __Symbol::Term_22_7c_7c_22((__tok0)),
+                            (27, __tok0) => __Symbol::Term_22_5e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         21 => match __lookahead.1 {
-                            (0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            (28, __tok0) => __Symbol::Term_22and_22((__tok0)),
                             _ => unreachable!(),
                         },
                         22 => match __lookahead.1 {
-                            (1, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23((__tok0)),
+                            (29, __tok0) => __Symbol::Term_22else_22((__tok0)),
                             _ => unreachable!(),
                         },
-                        _ => unreachable!(),
-                    };
-                    __states.push(__action - 1);
-                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
-                    continue '__shift;
-                } else if __action < 0 {
-                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                        return r;
-                    }
-                } else {
-                    let __state = *__states.last().unwrap() as usize;
-                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                        token: Some(__lookahead),
-                        expected: __expected_tokens(__state),
-                    };
-                    return Err(__error)
-                }
-            }
-        }
-        loop {
-            let __state = *__states.last().unwrap() as usize;
-            let __action = __EOF_ACTION[__state];
-            if __action < 0 {
-                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                    return r;
-                }
-            } else {
-                let __state = *__states.last().unwrap() as usize;
-                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                    token: None,
-                    expected: __expected_tokens(__state),
-                };
-                return Err(__error);
-            }
-        }
-    }
-    pub fn __reduce<
-        'input,
-    >(
-        input: &'input str,
-        __action: i32,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i32>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), ()>>>
-    {
-        let __nonterminal = match -__action {
-            1 => {
-                // AddExpr = AddExpr, AddOp, Factor => ActionFn(18);
-                let __sym2 = __pop_NtFactor(__symbols);
-                let __sym1 = __pop_NtAddOp(__symbols);
-                let __sym0 = __pop_NtAddExpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action18::<>(input, __sym0, __sym1, __sym2);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::NtAddExpr(__nt), __end));
-                0
-            }
-            2 => {
-                // AddExpr = Factor => ActionFn(19);
-                let __sym0 = __pop_NtFactor(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action19::<>(input, __sym0);
-                let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtAddExpr(__nt), __end));
-                0
-            }
-            3 => {
-                // AddOp = "+" => ActionFn(20);
-                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                        23 => match __lookahead.1 {
+                            (30, __tok0) => __Symbol::Term_22exception_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        24 => match __lookahead.1 {
+                            (31, __tok0) => __Symbol::Term_22false_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        25 => match __lookahead.1 {
+                            (32, __tok0) => __Symbol::Term_22fun_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (33, __tok0) => __Symbol::Term_22if_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (34, __tok0) => __Symbol::Term_22in_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (35, __tok0) => __Symbol::Term_22infix_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        29 => match __lookahead.1 {
+                            (36, __tok0) => __Symbol::Term_22infixl_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        30 => match __lookahead.1 {
+                            (37, __tok0) => __Symbol::Term_22infixr_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        31 => match __lookahead.1 {
+                            (38, __tok0) => __Symbol::Term_22let_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        32 => match __lookahead.1 {
+                            (39, __tok0) => __Symbol::Term_22letrec_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        33 => match __lookahead.1 {
+                            (40, __tok0) => __Symbol::Term_22match_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        34 => match __lookahead.1 {
+                            (41, __tok0) => __Symbol::Term_22mod_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        35 => match __lookahead.1 {
+                            (42, __tok0) => __Symbol::Term_22not_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        36 => match __lookahead.1 {
+                            (43, __tok0) => __Symbol::Term_22of_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        37 => match __lookahead.1 {
+                            (44, __tok0) => __Symbol::Term_22raise_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        38 => match __lookahead.1 {
+                            (45, __tok0) => __Symbol::Term_22then_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        39 => match __lookahead.1 {
+                            (46, __tok0) => __Symbol::Term_22true_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        40 => match __lookahead.1 {
+                            (47, __tok0) => __Symbol::Term_22try_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        41 => match __lookahead.1 {
+                            (48, __tok0) => __Symbol::Term_22type_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        42 => match __lookahead.1 {
+                            (49, __tok0) => __Symbol::Term_22with_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        43 => match __lookahead.1 {
+                            (50, __tok0) => __Symbol::Term_22_7b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        44 => match __lookahead.1 {
+                            (51, __tok0) => __Symbol::Term_22_7c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        45 => match __lookahead.1 {
+                            (52, __tok0) => __Symbol::Term_22_7c_7c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        46 => match __lookahead.1 {
+                            (53, __tok0) => __Symbol::Term_22_7d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        47 => match __lookahead.1 {
+                            (0, __tok0) => __Symbol::Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        48 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        49 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        50 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        51 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        52 => match __lookahead.1 {
+                            (5, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        53 => match __lookahead.1 {
+                            (6, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state];
+            if __action < 0 {
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let __state = *__states.last().unwrap() as usize;
+                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                    token: None,
+                    expected: __expected_tokens(__state),
+                };
+                return Err(__error);
+            }
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize, (usize, &'input str), fixity::Error>>>
+    {
+        let __nonterminal = match -__action {
+            1 => {
+                // "|"? = "|" => ActionFn(141);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action20::<>(input, __sym0);
+                let __nt = super::__action141::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtAddOp(__nt), __end));
-                1
+                __symbols.push((__start, __Symbol::Nt_22_7c_22_3f(__nt), __end));
+                0
             }
-            4 => {
-                // AddOp = "-" => ActionFn(21);
-                let __sym0 = __pop_Term_22_2d_22(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action21::<>(input, __sym0);
+            2 => {
+                // "|"? =  => ActionFn(142);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action142::<>(input, &__start, &__end);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtAddOp(__nt), __end));
-                1
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_22_7c_22_3f(__nt), __end));
+                0
             }
-            5 => {
-                // App = App, Term => ActionFn(26);
-                let __sym1 = __pop_NtTerm(__symbols);
-                let __sym0 = __pop_NtApp(__symbols);
+            3 => {
+                // ("*" <AppType>) = "*", AppType => ActionFn(79);
+                let __sym1 = __pop_NtAppType(__symbols);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym1.2.clone();
-                let __nt = super::__action26::<>(input, __sym0, __sym1);
+                let __nt = super::__action79::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 2);
-                __symbols.push((__start, __Symbol::NtApp(__nt), __end));
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29(__nt), __end));
+                1
+            }
+            4 => {
+                // ("*" <AppType>)* =  => ActionFn(77);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action77::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2a(__nt), __end));
                 2
             }
-            6 => {
-                // App = Term => ActionFn(27);
-                let __sym0 = __pop_NtTerm(__symbols);
+            5 => {
+                // ("*" <AppType>)* = ("*" <AppType>)+ => ActionFn(78);
+                let __sym0 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action27::<>(input, __sym0);
+                let __nt = super::__action78::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtApp(__nt), __end));
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2a(__nt), __end));
                 2
             }
-            7 => {
-                // Bool = "true" => ActionFn(34);
-                let __sym0 = __pop_Term_22true_22(__symbols);
+            6 => {
+                // ("*" <AppType>)+ = "*", AppType => ActionFn(198);
+                let __sym1 = __pop_NtAppType(__symbols);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action34::<>(input, __sym0);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action198::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtBool(__nt), __end));
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__nt), __end));
                 3
             }
-            8 => {
-                // Bool = "false" => ActionFn(35);
-                let __sym0 = __pop_Term_22false_22(__symbols);
+            7 => {
+                // ("*" <AppType>)+ = ("*" <AppType>)+, "*", AppType => ActionFn(199);
+                let __sym2 = __pop_NtAppType(__symbols);
+                let __sym1 = __pop_Term_22_2a_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action35::<>(input, __sym0);
+                let __end = __sym2.2.clone();
+                let __nt = super::__action199::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtBool(__nt), __end));
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__nt), __end));
                 3
             }
-            9 => {
-                // Expr = Let => ActionFn(1);
-                let __sym0 = __pop_NtLet(__symbols);
+            8 => {
+                // ("," <FieldInit>) = ",", FieldInit => ActionFn(188);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action188::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29(__nt), __end));
                 4
             }
-            10 => {
-                // Factor = Factor, FactorOp, App => ActionFn(22);
-                let __sym2 = __pop_NtApp(__symbols);
-                let __sym1 = __pop_NtFactorOp(__symbols);
-                let __sym0 = __pop_NtFactor(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action22::<>(input, __sym0, __sym1, __sym2);
+            9 => {
+                // ("," <FieldInit>)* =  => ActionFn(186);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action186::<>(input, &__start, &__end);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(__nt), __end));
                 5
             }
-            11 => {
-                // Factor = App => ActionFn(23);
-                let __sym0 = __pop_NtApp(__symbols);
+            10 => {
+                // ("," <FieldInit>)* = ("," <FieldInit>)+ => ActionFn(187);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action23::<>(input, __sym0);
+                let __nt = super::__action187::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(__nt), __end));
                 5
             }
+            11 => {
+                // ("," <FieldInit>)+ = ",", FieldInit => ActionFn(204);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action204::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__nt), __end));
+                6
+            }
             12 => {
-                // FactorOp = "*" => ActionFn(24);
-                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                // ("," <FieldInit>)+ = ("," <FieldInit>)+, ",", FieldInit => ActionFn(205);
+                let __sym2 = __pop_NtFieldInit(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action24::<>(input, __sym0);
+                let __end = __sym2.2.clone();
+                let __nt = super::__action205::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__nt), __end));
                 6
             }
             13 => {
-                // FactorOp = "/" => ActionFn(25);
-                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                // ("," <Id>) = ",", Id => ActionFn(148);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action25::<>(input, __sym0);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action148::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
-                6
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29(__nt), __end));
+                7
             }
             14 => {
-                // Id = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(33);
-                let __sym0 = __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__symbols);
+                // ("," <Id>)+ = ",", Id => ActionFn(210);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action33::<>(input, __sym0);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action210::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtId(__nt), __end));
-                7
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29_2b(__nt), __end));
+                8
             }
             15 => {
-                // If = "if", Let, "then", Let, "else", Let => ActionFn(5);
-                let __sym5 = __pop_NtLet(__symbols);
-                let __sym4 = __pop_Term_22else_22(__symbols);
-                let __sym3 = __pop_NtLet(__symbols);
-                let __sym2 = __pop_Term_22then_22(__symbols);
-                let __sym1 = __pop_NtLet(__symbols);
-                let __sym0 = __pop_Term_22if_22(__symbols);
+                // ("," <Id>)+ = ("," <Id>)+, ",", Id => ActionFn(211);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action5::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __end = __sym2.2.clone();
+                let __nt = super::__action211::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::NtIf(__nt), __end));
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29_2b(__nt), __end));
                 8
             }
             16 => {
-                // If = Lambda => ActionFn(6);
-                let __sym0 = __pop_NtLambda(__symbols);
+                // ("," <Pattern>) = ",", Pattern => ActionFn(89);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action6::<>(input, __sym0);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action89::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtIf(__nt), __end));
-                8
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29(__nt), __end));
+                9
             }
             17 => {
-                // Lambda = "fun", Id, "->", Expr => ActionFn(7);
-                let __sym3 = __pop_NtExpr(__symbols);
-                let __sym2 = __pop_Term_22_2d_3e_22(__symbols);
-                let __sym1 = __pop_NtId(__symbols);
-                let __sym0 = __pop_Term_22fun_22(__symbols);
+                // ("," <Pattern>)+ = ",", Pattern => ActionFn(212);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym3.2.clone();
-                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action212::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 4);
-                __symbols.push((__start, __Symbol::NtLambda(__nt), __end));
-                9
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__nt), __end));
+                10
             }
             18 => {
-                // Lambda = LogicExpr => ActionFn(8);
-                let __sym0 = __pop_NtLogicExpr(__symbols);
+                // ("," <Pattern>)+ = ("," <Pattern>)+, ",", Pattern => ActionFn(213);
+                let __sym2 = __pop_NtPattern(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action8::<>(input, __sym0);
+                let __end = __sym2.2.clone();
+                let __nt = super::__action213::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtLambda(__nt), __end));
-                9
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__nt), __end));
+                10
             }
             19 => {
-                // Let = "let", Id, "=", Let, "in", Let => ActionFn(2);
-                let __sym5 = __pop_NtLet(__symbols);
-                let __sym4 = __pop_Term_22in_22(__symbols);
-                let __sym3 = __pop_NtLet(__symbols);
-                let __sym2 = __pop_Term_22_3d_22(__symbols);
-                let __sym1 = __pop_NtId(__symbols);
-                let __sym0 = __pop_Term_22let_22(__symbols);
+                // ("," <Seq<"Open">>) = ",", Seq<"Open"> => ActionFn(191);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action2::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action191::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::NtLet(__nt), __end));
-                10
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29(__nt), __end));
+                11
             }
             20 => {
-                // Let = "letrec", Id, "=", Let, "in", Let => ActionFn(3);
-                let __sym5 = __pop_NtLet(__symbols);
-                let __sym4 = __pop_Term_22in_22(__symbols);
-                let __sym3 = __pop_NtLet(__symbols);
-                let __sym2 = __pop_Term_22_3d_22(__symbols);
-                let __sym1 = __pop_NtId(__symbols);
-                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                // ("," <Seq<"Open">>)+ = ",", Seq<"Open"> => ActionFn(214);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym5.2.clone();
-                let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action214::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 6);
-                __symbols.push((__start, __Symbol::NtLet(__nt), __end));
-                10
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__nt), __end));
+                12
             }
             21 => {
-                // Let = If => ActionFn(4);
-                let __sym0 = __pop_NtIf(__symbols);
+                // ("," <Seq<"Open">>)+ = ("," <Seq<"Open">>)+, ",", Seq<"Open"> => ActionFn(215);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action4::<>(input, __sym0);
+                let __end = __sym2.2.clone();
+                let __nt = super::__action215::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtLet(__nt), __end));
-                10
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__nt), __end));
+                12
             }
             22 => {
-                // LogicExpr = LogicExpr, LogicOp, RelExpr => ActionFn(9);
-                let __sym2 = __pop_NtRelExpr(__symbols);
-                let __sym1 = __pop_NtLogicOp(__symbols);
-                let __sym0 = __pop_NtLogicExpr(__symbols);
+                // ("," <Type>) = ",", Type => ActionFn(74);
+                let __sym1 = __pop_NtType(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
                 let __start = __sym0.0.clone();
-                let __end = __sym2.2.clone();
-                let __nt = super::__action9::<>(input, __sym0, __sym1, __sym2);
+                let __end = __sym1.2.clone();
+                let __nt = super::__action74::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::NtLogicExpr(__nt), __end));
-                11
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29(__nt), __end));
+                13
             }
             23 => {
-                // LogicExpr = RelExpr => ActionFn(10);
-                let __sym0 = __pop_NtRelExpr(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action10::<>(input, __sym0);
+                // ("," <Type>)* =  => ActionFn(72);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action72::<>(input, &__start, &__end);
                 let __states_len = __states.len();
-                __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtLogicExpr(__nt), __end));
-                11
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2a(__nt), __end));
+                14
             }
             24 => {
-                // LogicOp = "&&" => ActionFn(11);
-                let __sym0 = __pop_Term_22_26_26_22(__symbols);
+                // ("," <Type>)* = ("," <Type>)+ => ActionFn(73);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action11::<>(input, __sym0);
+                let __nt = super::__action73::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtLogicOp(__nt), __end));
-                12
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2a(__nt), __end));
+                14
             }
             25 => {
-                // LogicOp = "||" => ActionFn(12);
-                let __sym0 = __pop_Term_22_7c_7c_22(__symbols);
+                // ("," <Type>)+ = ",", Type => ActionFn(216);
+                let __sym1 = __pop_NtType(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action216::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2b(__nt), __end));
+                15
+            }
+            26 => {
+                // ("," <Type>)+ = ("," <Type>)+, ",", Type => ActionFn(217);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action217::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2b(__nt), __end));
+                15
+            }
+            27 => {
+                // ("and" <RecBinding>) = "and", RecBinding => ActionFn(145);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22and_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action145::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29(__nt), __end));
+                16
+            }
+            28 => {
+                // ("and" <RecBinding>)+ = "and", RecBinding => ActionFn(220);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22and_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action220::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__nt), __end));
+                17
+            }
+            29 => {
+                // ("and" <RecBinding>)+ = ("and" <RecBinding>)+, "and", RecBinding => ActionFn(221);
+                let __sym2 = __pop_NtRecBinding(__symbols);
+                let __sym1 = __pop_Term_22and_22(__symbols);
+                let __sym0 = __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action221::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__nt), __end));
+                17
+            }
+            30 => {
+                // ("|" <Arm>) = "|", Arm => ActionFn(140);
+                let __sym1 = __pop_NtArm(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action140::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29(__nt), __end));
+                18
+            }
+            31 => {
+                // ("|" <Arm>)* =  => ActionFn(138);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action138::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2a(__nt), __end));
+                19
+            }
+            32 => {
+                // ("|" <Arm>)* = ("|" <Arm>)+ => ActionFn(139);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action12::<>(input, __sym0);
+                let __nt = super::__action139::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtLogicOp(__nt), __end));
-                12
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2a(__nt), __end));
+                19
             }
-            26 => {
-                // Num = r#"[0-9]+"# => ActionFn(32);
-                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+            33 => {
+                // ("|" <Arm>)+ = "|", Arm => ActionFn(222);
+                let __sym1 = __pop_NtArm(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action222::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2b(__nt), __end));
+                20
+            }
+            34 => {
+                // ("|" <Arm>)+ = ("|" <Arm>)+, "|", Arm => ActionFn(223);
+                let __sym2 = __pop_NtArm(__symbols);
+                let __sym1 = __pop_Term_22_7c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action223::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2b(__nt), __end));
+                20
+            }
+            35 => {
+                // ("|" <CtorDecl>) = "|", CtorDecl => ActionFn(84);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action84::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29(__nt), __end));
+                21
+            }
+            36 => {
+                // ("|" <CtorDecl>)* =  => ActionFn(82);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action82::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(__nt), __end));
+                22
+            }
+            37 => {
+                // ("|" <CtorDecl>)* = ("|" <CtorDecl>)+ => ActionFn(83);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action32::<>(input, __sym0);
+                let __nt = super::__action83::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
-                13
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(__nt), __end));
+                22
             }
-            27 => {
-                // RelExpr = RelExpr, RelOp, AddExpr => ActionFn(13);
-                let __sym2 = __pop_NtAddExpr(__symbols);
-                let __sym1 = __pop_NtRelOp(__symbols);
-                let __sym0 = __pop_NtRelExpr(__symbols);
+            38 => {
+                // ("|" <CtorDecl>)+ = "|", CtorDecl => ActionFn(232);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action232::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__nt), __end));
+                23
+            }
+            39 => {
+                // ("|" <CtorDecl>)+ = ("|" <CtorDecl>)+, "|", CtorDecl => ActionFn(233);
+                let __sym2 = __pop_NtCtorDecl(__symbols);
+                let __sym1 = __pop_Term_22_7c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action233::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::NtRelExpr(__nt), __end));
-                14
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__nt), __end));
+                23
             }
-            28 => {
-                // RelExpr = AddExpr => ActionFn(14);
-                let __sym0 = __pop_NtAddExpr(__symbols);
+            40 => {
+                // (Operator Unary<"Any">) = Operator, Unary<"Any"> => ActionFn(67);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtOperator(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action67::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29(__nt), __end));
+                24
+            }
+            41 => {
+                // (Operator Unary<"Any">)+ = Operator, Unary<"Any"> => ActionFn(236);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtOperator(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action236::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__nt), __end));
+                25
+            }
+            42 => {
+                // (Operator Unary<"Any">)+ = (Operator Unary<"Any">)+, Operator, Unary<"Any"> => ActionFn(237);
+                let __sym2 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym1 = __pop_NtOperator(__symbols);
+                let __sym0 = __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action237::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__nt), __end));
+                25
+            }
+            43 => {
+                // @L =  => ActionFn(93);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action93::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                26
+            }
+            44 => {
+                // @R =  => ActionFn(90);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action90::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                27
+            }
+            45 => {
+                // Access<"Any"> = Access<"Any">, ".", Id => ActionFn(321);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action321::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAccess_3c_22Any_22_3e(__nt), __end));
+                28
+            }
+            46 => {
+                // Access<"Any"> = Term<"Any"> => ActionFn(133);
+                let __sym0 = __pop_NtTerm_3c_22Any_22_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action14::<>(input, __sym0);
+                let __nt = super::__action133::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtRelExpr(__nt), __end));
-                14
+                __symbols.push((__start, __Symbol::NtAccess_3c_22Any_22_3e(__nt), __end));
+                28
             }
-            29 => {
-                // RelOp = ">" => ActionFn(15);
-                let __sym0 = __pop_Term_22_3e_22(__symbols);
+            47 => {
+                // Access<"NoNum"> = Access<"NoNum">, ".", Id => ActionFn(322);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtAccess_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action322::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAccess_3c_22NoNum_22_3e(__nt), __end));
+                29
+            }
+            48 => {
+                // Access<"NoNum"> = Term<"NoNum"> => ActionFn(172);
+                let __sym0 = __pop_NtTerm_3c_22NoNum_22_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action15::<>(input, __sym0);
+                let __nt = super::__action172::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtRelOp(__nt), __end));
-                15
+                __symbols.push((__start, __Symbol::NtAccess_3c_22NoNum_22_3e(__nt), __end));
+                29
             }
-            30 => {
-                // RelOp = "<" => ActionFn(16);
-                let __sym0 = __pop_Term_22_3c_22(__symbols);
+            49 => {
+                // App<"Any"> = App<"Any">, Access<"Any"> => ActionFn(323);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtApp_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action323::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22Any_22_3e(__nt), __end));
+                30
+            }
+            50 => {
+                // App<"Any"> = "raise", Access<"Any"> => ActionFn(324);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22raise_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action324::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22Any_22_3e(__nt), __end));
+                30
+            }
+            51 => {
+                // App<"Any"> = Access<"Any"> => ActionFn(124);
+                let __sym0 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action16::<>(input, __sym0);
+                let __nt = super::__action124::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtRelOp(__nt), __end));
-                15
+                __symbols.push((__start, __Symbol::NtApp_3c_22Any_22_3e(__nt), __end));
+                30
             }
-            31 => {
-                // RelOp = "=" => ActionFn(17);
-                let __sym0 = __pop_Term_22_3d_22(__symbols);
+            52 => {
+                // App<"NoNum"> = App<"NoNum">, Access<"Any"> => ActionFn(325);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtApp_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action325::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22NoNum_22_3e(__nt), __end));
+                31
+            }
+            53 => {
+                // App<"NoNum"> = "raise", Access<"Any"> => ActionFn(326);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22raise_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action326::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22NoNum_22_3e(__nt), __end));
+                31
+            }
+            54 => {
+                // App<"NoNum"> = Access<"NoNum"> => ActionFn(131);
+                let __sym0 = __pop_NtAccess_3c_22NoNum_22_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action17::<>(input, __sym0);
+                let __nt = super::__action131::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtRelOp(__nt), __end));
-                15
+                __symbols.push((__start, __Symbol::NtApp_3c_22NoNum_22_3e(__nt), __end));
+                31
             }
-            32 => {
-                // Term = Num => ActionFn(28);
-                let __sym0 = __pop_NtNum(__symbols);
+            55 => {
+                // AppType = Id, "<", Type, ">" => ActionFn(218);
+                let __sym3 = __pop_Term_22_3e_22(__symbols);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_3c_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action218::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            56 => {
+                // AppType = Id, "<", Type, ("," <Type>)+, ">" => ActionFn(219);
+                let __sym4 = __pop_Term_22_3e_22(__symbols);
+                let __sym3 = __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_3c_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action219::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            57 => {
+                // AppType = TypeVar => ActionFn(28);
+                let __sym0 = __pop_NtTypeVar(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
                 let __nt = super::__action28::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                16
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
             }
-            33 => {
-                // Term = Id => ActionFn(29);
+            58 => {
+                // AppType = Id => ActionFn(29);
                 let __sym0 = __pop_NtId(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
                 let __nt = super::__action29::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                16
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
             }
-            34 => {
-                // Term = Bool => ActionFn(30);
+            59 => {
+                // AppType = "(", Type, ")" => ActionFn(30);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtType(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action30::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            60 => {
+                // Arm = Pattern, "->", Seq<"Closed"> => ActionFn(7);
+                let __sym2 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym0 = __pop_NtPattern(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtArm(__nt), __end));
+                33
+            }
+            61 => {
+                // AssignExpr = InfixExpr, ":=", AssignExpr => ActionFn(327);
+                let __sym2 = __pop_NtAssignExpr(__symbols);
+                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
+                let __sym0 = __pop_NtInfixExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action327::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssignExpr(__nt), __end));
+                34
+            }
+            62 => {
+                // AssignExpr = InfixExpr => ActionFn(33);
+                let __sym0 = __pop_NtInfixExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssignExpr(__nt), __end));
+                34
+            }
+            63 => {
+                // Assoc = "infixl" => ActionFn(4);
+                let __sym0 = __pop_Term_22infixl_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssoc(__nt), __end));
+                35
+            }
+            64 => {
+                // Assoc = "infixr" => ActionFn(5);
+                let __sym0 = __pop_Term_22infixr_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssoc(__nt), __end));
+                35
+            }
+            65 => {
+                // Assoc = "infix" => ActionFn(6);
+                let __sym0 = __pop_Term_22infix_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action6::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssoc(__nt), __end));
+                35
+            }
+            66 => {
+                // AtomPattern = Id => ActionFn(328);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action328::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            67 => {
+                // AtomPattern = Num => ActionFn(329);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action329::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            68 => {
+                // AtomPattern = "-", Digits => ActionFn(330);
+                let __sym1 = __pop_NtDigits(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action330::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            69 => {
+                // AtomPattern = Bool => ActionFn(331);
                 let __sym0 = __pop_NtBool(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action30::<>(input, __sym0);
+                let __nt = super::__action331::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                16
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
             }
-            35 => {
-                // Term = "(", Expr, ")" => ActionFn(31);
+            70 => {
+                // AtomPattern = "(", Pattern, ("," <Pattern>)+, ")" => ActionFn(332);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action332::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            71 => {
+                // AtomPattern = "(", Pattern, ")" => ActionFn(15);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
-                let __sym1 = __pop_NtExpr(__symbols);
+                let __sym1 = __pop_NtPattern(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action15::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
-                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                16
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
             }
-            36 => {
-                // __Expr = Expr => ActionFn(0);
-                let __sym0 = __pop_NtExpr(__symbols);
+            72 => {
+                // AtomPattern+ = AtomPattern => ActionFn(91);
+                let __sym0 = __pop_NtAtomPattern(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
-                return Some(Ok(__nt));
+                let __nt = super::__action91::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern_2b(__nt), __end));
+                37
             }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 18 + __nonterminal] - 1;
-        __states.push(__next_state);
-        None
-    }
-    fn __pop_Term_22_26_26_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_26_26_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
-    fn __pop_Term_22_28_22<
-      'input,
+            73 => {
+                // AtomPattern+ = AtomPattern+, AtomPattern => ActionFn(92);
+                let __sym1 = __pop_NtAtomPattern(__symbols);
+                let __sym0 = __pop_NtAtomPattern_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action92::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtAtomPattern_2b(__nt), __end));
+                37
+            }
+            74 => {
+                // Bool = "true" => ActionFn(63);
+                let __sym0 = __pop_Term_22true_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action63::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBool(__nt), __end));
+                38
+            }
+            75 => {
+                // Bool = "false" => ActionFn(64);
+                let __sym0 = __pop_Term_22false_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action64::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBool(__nt), __end));
+                38
+            }
+            76 => {
+                // BuiltinOp = "||" => ActionFn(38);
+                let __sym0 = __pop_Term_22_7c_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action38::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            77 => {
+                // BuiltinOp = "&&" => ActionFn(39);
+                let __sym0 = __pop_Term_22_26_26_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action39::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            78 => {
+                // BuiltinOp = ">" => ActionFn(40);
+                let __sym0 = __pop_Term_22_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action40::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            79 => {
+                // BuiltinOp = "<" => ActionFn(41);
+                let __sym0 = __pop_Term_22_3c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            80 => {
+                // BuiltinOp = "=" => ActionFn(42);
+                let __sym0 = __pop_Term_22_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            81 => {
+                // BuiltinOp = ">=" => ActionFn(43);
+                let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action43::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            82 => {
+                // BuiltinOp = "<=" => ActionFn(44);
+                let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action44::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            83 => {
+                // BuiltinOp = "!=" => ActionFn(45);
+                let __sym0 = __pop_Term_22_21_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action45::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            84 => {
+                // BuiltinOp = "+" => ActionFn(46);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action46::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            85 => {
+                // BuiltinOp = "-" => ActionFn(47);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action47::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            86 => {
+                // BuiltinOp = "^" => ActionFn(48);
+                let __sym0 = __pop_Term_22_5e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action48::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            87 => {
+                // BuiltinOp = "*" => ActionFn(49);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action49::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            88 => {
+                // BuiltinOp = "/" => ActionFn(50);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action50::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            89 => {
+                // BuiltinOp = "mod" => ActionFn(51);
+                let __sym0 = __pop_Term_22mod_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action51::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            90 => {
+                // Char = r#"'(\\\\.|[^'\\\\])'"# => ActionFn(57);
+                let __sym0 = __pop_Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action57::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtChar(__nt), __end));
+                40
+            }
+            91 => {
+                // CtorDecl = Id => ActionFn(20);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action20::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCtorDecl(__nt), __end));
+                41
+            }
+            92 => {
+                // CtorDecl = Id, "of", AppType => ActionFn(200);
+                let __sym2 = __pop_NtAppType(__symbols);
+                let __sym1 = __pop_Term_22of_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action200::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtCtorDecl(__nt), __end));
+                41
+            }
+            93 => {
+                // CtorDecl = Id, "of", AppType, ("*" <AppType>)+ => ActionFn(201);
+                let __sym3 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtAppType(__symbols);
+                let __sym1 = __pop_Term_22of_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action201::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtCtorDecl(__nt), __end));
+                41
+            }
+            94 => {
+                // Digits = r#"[0-9]+"# => ActionFn(54);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action54::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtDigits(__nt), __end));
+                42
+            }
+            95 => {
+                // Expr = Seq<"Open"> => ActionFn(2);
+                let __sym0 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action2::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                43
+            }
+            96 => {
+                // FieldInit = Id, "=", Seq<"Open"> => ActionFn(52);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFieldInit(__nt), __end));
+                44
+            }
+            97 => {
+                // FixityDecl = Assoc, Num, Operator => ActionFn(3);
+                let __sym2 = __pop_NtOperator(__symbols);
+                let __sym1 = __pop_NtNum(__symbols);
+                let __sym0 = __pop_NtAssoc(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFixityDecl(__nt), __end));
+                45
+            }
+            98 => {
+                // Float = r#"[0-9]+\\.[0-9]+"# => ActionFn(55);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action55::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFloat(__nt), __end));
+                46
+            }
+            99 => {
+                // Id = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(60);
+                let __sym0 = __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action60::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtId(__nt), __end));
+                47
+            }
+            100 => {
+                // If<"Closed"> = "if", Seq<"Open">, "then", Seq<"Open">, "else", Seq<"Closed"> => ActionFn(333);
+                let __sym5 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym4 = __pop_Term_22else_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22then_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22if_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action333::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Closed_22_3e(__nt), __end));
+                48
+            }
+            101 => {
+                // If<"Closed"> = Lambda<"Closed"> => ActionFn(135);
+                let __sym0 = __pop_NtLambda_3c_22Closed_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action135::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Closed_22_3e(__nt), __end));
+                48
+            }
+            102 => {
+                // If<"Open"> = "if", Seq<"Open">, "then", Seq<"Open">, "else", Seq<"Open"> => ActionFn(334);
+                let __sym5 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym4 = __pop_Term_22else_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22then_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22if_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action334::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Open_22_3e(__nt), __end));
+                49
+            }
+            103 => {
+                // If<"Open"> = Lambda<"Open"> => ActionFn(137);
+                let __sym0 = __pop_NtLambda_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action137::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Open_22_3e(__nt), __end));
+                49
+            }
+            104 => {
+                // InfixExpr = Unary<"Any">, (Operator Unary<"Any">)+ => ActionFn(335);
+                let __sym1 = __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__symbols);
+                let __sym0 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action335::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtInfixExpr(__nt), __end));
+                50
+            }
+            105 => {
+                // InfixExpr = Unary<"Any"> => ActionFn(35);
+                let __sym0 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action35::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtInfixExpr(__nt), __end));
+                50
+            }
+            106 => {
+                // Lambda<"Closed"> = "fun", Id, "->", Seq<"Closed"> => ActionFn(336);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action336::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Closed_22_3e(__nt), __end));
+                51
+            }
+            107 => {
+                // Lambda<"Closed"> = "fun", "(", Id, ":", SpannedType, ")", "->", Seq<"Closed"> => ActionFn(337);
+                let __sym7 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym6 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym5 = __pop_Term_22_29_22(__symbols);
+                let __sym4 = __pop_NtSpannedType(__symbols);
+                let __sym3 = __pop_Term_22_3a_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = super::__action337::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 8);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Closed_22_3e(__nt), __end));
+                51
+            }
+            108 => {
+                // Lambda<"Closed"> = AssignExpr => ActionFn(156);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action156::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Closed_22_3e(__nt), __end));
+                51
+            }
+            109 => {
+                // Lambda<"Open"> = "fun", Id, "->", Seq<"Open"> => ActionFn(338);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action338::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Open_22_3e(__nt), __end));
+                52
+            }
+            110 => {
+                // Lambda<"Open"> = "fun", "(", Id, ":", SpannedType, ")", "->", Seq<"Open"> => ActionFn(339);
+                let __sym7 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym6 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym5 = __pop_Term_22_29_22(__symbols);
+                let __sym4 = __pop_NtSpannedType(__symbols);
+                let __sym3 = __pop_Term_22_3a_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = super::__action339::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 8);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Open_22_3e(__nt), __end));
+                52
+            }
+            111 => {
+                // Lambda<"Open"> = AssignExpr => ActionFn(153);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action153::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Open_22_3e(__nt), __end));
+                52
+            }
+            112 => {
+                // Let<"Closed"> = "let", Name, "=", Seq<"Open">, "in", Seq<"Closed"> => ActionFn(340);
+                let __sym5 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action340::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            113 => {
+                // Let<"Closed"> = "let", "(", Id, ("," <Id>)+, ")", "=", Seq<"Open">, "in", Seq<"Closed"> => ActionFn(341);
+                let __sym8 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym7 = __pop_Term_22in_22(__symbols);
+                let __sym6 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym5 = __pop_Term_22_3d_22(__symbols);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym8.2.clone();
+                let __nt = super::__action341::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 9);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            114 => {
+                // Let<"Closed"> = "letrec", Name, "=", Seq<"Open">, "in", Seq<"Closed"> => ActionFn(342);
+                let __sym5 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action342::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            115 => {
+                // Let<"Closed"> = "letrec", RecBinding, ("and" <RecBinding>)+, "in", Seq<"Closed"> => ActionFn(343);
+                let __sym4 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym3 = __pop_Term_22in_22(__symbols);
+                let __sym2 = __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action343::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            116 => {
+                // Let<"Closed"> = "type", TypeDecl, "in", Seq<"Closed"> => ActionFn(344);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeDecl(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action344::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            117 => {
+                // Let<"Closed"> = "type", TypeAlias, "in", Seq<"Closed"> => ActionFn(345);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeAlias(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action345::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            118 => {
+                // Let<"Closed"> = "exception", CtorDecl, "in", Seq<"Closed"> => ActionFn(346);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22exception_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action346::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            119 => {
+                // Let<"Closed"> = FixityDecl, "in", Seq<"Closed"> => ActionFn(347);
+                let __sym2 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym1 = __pop_Term_22in_22(__symbols);
+                let __sym0 = __pop_NtFixityDecl(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action347::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            120 => {
+                // Let<"Closed"> = If<"Closed"> => ActionFn(117);
+                let __sym0 = __pop_NtIf_3c_22Closed_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action117::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            121 => {
+                // Let<"Open"> = "let", Name, "=", Seq<"Open">, "in", Seq<"Open"> => ActionFn(348);
+                let __sym5 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action348::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            122 => {
+                // Let<"Open"> = "let", "(", Id, ("," <Id>)+, ")", "=", Seq<"Open">, "in", Seq<"Open"> => ActionFn(349);
+                let __sym8 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym7 = __pop_Term_22in_22(__symbols);
+                let __sym6 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym5 = __pop_Term_22_3d_22(__symbols);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym8.2.clone();
+                let __nt = super::__action349::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 9);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            123 => {
+                // Let<"Open"> = "letrec", Name, "=", Seq<"Open">, "in", Seq<"Open"> => ActionFn(350);
+                let __sym5 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action350::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            124 => {
+                // Let<"Open"> = "letrec", RecBinding, ("and" <RecBinding>)+, "in", Seq<"Open"> => ActionFn(351);
+                let __sym4 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym3 = __pop_Term_22in_22(__symbols);
+                let __sym2 = __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action351::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            125 => {
+                // Let<"Open"> = "type", TypeDecl, "in", Seq<"Open"> => ActionFn(352);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeDecl(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action352::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            126 => {
+                // Let<"Open"> = "type", TypeAlias, "in", Seq<"Open"> => ActionFn(353);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeAlias(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action353::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            127 => {
+                // Let<"Open"> = "exception", CtorDecl, "in", Seq<"Open"> => ActionFn(354);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22exception_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action354::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            128 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", "|", Arm => ActionFn(355);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action355::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            129 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", "|", Arm, ("|" <Arm>)+ => ActionFn(356);
+                let __sym5 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action356::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            130 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", Arm => ActionFn(357);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action357::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            131 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", Arm, ("|" <Arm>)+ => ActionFn(358);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action358::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            132 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", "|", Arm => ActionFn(359);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action359::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            133 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", "|", Arm, ("|" <Arm>)+ => ActionFn(360);
+                let __sym5 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action360::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            134 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", Arm => ActionFn(361);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action361::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            135 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", Arm, ("|" <Arm>)+ => ActionFn(362);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action362::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            136 => {
+                // Let<"Open"> = FixityDecl, "in", Seq<"Open"> => ActionFn(363);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22in_22(__symbols);
+                let __sym0 = __pop_NtFixityDecl(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action363::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            137 => {
+                // Let<"Open"> = If<"Open"> => ActionFn(108);
+                let __sym0 = __pop_NtIf_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action108::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            138 => {
+                // Name = Id => ActionFn(58);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action58::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtName(__nt), __end));
+                55
+            }
+            139 => {
+                // Name = "(", Sym, ")" => ActionFn(59);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSym(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtName(__nt), __end));
+                55
+            }
+            140 => {
+                // Num = Digits => ActionFn(364);
+                let __sym0 = __pop_NtDigits(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action364::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                56
+            }
+            141 => {
+                // Operator = BuiltinOp => ActionFn(365);
+                let __sym0 = __pop_NtBuiltinOp(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action365::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOperator(__nt), __end));
+                57
+            }
+            142 => {
+                // Operator = Sym => ActionFn(366);
+                let __sym0 = __pop_NtSym(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action366::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOperator(__nt), __end));
+                57
+            }
+            143 => {
+                // Pattern = Id, AtomPattern+ => ActionFn(367);
+                let __sym1 = __pop_NtAtomPattern_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action367::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtPattern(__nt), __end));
+                58
+            }
+            144 => {
+                // Pattern = AtomPattern => ActionFn(9);
+                let __sym0 = __pop_NtAtomPattern(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtPattern(__nt), __end));
+                58
+            }
+            145 => {
+                // RecBinding = Name, "=", Seq<"Open"> => ActionFn(31);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtName(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtRecBinding(__nt), __end));
+                59
+            }
+            146 => {
+                // Seq<"Closed"> = AssignExpr, ";", Seq<"Closed"> => ActionFn(368);
+                let __sym2 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action368::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Closed_22_3e(__nt), __end));
+                60
+            }
+            147 => {
+                // Seq<"Closed"> = Let<"Closed"> => ActionFn(95);
+                let __sym0 = __pop_NtLet_3c_22Closed_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action95::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Closed_22_3e(__nt), __end));
+                60
+            }
+            148 => {
+                // Seq<"Open"> = AssignExpr, ";", Seq<"Open"> => ActionFn(369);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action369::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Open_22_3e(__nt), __end));
+                61
+            }
+            149 => {
+                // Seq<"Open"> = Let<"Open"> => ActionFn(97);
+                let __sym0 = __pop_NtLet_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action97::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Open_22_3e(__nt), __end));
+                61
+            }
+            150 => {
+                // SpannedType = Type => ActionFn(370);
+                let __sym0 = __pop_NtType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action370::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSpannedType(__nt), __end));
+                62
+            }
+            151 => {
+                // Str = r#"\"(\\\\.|[^\"\\\\])*\""# => ActionFn(56);
+                let __sym0 = __pop_Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action56::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                63
+            }
+            152 => {
+                // Sym = r#"[-+*/=<|&^@$%~?][-+*/=<>|&^@$%~?!.:]*"# => ActionFn(61);
+                let __sym0 = __pop_Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action61::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSym(__nt), __end));
+                64
+            }
+            153 => {
+                // Term<"Any"> = Num => ActionFn(371);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action371::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            154 => {
+                // Term<"Any"> = Float => ActionFn(372);
+                let __sym0 = __pop_NtFloat(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action372::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            155 => {
+                // Term<"Any"> = Str => ActionFn(373);
+                let __sym0 = __pop_NtStr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action373::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            156 => {
+                // Term<"Any"> = Char => ActionFn(374);
+                let __sym0 = __pop_NtChar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action374::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            157 => {
+                // Term<"Any"> = Id => ActionFn(375);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action375::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            158 => {
+                // Term<"Any"> = Bool => ActionFn(376);
+                let __sym0 = __pop_NtBool(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action376::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            159 => {
+                // Term<"Any"> = "(", Seq<"Open">, ("," <Seq<"Open">>)+, ")" => ActionFn(377);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action377::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            160 => {
+                // Term<"Any"> = "{", FieldInit, "}" => ActionFn(378);
+                let __sym2 = __pop_Term_22_7d_22(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action378::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            161 => {
+                // Term<"Any"> = "{", FieldInit, ("," <FieldInit>)+, "}" => ActionFn(379);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action379::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            162 => {
+                // Term<"Any"> = "{", "}" => ActionFn(380);
+                let __sym1 = __pop_Term_22_7d_22(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action380::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            163 => {
+                // Term<"Any"> = "(", Seq<"Open">, ":", SpannedType, ")" => ActionFn(381);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_NtSpannedType(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action381::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            164 => {
+                // Term<"Any"> = "(", ")" => ActionFn(382);
+                let __sym1 = __pop_Term_22_29_22(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action382::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            165 => {
+                // Term<"Any"> = "!", Term<"Any"> => ActionFn(383);
+                let __sym1 = __pop_NtTerm_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_21_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action383::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            166 => {
+                // Term<"Any"> = "(", Sym, ")" => ActionFn(384);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSym(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action384::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            167 => {
+                // Term<"Any"> = "(", Seq<"Open">, ")" => ActionFn(170);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            168 => {
+                // Term<"NoNum"> = Float => ActionFn(385);
+                let __sym0 = __pop_NtFloat(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action385::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            169 => {
+                // Term<"NoNum"> = Str => ActionFn(386);
+                let __sym0 = __pop_NtStr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action386::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            170 => {
+                // Term<"NoNum"> = Char => ActionFn(387);
+                let __sym0 = __pop_NtChar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action387::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            171 => {
+                // Term<"NoNum"> = Id => ActionFn(388);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action388::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            172 => {
+                // Term<"NoNum"> = Bool => ActionFn(389);
+                let __sym0 = __pop_NtBool(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action389::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            173 => {
+                // Term<"NoNum"> = "(", Seq<"Open">, ("," <Seq<"Open">>)+, ")" => ActionFn(390);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action390::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            174 => {
+                // Term<"NoNum"> = "{", FieldInit, "}" => ActionFn(391);
+                let __sym2 = __pop_Term_22_7d_22(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action391::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            175 => {
+                // Term<"NoNum"> = "{", FieldInit, ("," <FieldInit>)+, "}" => ActionFn(392);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action392::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            176 => {
+                // Term<"NoNum"> = "{", "}" => ActionFn(393);
+                let __sym1 = __pop_Term_22_7d_22(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action393::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            177 => {
+                // Term<"NoNum"> = "(", Seq<"Open">, ":", SpannedType, ")" => ActionFn(394);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_NtSpannedType(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action394::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            178 => {
+                // Term<"NoNum"> = "(", ")" => ActionFn(395);
+                let __sym1 = __pop_Term_22_29_22(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action395::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            179 => {
+                // Term<"NoNum"> = "!", Term<"Any"> => ActionFn(396);
+                let __sym1 = __pop_NtTerm_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_21_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action396::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            180 => {
+                // Term<"NoNum"> = "(", Sym, ")" => ActionFn(397);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSym(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action397::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            181 => {
+                // Term<"NoNum"> = "(", Seq<"Open">, ")" => ActionFn(185);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action185::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            182 => {
+                // TupleType = AppType, ("*" <AppType>)+ => ActionFn(25);
+                let __sym1 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym0 = __pop_NtAppType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action25::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTupleType(__nt), __end));
+                67
+            }
+            183 => {
+                // TupleType = AppType => ActionFn(26);
+                let __sym0 = __pop_NtAppType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action26::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTupleType(__nt), __end));
+                67
+            }
+            184 => {
+                // Type = TupleType, "->", Type => ActionFn(23);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym0 = __pop_NtTupleType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action23::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtType(__nt), __end));
+                68
+            }
+            185 => {
+                // Type = TupleType => ActionFn(24);
+                let __sym0 = __pop_NtTupleType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action24::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtType(__nt), __end));
+                68
+            }
+            186 => {
+                // TypeAlias = Id, "=", Type => ActionFn(404);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action404::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTypeAlias(__nt), __end));
+                69
+            }
+            187 => {
+                // TypeAlias = Id, TypeVar+, "=", Type => ActionFn(405);
+                let __sym3 = __pop_NtType(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action405::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTypeAlias(__nt), __end));
+                69
+            }
+            188 => {
+                // TypeDecl = Id, "=", "|", CtorDecl => ActionFn(406);
+                let __sym3 = __pop_NtCtorDecl(__symbols);
+                let __sym2 = __pop_Term_22_7c_22(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action406::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            189 => {
+                // TypeDecl = Id, TypeVar+, "=", "|", CtorDecl => ActionFn(407);
+                let __sym4 = __pop_NtCtorDecl(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action407::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            190 => {
+                // TypeDecl = Id, "=", "|", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(408);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtCtorDecl(__symbols);
+                let __sym2 = __pop_Term_22_7c_22(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action408::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            191 => {
+                // TypeDecl = Id, TypeVar+, "=", "|", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(409);
+                let __sym5 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtCtorDecl(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action409::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            192 => {
+                // TypeDecl = Id, "=", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(410);
+                let __sym3 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtCtorDecl(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action410::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            193 => {
+                // TypeDecl = Id, TypeVar+, "=", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(411);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtCtorDecl(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action411::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            194 => {
+                // TypeDecl = Id, "=", Id, "of", AppType => ActionFn(412);
+                let __sym4 = __pop_NtAppType(__symbols);
+                let __sym3 = __pop_Term_22of_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action412::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            195 => {
+                // TypeDecl = Id, TypeVar+, "=", Id, "of", AppType => ActionFn(413);
+                let __sym5 = __pop_NtAppType(__symbols);
+                let __sym4 = __pop_Term_22of_22(__symbols);
+                let __sym3 = __pop_NtId(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action413::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            196 => {
+                // TypeDecl = Id, "=", Id, "of", AppType, ("*" <AppType>)+ => ActionFn(414);
+                let __sym5 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtAppType(__symbols);
+                let __sym3 = __pop_Term_22of_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action414::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            197 => {
+                // TypeDecl = Id, TypeVar+, "=", Id, "of", AppType, ("*" <AppType>)+ => ActionFn(415);
+                let __sym6 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym5 = __pop_NtAppType(__symbols);
+                let __sym4 = __pop_Term_22of_22(__symbols);
+                let __sym3 = __pop_NtId(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym6.2.clone();
+                let __nt = super::__action415::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 7);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            198 => {
+                // TypeVar = r#"'[a-z][a-zA-Z0-9_]*"# => ActionFn(62);
+                let __sym0 = __pop_Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action62::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTypeVar(__nt), __end));
+                71
+            }
+            199 => {
+                // TypeVar* =  => ActionFn(85);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action85::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtTypeVar_2a(__nt), __end));
+                72
+            }
+            200 => {
+                // TypeVar* = TypeVar+ => ActionFn(86);
+                let __sym0 = __pop_NtTypeVar_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action86::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTypeVar_2a(__nt), __end));
+                72
+            }
+            201 => {
+                // TypeVar+ = TypeVar => ActionFn(118);
+                let __sym0 = __pop_NtTypeVar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action118::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTypeVar_2b(__nt), __end));
+                73
+            }
+            202 => {
+                // TypeVar+ = TypeVar+, TypeVar => ActionFn(119);
+                let __sym1 = __pop_NtTypeVar(__symbols);
+                let __sym0 = __pop_NtTypeVar_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action119::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTypeVar_2b(__nt), __end));
+                73
+            }
+            203 => {
+                // Unary<"Any"> = "-", Digits => ActionFn(398);
+                let __sym1 = __pop_NtDigits(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action398::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            204 => {
+                // Unary<"Any"> = "-", Unary<"NoNum"> => ActionFn(399);
+                let __sym1 = __pop_NtUnary_3c_22NoNum_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action399::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            205 => {
+                // Unary<"Any"> = "not", Unary<"Any"> => ActionFn(400);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action400::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            206 => {
+                // Unary<"Any"> = App<"Any"> => ActionFn(71);
+                let __sym0 = __pop_NtApp_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action71::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            207 => {
+                // Unary<"NoNum"> = "-", Digits => ActionFn(401);
+                let __sym1 = __pop_NtDigits(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action401::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            208 => {
+                // Unary<"NoNum"> = "-", Unary<"NoNum"> => ActionFn(402);
+                let __sym1 = __pop_NtUnary_3c_22NoNum_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action402::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            209 => {
+                // Unary<"NoNum"> = "not", Unary<"Any"> => ActionFn(403);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action403::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            210 => {
+                // Unary<"NoNum"> = App<"NoNum"> => ActionFn(128);
+                let __sym0 = __pop_NtApp_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action128::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            211 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            212 => {
+                // __Type = Type => ActionFn(1);
+                let __sym0 = __pop_NtType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____Type(__nt), __end));
+                77
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 78 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Term_22_21_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_21_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_26_26_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_26_26_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2f_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22and_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22and_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22else_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22else_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22exception_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22exception_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22false_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22false_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22fun_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22fun_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22if_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22if_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22in_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22in_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22infix_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22infix_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22infixl_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22infixl_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22infixr_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22infixr_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22let_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22letrec_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22letrec_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22match_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22match_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22mod_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22mod_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22not_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22not_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22of_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22of_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22raise_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22raise_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22then_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22then_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22true_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22true_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22try_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22try_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22type_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22type_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22with_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22with_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7c_7c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7c_7c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_22_7c_22_3f<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::option::Option<&'input str>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_22_7c_22_3f(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2a_22_20_3cAppType_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cId_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cPattern_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<Pattern>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cPattern_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Spanned<Pattern>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cType_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cType_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22and_22_20_3cRecBinding_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cArm_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<Pattern>, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Vec<TypeExpr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<String>, Box<Spanned<Expr>>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAccess_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAccess_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAccess_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAccess_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtApp_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtApp_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtApp_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtApp_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAppType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAppType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtArm<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<Pattern>, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtArm(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAssignExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAssignExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAssoc<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Assoc, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAssoc(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAtomPattern<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<Pattern>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAtomPattern(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAtomPattern_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Spanned<Pattern>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAtomPattern_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBool<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBool(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBuiltinOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBuiltinOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtChar<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, char, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtChar(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtCtorDecl<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Vec<TypeExpr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtCtorDecl(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtDigits<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtDigits(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtFieldInit<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtFieldInit(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtFixityDecl<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, FixityDecl, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtFixityDecl(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtFloat<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, f64, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtFloat(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtId<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtId(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtIf_3c_22Closed_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtIf_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtIf_3c_22Open_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtIf_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtInfixExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtInfixExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtLambda_3c_22Closed_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtLambda_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtLambda_3c_22Open_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtLambda_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtLet_3c_22Closed_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtLet_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtLet_3c_22Open_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtLet_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtName<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtName(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtOperator<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtOperator(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtPattern<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<Pattern>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtPattern(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtRecBinding<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtRecBinding(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSeq_3c_22Closed_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSeq_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSeq_3c_22Open_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSeq_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSpannedType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSpannedType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSym<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSym(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTupleType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTupleType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeAlias<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeAlias, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeAlias(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeDecl<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeDecl, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeDecl(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeVar<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeVar(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeVar_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeVar_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeVar_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeVar_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtUnary_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtUnary_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtUnary_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtUnary_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Type<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Type(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Expr::parse_Expr;
+
+mod __parse__Type {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports)]
+
+    use std::str::FromStr;
+    use lalrpop_util::ParseError;
+    use ast::{unescape, Assoc, Expr, ExceptionDecl, FixityDecl, UnOp, Pattern, Spanned, TypeExpr, TypeDecl, TypeAlias};
+    use fixity;
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input> {
+        Term_22_21_22(&'input str),
+        Term_22_21_3d_22(&'input str),
+        Term_22_26_26_22(&'input str),
+        Term_22_28_22(&'input str),
+        Term_22_29_22(&'input str),
+        Term_22_2a_22(&'input str),
+        Term_22_2b_22(&'input str),
+        Term_22_2c_22(&'input str),
+        Term_22_2d_22(&'input str),
+        Term_22_2d_3e_22(&'input str),
+        Term_22_2e_22(&'input str),
+        Term_22_2f_22(&'input str),
+        Term_22_3a_22(&'input str),
+        Term_22_3a_3d_22(&'input str),
+        Term_22_3b_22(&'input str),
+        Term_22_3c_22(&'input str),
+        Term_22_3c_3d_22(&'input str),
+        Term_22_3d_22(&'input str),
+        Term_22_3e_22(&'input str),
+        Term_22_3e_3d_22(&'input str),
+        Term_22_5e_22(&'input str),
+        Term_22and_22(&'input str),
+        Term_22else_22(&'input str),
+        Term_22exception_22(&'input str),
+        Term_22false_22(&'input str),
+        Term_22fun_22(&'input str),
+        Term_22if_22(&'input str),
+        Term_22in_22(&'input str),
+        Term_22infix_22(&'input str),
+        Term_22infixl_22(&'input str),
+        Term_22infixr_22(&'input str),
+        Term_22let_22(&'input str),
+        Term_22letrec_22(&'input str),
+        Term_22match_22(&'input str),
+        Term_22mod_22(&'input str),
+        Term_22not_22(&'input str),
+        Term_22of_22(&'input str),
+        Term_22raise_22(&'input str),
+        Term_22then_22(&'input str),
+        Term_22true_22(&'input str),
+        Term_22try_22(&'input str),
+        Term_22type_22(&'input str),
+        Term_22with_22(&'input str),
+        Term_22_7b_22(&'input str),
+        Term_22_7c_22(&'input str),
+        Term_22_7c_7c_22(&'input str),
+        Term_22_7d_22(&'input str),
+        Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23(&'input str),
+        Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23(&'input str),
+        Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(&'input str),
+        Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(&'input str),
+        Nt_22_7c_22_3f(::std::option::Option<&'input str>),
+        Nt_28_22_2a_22_20_3cAppType_3e_29(TypeExpr),
+        Nt_28_22_2a_22_20_3cAppType_3e_29_2a(::std::vec::Vec<TypeExpr>),
+        Nt_28_22_2a_22_20_3cAppType_3e_29_2b(::std::vec::Vec<TypeExpr>),
+        Nt_28_22_2c_22_20_3cFieldInit_3e_29((String, Spanned<Expr>)),
+        Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(::std::vec::Vec<(String, Spanned<Expr>)>),
+        Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(::std::vec::Vec<(String, Spanned<Expr>)>),
+        Nt_28_22_2c_22_20_3cId_3e_29(String),
+        Nt_28_22_2c_22_20_3cId_3e_29_2b(::std::vec::Vec<String>),
+        Nt_28_22_2c_22_20_3cPattern_3e_29(Spanned<Pattern>),
+        Nt_28_22_2c_22_20_3cPattern_3e_29_2b(::std::vec::Vec<Spanned<Pattern>>),
+        Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29(Box<Spanned<Expr>>),
+        Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(::std::vec::Vec<Box<Spanned<Expr>>>),
+        Nt_28_22_2c_22_20_3cType_3e_29(TypeExpr),
+        Nt_28_22_2c_22_20_3cType_3e_29_2a(::std::vec::Vec<TypeExpr>),
+        Nt_28_22_2c_22_20_3cType_3e_29_2b(::std::vec::Vec<TypeExpr>),
+        Nt_28_22and_22_20_3cRecBinding_3e_29((String, Spanned<Expr>)),
+        Nt_28_22and_22_20_3cRecBinding_3e_29_2b(::std::vec::Vec<(String, Spanned<Expr>)>),
+        Nt_28_22_7c_22_20_3cArm_3e_29((Spanned<Pattern>, Spanned<Expr>)),
+        Nt_28_22_7c_22_20_3cArm_3e_29_2a(::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>),
+        Nt_28_22_7c_22_20_3cArm_3e_29_2b(::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>),
+        Nt_28_22_7c_22_20_3cCtorDecl_3e_29((String, Vec<TypeExpr>)),
+        Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(::std::vec::Vec<(String, Vec<TypeExpr>)>),
+        Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(::std::vec::Vec<(String, Vec<TypeExpr>)>),
+        Nt_28Operator_20Unary_3c_22Any_22_3e_29((Spanned<String>, Box<Spanned<Expr>>)),
+        Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtAccess_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtAccess_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        NtApp_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtApp_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        NtAppType(TypeExpr),
+        NtArm((Spanned<Pattern>, Spanned<Expr>)),
+        NtAssignExpr(Box<Spanned<Expr>>),
+        NtAssoc(Assoc),
+        NtAtomPattern(Spanned<Pattern>),
+        NtAtomPattern_2b(::std::vec::Vec<Spanned<Pattern>>),
+        NtBool(bool),
+        NtBuiltinOp(&'static str),
+        NtChar(char),
+        NtCtorDecl((String, Vec<TypeExpr>)),
+        NtDigits(&'input str),
+        NtExpr(Box<Spanned<Expr>>),
+        NtFieldInit((String, Spanned<Expr>)),
+        NtFixityDecl(FixityDecl),
+        NtFloat(f64),
+        NtId(String),
+        NtIf_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtIf_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtInfixExpr(Box<Spanned<Expr>>),
+        NtLambda_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtLambda_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtLet_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtLet_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtName(String),
+        NtNum(i32),
+        NtOperator(Spanned<String>),
+        NtPattern(Spanned<Pattern>),
+        NtRecBinding((String, Spanned<Expr>)),
+        NtSeq_3c_22Closed_22_3e(Box<Spanned<Expr>>),
+        NtSeq_3c_22Open_22_3e(Box<Spanned<Expr>>),
+        NtSpannedType(Spanned<TypeExpr>),
+        NtStr(String),
+        NtSym(String),
+        NtTerm_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtTerm_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        NtTupleType(TypeExpr),
+        NtType(TypeExpr),
+        NtTypeAlias(TypeAlias),
+        NtTypeDecl(TypeDecl),
+        NtTypeVar(String),
+        NtTypeVar_2a(::std::vec::Vec<String>),
+        NtTypeVar_2b(::std::vec::Vec<String>),
+        NtUnary_3c_22Any_22_3e(Box<Spanned<Expr>>),
+        NtUnary_3c_22NoNum_22_3e(Box<Spanned<Expr>>),
+        Nt____Expr(Box<Spanned<Expr>>),
+        Nt____Type(TypeExpr),
+    }
+    const __ACTION: &'static [i32] = &[
+        // State 0
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 1
+        0, 0, 0, 0, -183, 11, 0, -183, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, -58, -58, 0, -58, 0, -58, 0, 0, 0, 0, 0, 12, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, -185, 0, 0, -185, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212, -212,
+        // State 5
+        -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57,
+        // State 6
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 7
+        -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198, -198,
+        // State 8
+        -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99,
+        // State 9
+        0, 0, 0, 0, -182, 15, 0, -182, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, -182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 11
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 12
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 13
+        0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 15
+        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184, -184,
+        // State 18
+        -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59,
+        // State 19
+        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 22
+        -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55,
+        // State 23
+        0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 9,
+        // State 24
+        -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56,
+        // State 25
+        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        // State 26
+        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+    ];
+    const __EOF_ACTION: &'static [i32] = &[
+        0,
+        -183,
+        -58,
+        -185,
+        -212,
+        -57,
+        0,
+        -198,
+        -99,
+        -182,
+        0,
+        0,
+        0,
+        0,
+        0,
+        -6,
+        0,
+        -184,
+        -59,
+        -7,
+        0,
+        0,
+        -55,
+        0,
+        -56,
+        -25,
+        -26,
+    ];
+    const __GOTO: &'static [i32] = &[
+        // State 0
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 1
+        0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 14, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 17, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 18, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 26, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 27, 0, 0, 6, 0, 0, 0, 0, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""!""###,
+            r###""!=""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###"",""###,
+            r###""-""###,
+            r###""->""###,
+            r###"".""###,
+            r###""/""###,
+            r###"":""###,
+            r###"":=""###,
+            r###"";""###,
+            r###""<""###,
+            r###""<=""###,
+            r###""=""###,
+            r###"">""###,
+            r###"">=""###,
+            r###""^""###,
+            r###""and""###,
+            r###""else""###,
+            r###""exception""###,
+            r###""false""###,
+            r###""fun""###,
+            r###""if""###,
+            r###""in""###,
+            r###""infix""###,
+            r###""infixl""###,
+            r###""infixr""###,
+            r###""let""###,
+            r###""letrec""###,
+            r###""match""###,
+            r###""mod""###,
+            r###""not""###,
+            r###""of""###,
+            r###""raise""###,
+            r###""then""###,
+            r###""true""###,
+            r###""try""###,
+            r###""type""###,
+            r###""with""###,
+            r###""{""###,
+            r###""|""###,
+            r###""||""###,
+            r###""}""###,
+            r###"r#"\"(\\\\.|[^\"\\\\])*\""#"###,
+            r###"r#"'(\\\\.|[^'\\\\])'"#"###,
+            r###"r#"'[a-z][a-zA-Z0-9_]*"#"###,
+            r###"r#"[-+*/=<|&^@$%~?][-+*/=<>|&^@$%~?!.:]*"#"###,
+            r###"r#"[0-9]+"#"###,
+            r###"r#"[0-9]+\\.[0-9]+"#"###,
+            r###"r#"[a-zA-Z_][a-zA-Z0-9_]*"#"###,
+        ];
+        __ACTION[(__state * 54)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    pub fn parse_Type<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<TypeExpr, __lalrpop_util::ParseError<usize, (usize, &'input str), fixity::Error>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![0_i32];
+        let mut __symbols = vec![];
+        let mut __integer;
+        let mut __lookahead;
+        let mut __last_location = Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            __last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                (7, _) if true => 0,
+                (8, _) if true => 1,
+                (9, _) if true => 2,
+                (10, _) if true => 3,
+                (11, _) if true => 4,
+                (12, _) if true => 5,
+                (13, _) if true => 6,
+                (14, _) if true => 7,
+                (15, _) if true => 8,
+                (16, _) if true => 9,
+                (17, _) if true => 10,
+                (18, _) if true => 11,
+                (19, _) if true => 12,
+                (20, _) if true => 13,
+                (21, _) if true => 14,
+                (22, _) if true => 15,
+                (23, _) if true => 16,
+                (24, _) if true => 17,
+                (25, _) if true => 18,
+                (26, _) if true => 19,
+                (27, _) if true => 20,
+                (28, _) if true => 21,
+                (29, _) if true => 22,
+                (30, _) if true => 23,
+                (31, _) if true => 24,
+                (32, _) if true => 25,
+                (33, _) if true => 26,
+                (34, _) if true => 27,
+                (35, _) if true => 28,
+                (36, _) if true => 29,
+                (37, _) if true => 30,
+                (38, _) if true => 31,
+                (39, _) if true => 32,
+                (40, _) if true => 33,
+                (41, _) if true => 34,
+                (42, _) if true => 35,
+                (43, _) if true => 36,
+                (44, _) if true => 37,
+                (45, _) if true => 38,
+                (46, _) if true => 39,
+                (47, _) if true => 40,
+                (48, _) if true => 41,
+                (49, _) if true => 42,
+                (50, _) if true => 43,
+                (51, _) if true => 44,
+                (52, _) if true => 45,
+                (53, _) if true => 46,
+                (0, _) if true => 47,
+                (1, _) if true => 48,
+                (2, _) if true => 49,
+                (3, _) if true => 50,
+                (4, _) if true => 51,
+                (5, _) if true => 52,
+                (6, _) if true => 53,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 54 + __integer];
+                if __action > 0 {
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            (7, __tok0) => __Symbol::Term_22_21_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            (8, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            (9, __tok0) => __Symbol::Term_22_26_26_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            (10, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            (11, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            (12, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        6 => match __lookahead.1 {
+                            (13, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        7 => match __lookahead.1 {
+                            (14, __tok0) => __Symbol::Term_22_2c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        8 => match __lookahead.1 {
+                            (15, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        9 => match __lookahead.1 {
+                            (16, __tok0) => __Symbol::Term_22_2d_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        10 => match __lookahead.1 {
+                            (17, __tok0) => __Symbol::Term_22_2e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        11 => match __lookahead.1 {
+                            (18, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        12 => match __lookahead.1 {
+                            (19, __tok0) => __Symbol::Term_22_3a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        13 => match __lookahead.1 {
+                            (20, __tok0) => __Symbol::Term_22_3a_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        14 => match __lookahead.1 {
+                            (21, __tok0) => __Symbol::Term_22_3b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        15 => match __lookahead.1 {
+                            (22, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        16 => match __lookahead.1 {
+                            (23, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        17 => match __lookahead.1 {
+                            (24, __tok0) => __Symbol::Term_22_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        18 => match __lookahead.1 {
+                            (25, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        19 => match __lookahead.1 {
+                            (26, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        20 => match __lookahead.1 {
+                            (27, __tok0) => __Symbol::Term_22_5e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        21 => match __lookahead.1 {
+                            (28, __tok0) => __Symbol::Term_22and_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        22 => match __lookahead.1 {
+                            (29, __tok0) => __Symbol::Term_22else_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        23 => match __lookahead.1 {
+                            (30, __tok0) => __Symbol::Term_22exception_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        24 => match __lookahead.1 {
+                            (31, __tok0) => __Symbol::Term_22false_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        25 => match __lookahead.1 {
+                            (32, __tok0) => __Symbol::Term_22fun_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (33, __tok0) => __Symbol::Term_22if_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (34, __tok0) => __Symbol::Term_22in_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (35, __tok0) => __Symbol::Term_22infix_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        29 => match __lookahead.1 {
+                            (36, __tok0) => __Symbol::Term_22infixl_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        30 => match __lookahead.1 {
+                            (37, __tok0) => __Symbol::Term_22infixr_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        31 => match __lookahead.1 {
+                            (38, __tok0) => __Symbol::Term_22let_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        32 => match __lookahead.1 {
+                            (39, __tok0) => __Symbol::Term_22letrec_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        33 => match __lookahead.1 {
+                            (40, __tok0) => __Symbol::Term_22match_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        34 => match __lookahead.1 {
+                            (41, __tok0) => __Symbol::Term_22mod_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        35 => match __lookahead.1 {
+                            (42, __tok0) => __Symbol::Term_22not_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        36 => match __lookahead.1 {
+                            (43, __tok0) => __Symbol::Term_22of_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        37 => match __lookahead.1 {
+                            (44, __tok0) => __Symbol::Term_22raise_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        38 => match __lookahead.1 {
+                            (45, __tok0) => __Symbol::Term_22then_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        39 => match __lookahead.1 {
+                            (46, __tok0) => __Symbol::Term_22true_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        40 => match __lookahead.1 {
+                            (47, __tok0) => __Symbol::Term_22try_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        41 => match __lookahead.1 {
+                            (48, __tok0) => __Symbol::Term_22type_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        42 => match __lookahead.1 {
+                            (49, __tok0) => __Symbol::Term_22with_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        43 => match __lookahead.1 {
+                            (50, __tok0) => __Symbol::Term_22_7b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        44 => match __lookahead.1 {
+                            (51, __tok0) => __Symbol::Term_22_7c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        45 => match __lookahead.1 {
+                            (52, __tok0) => __Symbol::Term_22_7c_7c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        46 => match __lookahead.1 {
+                            (53, __tok0) => __Symbol::Term_22_7d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        47 => match __lookahead.1 {
+                            (0, __tok0) => __Symbol::Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        48 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        49 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        50 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        51 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        52 => match __lookahead.1 {
+                            (5, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        53 => match __lookahead.1 {
+                            (6, __tok0) => __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state];
+            if __action < 0 {
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let __state = *__states.last().unwrap() as usize;
+                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                    token: None,
+                    expected: __expected_tokens(__state),
+                };
+                return Err(__error);
+            }
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<TypeExpr,__lalrpop_util::ParseError<usize, (usize, &'input str), fixity::Error>>>
+    {
+        let __nonterminal = match -__action {
+            1 => {
+                // "|"? = "|" => ActionFn(141);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action141::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_22_7c_22_3f(__nt), __end));
+                0
+            }
+            2 => {
+                // "|"? =  => ActionFn(142);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action142::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_22_7c_22_3f(__nt), __end));
+                0
+            }
+            3 => {
+                // ("*" <AppType>) = "*", AppType => ActionFn(79);
+                let __sym1 = __pop_NtAppType(__symbols);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action79::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29(__nt), __end));
+                1
+            }
+            4 => {
+                // ("*" <AppType>)* =  => ActionFn(77);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action77::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2a(__nt), __end));
+                2
+            }
+            5 => {
+                // ("*" <AppType>)* = ("*" <AppType>)+ => ActionFn(78);
+                let __sym0 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action78::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2a(__nt), __end));
+                2
+            }
+            6 => {
+                // ("*" <AppType>)+ = "*", AppType => ActionFn(198);
+                let __sym1 = __pop_NtAppType(__symbols);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action198::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__nt), __end));
+                3
+            }
+            7 => {
+                // ("*" <AppType>)+ = ("*" <AppType>)+, "*", AppType => ActionFn(199);
+                let __sym2 = __pop_NtAppType(__symbols);
+                let __sym1 = __pop_Term_22_2a_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action199::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__nt), __end));
+                3
+            }
+            8 => {
+                // ("," <FieldInit>) = ",", FieldInit => ActionFn(188);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action188::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29(__nt), __end));
+                4
+            }
+            9 => {
+                // ("," <FieldInit>)* =  => ActionFn(186);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action186::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(__nt), __end));
+                5
+            }
+            10 => {
+                // ("," <FieldInit>)* = ("," <FieldInit>)+ => ActionFn(187);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action187::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(__nt), __end));
+                5
+            }
+            11 => {
+                // ("," <FieldInit>)+ = ",", FieldInit => ActionFn(204);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action204::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__nt), __end));
+                6
+            }
+            12 => {
+                // ("," <FieldInit>)+ = ("," <FieldInit>)+, ",", FieldInit => ActionFn(205);
+                let __sym2 = __pop_NtFieldInit(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action205::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__nt), __end));
+                6
+            }
+            13 => {
+                // ("," <Id>) = ",", Id => ActionFn(148);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action148::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29(__nt), __end));
+                7
+            }
+            14 => {
+                // ("," <Id>)+ = ",", Id => ActionFn(210);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action210::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29_2b(__nt), __end));
+                8
+            }
+            15 => {
+                // ("," <Id>)+ = ("," <Id>)+, ",", Id => ActionFn(211);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action211::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29_2b(__nt), __end));
+                8
+            }
+            16 => {
+                // ("," <Pattern>) = ",", Pattern => ActionFn(89);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action89::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29(__nt), __end));
+                9
+            }
+            17 => {
+                // ("," <Pattern>)+ = ",", Pattern => ActionFn(212);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action212::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__nt), __end));
+                10
+            }
+            18 => {
+                // ("," <Pattern>)+ = ("," <Pattern>)+, ",", Pattern => ActionFn(213);
+                let __sym2 = __pop_NtPattern(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action213::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__nt), __end));
+                10
+            }
+            19 => {
+                // ("," <Seq<"Open">>) = ",", Seq<"Open"> => ActionFn(191);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action191::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29(__nt), __end));
+                11
+            }
+            20 => {
+                // ("," <Seq<"Open">>)+ = ",", Seq<"Open"> => ActionFn(214);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action214::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__nt), __end));
+                12
+            }
+            21 => {
+                // ("," <Seq<"Open">>)+ = ("," <Seq<"Open">>)+, ",", Seq<"Open"> => ActionFn(215);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action215::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__nt), __end));
+                12
+            }
+            22 => {
+                // ("," <Type>) = ",", Type => ActionFn(74);
+                let __sym1 = __pop_NtType(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action74::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29(__nt), __end));
+                13
+            }
+            23 => {
+                // ("," <Type>)* =  => ActionFn(72);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action72::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2a(__nt), __end));
+                14
+            }
+            24 => {
+                // ("," <Type>)* = ("," <Type>)+ => ActionFn(73);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action73::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2a(__nt), __end));
+                14
+            }
+            25 => {
+                // ("," <Type>)+ = ",", Type => ActionFn(216);
+                let __sym1 = __pop_NtType(__symbols);
+                let __sym0 = __pop_Term_22_2c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action216::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2b(__nt), __end));
+                15
+            }
+            26 => {
+                // ("," <Type>)+ = ("," <Type>)+, ",", Type => ActionFn(217);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_2c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action217::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2b(__nt), __end));
+                15
+            }
+            27 => {
+                // ("and" <RecBinding>) = "and", RecBinding => ActionFn(145);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22and_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action145::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29(__nt), __end));
+                16
+            }
+            28 => {
+                // ("and" <RecBinding>)+ = "and", RecBinding => ActionFn(220);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22and_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action220::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__nt), __end));
+                17
+            }
+            29 => {
+                // ("and" <RecBinding>)+ = ("and" <RecBinding>)+, "and", RecBinding => ActionFn(221);
+                let __sym2 = __pop_NtRecBinding(__symbols);
+                let __sym1 = __pop_Term_22and_22(__symbols);
+                let __sym0 = __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action221::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__nt), __end));
+                17
+            }
+            30 => {
+                // ("|" <Arm>) = "|", Arm => ActionFn(140);
+                let __sym1 = __pop_NtArm(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action140::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29(__nt), __end));
+                18
+            }
+            31 => {
+                // ("|" <Arm>)* =  => ActionFn(138);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action138::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2a(__nt), __end));
+                19
+            }
+            32 => {
+                // ("|" <Arm>)* = ("|" <Arm>)+ => ActionFn(139);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action139::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2a(__nt), __end));
+                19
+            }
+            33 => {
+                // ("|" <Arm>)+ = "|", Arm => ActionFn(222);
+                let __sym1 = __pop_NtArm(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action222::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2b(__nt), __end));
+                20
+            }
+            34 => {
+                // ("|" <Arm>)+ = ("|" <Arm>)+, "|", Arm => ActionFn(223);
+                let __sym2 = __pop_NtArm(__symbols);
+                let __sym1 = __pop_Term_22_7c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action223::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2b(__nt), __end));
+                20
+            }
+            35 => {
+                // ("|" <CtorDecl>) = "|", CtorDecl => ActionFn(84);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action84::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29(__nt), __end));
+                21
+            }
+            36 => {
+                // ("|" <CtorDecl>)* =  => ActionFn(82);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action82::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(__nt), __end));
+                22
+            }
+            37 => {
+                // ("|" <CtorDecl>)* = ("|" <CtorDecl>)+ => ActionFn(83);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action83::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(__nt), __end));
+                22
+            }
+            38 => {
+                // ("|" <CtorDecl>)+ = "|", CtorDecl => ActionFn(232);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action232::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__nt), __end));
+                23
+            }
+            39 => {
+                // ("|" <CtorDecl>)+ = ("|" <CtorDecl>)+, "|", CtorDecl => ActionFn(233);
+                let __sym2 = __pop_NtCtorDecl(__symbols);
+                let __sym1 = __pop_Term_22_7c_22(__symbols);
+                let __sym0 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action233::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__nt), __end));
+                23
+            }
+            40 => {
+                // (Operator Unary<"Any">) = Operator, Unary<"Any"> => ActionFn(67);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtOperator(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action67::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29(__nt), __end));
+                24
+            }
+            41 => {
+                // (Operator Unary<"Any">)+ = Operator, Unary<"Any"> => ActionFn(236);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtOperator(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action236::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__nt), __end));
+                25
+            }
+            42 => {
+                // (Operator Unary<"Any">)+ = (Operator Unary<"Any">)+, Operator, Unary<"Any"> => ActionFn(237);
+                let __sym2 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym1 = __pop_NtOperator(__symbols);
+                let __sym0 = __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action237::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__nt), __end));
+                25
+            }
+            43 => {
+                // @L =  => ActionFn(93);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action93::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                26
+            }
+            44 => {
+                // @R =  => ActionFn(90);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action90::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                27
+            }
+            45 => {
+                // Access<"Any"> = Access<"Any">, ".", Id => ActionFn(321);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action321::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAccess_3c_22Any_22_3e(__nt), __end));
+                28
+            }
+            46 => {
+                // Access<"Any"> = Term<"Any"> => ActionFn(133);
+                let __sym0 = __pop_NtTerm_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action133::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAccess_3c_22Any_22_3e(__nt), __end));
+                28
+            }
+            47 => {
+                // Access<"NoNum"> = Access<"NoNum">, ".", Id => ActionFn(322);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_2e_22(__symbols);
+                let __sym0 = __pop_NtAccess_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action322::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAccess_3c_22NoNum_22_3e(__nt), __end));
+                29
+            }
+            48 => {
+                // Access<"NoNum"> = Term<"NoNum"> => ActionFn(172);
+                let __sym0 = __pop_NtTerm_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action172::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAccess_3c_22NoNum_22_3e(__nt), __end));
+                29
+            }
+            49 => {
+                // App<"Any"> = App<"Any">, Access<"Any"> => ActionFn(323);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtApp_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action323::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22Any_22_3e(__nt), __end));
+                30
+            }
+            50 => {
+                // App<"Any"> = "raise", Access<"Any"> => ActionFn(324);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22raise_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action324::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22Any_22_3e(__nt), __end));
+                30
+            }
+            51 => {
+                // App<"Any"> = Access<"Any"> => ActionFn(124);
+                let __sym0 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action124::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtApp_3c_22Any_22_3e(__nt), __end));
+                30
+            }
+            52 => {
+                // App<"NoNum"> = App<"NoNum">, Access<"Any"> => ActionFn(325);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_NtApp_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action325::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22NoNum_22_3e(__nt), __end));
+                31
+            }
+            53 => {
+                // App<"NoNum"> = "raise", Access<"Any"> => ActionFn(326);
+                let __sym1 = __pop_NtAccess_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22raise_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action326::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtApp_3c_22NoNum_22_3e(__nt), __end));
+                31
+            }
+            54 => {
+                // App<"NoNum"> = Access<"NoNum"> => ActionFn(131);
+                let __sym0 = __pop_NtAccess_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action131::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtApp_3c_22NoNum_22_3e(__nt), __end));
+                31
+            }
+            55 => {
+                // AppType = Id, "<", Type, ">" => ActionFn(218);
+                let __sym3 = __pop_Term_22_3e_22(__symbols);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_3c_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action218::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            56 => {
+                // AppType = Id, "<", Type, ("," <Type>)+, ">" => ActionFn(219);
+                let __sym4 = __pop_Term_22_3e_22(__symbols);
+                let __sym3 = __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_3c_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action219::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            57 => {
+                // AppType = TypeVar => ActionFn(28);
+                let __sym0 = __pop_NtTypeVar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action28::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            58 => {
+                // AppType = Id => ActionFn(29);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action29::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            59 => {
+                // AppType = "(", Type, ")" => ActionFn(30);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtType(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action30::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAppType(__nt), __end));
+                32
+            }
+            60 => {
+                // Arm = Pattern, "->", Seq<"Closed"> => ActionFn(7);
+                let __sym2 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym0 = __pop_NtPattern(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action7::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtArm(__nt), __end));
+                33
+            }
+            61 => {
+                // AssignExpr = InfixExpr, ":=", AssignExpr => ActionFn(327);
+                let __sym2 = __pop_NtAssignExpr(__symbols);
+                let __sym1 = __pop_Term_22_3a_3d_22(__symbols);
+                let __sym0 = __pop_NtInfixExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action327::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAssignExpr(__nt), __end));
+                34
+            }
+            62 => {
+                // AssignExpr = InfixExpr => ActionFn(33);
+                let __sym0 = __pop_NtInfixExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssignExpr(__nt), __end));
+                34
+            }
+            63 => {
+                // Assoc = "infixl" => ActionFn(4);
+                let __sym0 = __pop_Term_22infixl_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssoc(__nt), __end));
+                35
+            }
+            64 => {
+                // Assoc = "infixr" => ActionFn(5);
+                let __sym0 = __pop_Term_22infixr_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssoc(__nt), __end));
+                35
+            }
+            65 => {
+                // Assoc = "infix" => ActionFn(6);
+                let __sym0 = __pop_Term_22infix_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action6::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAssoc(__nt), __end));
+                35
+            }
+            66 => {
+                // AtomPattern = Id => ActionFn(328);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action328::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            67 => {
+                // AtomPattern = Num => ActionFn(329);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action329::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            68 => {
+                // AtomPattern = "-", Digits => ActionFn(330);
+                let __sym1 = __pop_NtDigits(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action330::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            69 => {
+                // AtomPattern = Bool => ActionFn(331);
+                let __sym0 = __pop_NtBool(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action331::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            70 => {
+                // AtomPattern = "(", Pattern, ("," <Pattern>)+, ")" => ActionFn(332);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action332::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            71 => {
+                // AtomPattern = "(", Pattern, ")" => ActionFn(15);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtPattern(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action15::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtAtomPattern(__nt), __end));
+                36
+            }
+            72 => {
+                // AtomPattern+ = AtomPattern => ActionFn(91);
+                let __sym0 = __pop_NtAtomPattern(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action91::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtAtomPattern_2b(__nt), __end));
+                37
+            }
+            73 => {
+                // AtomPattern+ = AtomPattern+, AtomPattern => ActionFn(92);
+                let __sym1 = __pop_NtAtomPattern(__symbols);
+                let __sym0 = __pop_NtAtomPattern_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action92::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtAtomPattern_2b(__nt), __end));
+                37
+            }
+            74 => {
+                // Bool = "true" => ActionFn(63);
+                let __sym0 = __pop_Term_22true_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action63::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBool(__nt), __end));
+                38
+            }
+            75 => {
+                // Bool = "false" => ActionFn(64);
+                let __sym0 = __pop_Term_22false_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action64::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBool(__nt), __end));
+                38
+            }
+            76 => {
+                // BuiltinOp = "||" => ActionFn(38);
+                let __sym0 = __pop_Term_22_7c_7c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action38::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            77 => {
+                // BuiltinOp = "&&" => ActionFn(39);
+                let __sym0 = __pop_Term_22_26_26_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action39::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            78 => {
+                // BuiltinOp = ">" => ActionFn(40);
+                let __sym0 = __pop_Term_22_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action40::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            79 => {
+                // BuiltinOp = "<" => ActionFn(41);
+                let __sym0 = __pop_Term_22_3c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            80 => {
+                // BuiltinOp = "=" => ActionFn(42);
+                let __sym0 = __pop_Term_22_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            81 => {
+                // BuiltinOp = ">=" => ActionFn(43);
+                let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action43::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            82 => {
+                // BuiltinOp = "<=" => ActionFn(44);
+                let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action44::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            83 => {
+                // BuiltinOp = "!=" => ActionFn(45);
+                let __sym0 = __pop_Term_22_21_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action45::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            84 => {
+                // BuiltinOp = "+" => ActionFn(46);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action46::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            85 => {
+                // BuiltinOp = "-" => ActionFn(47);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action47::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            86 => {
+                // BuiltinOp = "^" => ActionFn(48);
+                let __sym0 = __pop_Term_22_5e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action48::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            87 => {
+                // BuiltinOp = "*" => ActionFn(49);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action49::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            88 => {
+                // BuiltinOp = "/" => ActionFn(50);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action50::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            89 => {
+                // BuiltinOp = "mod" => ActionFn(51);
+                let __sym0 = __pop_Term_22mod_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action51::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBuiltinOp(__nt), __end));
+                39
+            }
+            90 => {
+                // Char = r#"'(\\\\.|[^'\\\\])'"# => ActionFn(57);
+                let __sym0 = __pop_Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action57::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtChar(__nt), __end));
+                40
+            }
+            91 => {
+                // CtorDecl = Id => ActionFn(20);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action20::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCtorDecl(__nt), __end));
+                41
+            }
+            92 => {
+                // CtorDecl = Id, "of", AppType => ActionFn(200);
+                let __sym2 = __pop_NtAppType(__symbols);
+                let __sym1 = __pop_Term_22of_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action200::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtCtorDecl(__nt), __end));
+                41
+            }
+            93 => {
+                // CtorDecl = Id, "of", AppType, ("*" <AppType>)+ => ActionFn(201);
+                let __sym3 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtAppType(__symbols);
+                let __sym1 = __pop_Term_22of_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action201::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtCtorDecl(__nt), __end));
+                41
+            }
+            94 => {
+                // Digits = r#"[0-9]+"# => ActionFn(54);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action54::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtDigits(__nt), __end));
+                42
+            }
+            95 => {
+                // Expr = Seq<"Open"> => ActionFn(2);
+                let __sym0 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action2::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                43
+            }
+            96 => {
+                // FieldInit = Id, "=", Seq<"Open"> => ActionFn(52);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action52::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFieldInit(__nt), __end));
+                44
+            }
+            97 => {
+                // FixityDecl = Assoc, Num, Operator => ActionFn(3);
+                let __sym2 = __pop_NtOperator(__symbols);
+                let __sym1 = __pop_NtNum(__symbols);
+                let __sym0 = __pop_NtAssoc(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action3::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtFixityDecl(__nt), __end));
+                45
+            }
+            98 => {
+                // Float = r#"[0-9]+\\.[0-9]+"# => ActionFn(55);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action55::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFloat(__nt), __end));
+                46
+            }
+            99 => {
+                // Id = r#"[a-zA-Z_][a-zA-Z0-9_]*"# => ActionFn(60);
+                let __sym0 = __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action60::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtId(__nt), __end));
+                47
+            }
+            100 => {
+                // If<"Closed"> = "if", Seq<"Open">, "then", Seq<"Open">, "else", Seq<"Closed"> => ActionFn(333);
+                let __sym5 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym4 = __pop_Term_22else_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22then_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22if_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action333::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Closed_22_3e(__nt), __end));
+                48
+            }
+            101 => {
+                // If<"Closed"> = Lambda<"Closed"> => ActionFn(135);
+                let __sym0 = __pop_NtLambda_3c_22Closed_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action135::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Closed_22_3e(__nt), __end));
+                48
+            }
+            102 => {
+                // If<"Open"> = "if", Seq<"Open">, "then", Seq<"Open">, "else", Seq<"Open"> => ActionFn(334);
+                let __sym5 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym4 = __pop_Term_22else_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22then_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22if_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action334::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Open_22_3e(__nt), __end));
+                49
+            }
+            103 => {
+                // If<"Open"> = Lambda<"Open"> => ActionFn(137);
+                let __sym0 = __pop_NtLambda_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action137::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtIf_3c_22Open_22_3e(__nt), __end));
+                49
+            }
+            104 => {
+                // InfixExpr = Unary<"Any">, (Operator Unary<"Any">)+ => ActionFn(335);
+                let __sym1 = __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__symbols);
+                let __sym0 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action335::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtInfixExpr(__nt), __end));
+                50
+            }
+            105 => {
+                // InfixExpr = Unary<"Any"> => ActionFn(35);
+                let __sym0 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action35::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtInfixExpr(__nt), __end));
+                50
+            }
+            106 => {
+                // Lambda<"Closed"> = "fun", Id, "->", Seq<"Closed"> => ActionFn(336);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action336::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Closed_22_3e(__nt), __end));
+                51
+            }
+            107 => {
+                // Lambda<"Closed"> = "fun", "(", Id, ":", SpannedType, ")", "->", Seq<"Closed"> => ActionFn(337);
+                let __sym7 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym6 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym5 = __pop_Term_22_29_22(__symbols);
+                let __sym4 = __pop_NtSpannedType(__symbols);
+                let __sym3 = __pop_Term_22_3a_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = super::__action337::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 8);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Closed_22_3e(__nt), __end));
+                51
+            }
+            108 => {
+                // Lambda<"Closed"> = AssignExpr => ActionFn(156);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action156::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Closed_22_3e(__nt), __end));
+                51
+            }
+            109 => {
+                // Lambda<"Open"> = "fun", Id, "->", Seq<"Open"> => ActionFn(338);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym1 = __pop_NtId(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action338::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Open_22_3e(__nt), __end));
+                52
+            }
+            110 => {
+                // Lambda<"Open"> = "fun", "(", Id, ":", SpannedType, ")", "->", Seq<"Open"> => ActionFn(339);
+                let __sym7 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym6 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym5 = __pop_Term_22_29_22(__symbols);
+                let __sym4 = __pop_NtSpannedType(__symbols);
+                let __sym3 = __pop_Term_22_3a_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22fun_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym7.2.clone();
+                let __nt = super::__action339::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 8);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Open_22_3e(__nt), __end));
+                52
+            }
+            111 => {
+                // Lambda<"Open"> = AssignExpr => ActionFn(153);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action153::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLambda_3c_22Open_22_3e(__nt), __end));
+                52
+            }
+            112 => {
+                // Let<"Closed"> = "let", Name, "=", Seq<"Open">, "in", Seq<"Closed"> => ActionFn(340);
+                let __sym5 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action340::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            113 => {
+                // Let<"Closed"> = "let", "(", Id, ("," <Id>)+, ")", "=", Seq<"Open">, "in", Seq<"Closed"> => ActionFn(341);
+                let __sym8 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym7 = __pop_Term_22in_22(__symbols);
+                let __sym6 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym5 = __pop_Term_22_3d_22(__symbols);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym8.2.clone();
+                let __nt = super::__action341::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 9);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            114 => {
+                // Let<"Closed"> = "letrec", Name, "=", Seq<"Open">, "in", Seq<"Closed"> => ActionFn(342);
+                let __sym5 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action342::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            115 => {
+                // Let<"Closed"> = "letrec", RecBinding, ("and" <RecBinding>)+, "in", Seq<"Closed"> => ActionFn(343);
+                let __sym4 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym3 = __pop_Term_22in_22(__symbols);
+                let __sym2 = __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action343::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            116 => {
+                // Let<"Closed"> = "type", TypeDecl, "in", Seq<"Closed"> => ActionFn(344);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeDecl(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action344::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            117 => {
+                // Let<"Closed"> = "type", TypeAlias, "in", Seq<"Closed"> => ActionFn(345);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeAlias(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action345::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            118 => {
+                // Let<"Closed"> = "exception", CtorDecl, "in", Seq<"Closed"> => ActionFn(346);
+                let __sym3 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22exception_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action346::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            119 => {
+                // Let<"Closed"> = FixityDecl, "in", Seq<"Closed"> => ActionFn(347);
+                let __sym2 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym1 = __pop_Term_22in_22(__symbols);
+                let __sym0 = __pop_NtFixityDecl(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action347::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            120 => {
+                // Let<"Closed"> = If<"Closed"> => ActionFn(117);
+                let __sym0 = __pop_NtIf_3c_22Closed_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action117::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Closed_22_3e(__nt), __end));
+                53
+            }
+            121 => {
+                // Let<"Open"> = "let", Name, "=", Seq<"Open">, "in", Seq<"Open"> => ActionFn(348);
+                let __sym5 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action348::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            122 => {
+                // Let<"Open"> = "let", "(", Id, ("," <Id>)+, ")", "=", Seq<"Open">, "in", Seq<"Open"> => ActionFn(349);
+                let __sym8 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym7 = __pop_Term_22in_22(__symbols);
+                let __sym6 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym5 = __pop_Term_22_3d_22(__symbols);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_28_22(__symbols);
+                let __sym0 = __pop_Term_22let_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym8.2.clone();
+                let __nt = super::__action349::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 9);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            123 => {
+                // Let<"Open"> = "letrec", Name, "=", Seq<"Open">, "in", Seq<"Open"> => ActionFn(350);
+                let __sym5 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym4 = __pop_Term_22in_22(__symbols);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtName(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action350::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            124 => {
+                // Let<"Open"> = "letrec", RecBinding, ("and" <RecBinding>)+, "in", Seq<"Open"> => ActionFn(351);
+                let __sym4 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym3 = __pop_Term_22in_22(__symbols);
+                let __sym2 = __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtRecBinding(__symbols);
+                let __sym0 = __pop_Term_22letrec_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action351::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            125 => {
+                // Let<"Open"> = "type", TypeDecl, "in", Seq<"Open"> => ActionFn(352);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeDecl(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action352::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            126 => {
+                // Let<"Open"> = "type", TypeAlias, "in", Seq<"Open"> => ActionFn(353);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtTypeAlias(__symbols);
+                let __sym0 = __pop_Term_22type_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action353::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            127 => {
+                // Let<"Open"> = "exception", CtorDecl, "in", Seq<"Open"> => ActionFn(354);
+                let __sym3 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym2 = __pop_Term_22in_22(__symbols);
+                let __sym1 = __pop_NtCtorDecl(__symbols);
+                let __sym0 = __pop_Term_22exception_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action354::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            128 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", "|", Arm => ActionFn(355);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action355::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            129 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", "|", Arm, ("|" <Arm>)+ => ActionFn(356);
+                let __sym5 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action356::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            130 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", Arm => ActionFn(357);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action357::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            131 => {
+                // Let<"Open"> = "match", Seq<"Open">, "with", Arm, ("|" <Arm>)+ => ActionFn(358);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22match_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action358::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            132 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", "|", Arm => ActionFn(359);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action359::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            133 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", "|", Arm, ("|" <Arm>)+ => ActionFn(360);
+                let __sym5 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtArm(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action360::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            134 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", Arm => ActionFn(361);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action361::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            135 => {
+                // Let<"Open"> = "try", Seq<"Open">, "with", Arm, ("|" <Arm>)+ => ActionFn(362);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtArm(__symbols);
+                let __sym2 = __pop_Term_22with_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22try_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action362::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            136 => {
+                // Let<"Open"> = FixityDecl, "in", Seq<"Open"> => ActionFn(363);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22in_22(__symbols);
+                let __sym0 = __pop_NtFixityDecl(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action363::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            137 => {
+                // Let<"Open"> = If<"Open"> => ActionFn(108);
+                let __sym0 = __pop_NtIf_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action108::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtLet_3c_22Open_22_3e(__nt), __end));
+                54
+            }
+            138 => {
+                // Name = Id => ActionFn(58);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action58::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtName(__nt), __end));
+                55
+            }
+            139 => {
+                // Name = "(", Sym, ")" => ActionFn(59);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSym(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action59::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtName(__nt), __end));
+                55
+            }
+            140 => {
+                // Num = Digits => ActionFn(364);
+                let __sym0 = __pop_NtDigits(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action364::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                56
+            }
+            141 => {
+                // Operator = BuiltinOp => ActionFn(365);
+                let __sym0 = __pop_NtBuiltinOp(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action365::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOperator(__nt), __end));
+                57
+            }
+            142 => {
+                // Operator = Sym => ActionFn(366);
+                let __sym0 = __pop_NtSym(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action366::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtOperator(__nt), __end));
+                57
+            }
+            143 => {
+                // Pattern = Id, AtomPattern+ => ActionFn(367);
+                let __sym1 = __pop_NtAtomPattern_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action367::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtPattern(__nt), __end));
+                58
+            }
+            144 => {
+                // Pattern = AtomPattern => ActionFn(9);
+                let __sym0 = __pop_NtAtomPattern(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtPattern(__nt), __end));
+                58
+            }
+            145 => {
+                // RecBinding = Name, "=", Seq<"Open"> => ActionFn(31);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtName(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action31::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtRecBinding(__nt), __end));
+                59
+            }
+            146 => {
+                // Seq<"Closed"> = AssignExpr, ";", Seq<"Closed"> => ActionFn(368);
+                let __sym2 = __pop_NtSeq_3c_22Closed_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action368::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Closed_22_3e(__nt), __end));
+                60
+            }
+            147 => {
+                // Seq<"Closed"> = Let<"Closed"> => ActionFn(95);
+                let __sym0 = __pop_NtLet_3c_22Closed_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action95::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Closed_22_3e(__nt), __end));
+                60
+            }
+            148 => {
+                // Seq<"Open"> = AssignExpr, ";", Seq<"Open"> => ActionFn(369);
+                let __sym2 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym1 = __pop_Term_22_3b_22(__symbols);
+                let __sym0 = __pop_NtAssignExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action369::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Open_22_3e(__nt), __end));
+                61
+            }
+            149 => {
+                // Seq<"Open"> = Let<"Open"> => ActionFn(97);
+                let __sym0 = __pop_NtLet_3c_22Open_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action97::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSeq_3c_22Open_22_3e(__nt), __end));
+                61
+            }
+            150 => {
+                // SpannedType = Type => ActionFn(370);
+                let __sym0 = __pop_NtType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action370::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSpannedType(__nt), __end));
+                62
+            }
+            151 => {
+                // Str = r#"\"(\\\\.|[^\"\\\\])*\""# => ActionFn(56);
+                let __sym0 = __pop_Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action56::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                63
+            }
+            152 => {
+                // Sym = r#"[-+*/=<|&^@$%~?][-+*/=<>|&^@$%~?!.:]*"# => ActionFn(61);
+                let __sym0 = __pop_Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action61::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSym(__nt), __end));
+                64
+            }
+            153 => {
+                // Term<"Any"> = Num => ActionFn(371);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action371::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            154 => {
+                // Term<"Any"> = Float => ActionFn(372);
+                let __sym0 = __pop_NtFloat(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action372::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            155 => {
+                // Term<"Any"> = Str => ActionFn(373);
+                let __sym0 = __pop_NtStr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action373::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            156 => {
+                // Term<"Any"> = Char => ActionFn(374);
+                let __sym0 = __pop_NtChar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action374::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            157 => {
+                // Term<"Any"> = Id => ActionFn(375);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action375::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            158 => {
+                // Term<"Any"> = Bool => ActionFn(376);
+                let __sym0 = __pop_NtBool(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action376::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            159 => {
+                // Term<"Any"> = "(", Seq<"Open">, ("," <Seq<"Open">>)+, ")" => ActionFn(377);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action377::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            160 => {
+                // Term<"Any"> = "{", FieldInit, "}" => ActionFn(378);
+                let __sym2 = __pop_Term_22_7d_22(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action378::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            161 => {
+                // Term<"Any"> = "{", FieldInit, ("," <FieldInit>)+, "}" => ActionFn(379);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action379::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            162 => {
+                // Term<"Any"> = "{", "}" => ActionFn(380);
+                let __sym1 = __pop_Term_22_7d_22(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action380::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            163 => {
+                // Term<"Any"> = "(", Seq<"Open">, ":", SpannedType, ")" => ActionFn(381);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_NtSpannedType(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action381::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            164 => {
+                // Term<"Any"> = "(", ")" => ActionFn(382);
+                let __sym1 = __pop_Term_22_29_22(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action382::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            165 => {
+                // Term<"Any"> = "!", Term<"Any"> => ActionFn(383);
+                let __sym1 = __pop_NtTerm_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_21_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action383::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            166 => {
+                // Term<"Any"> = "(", Sym, ")" => ActionFn(384);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSym(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action384::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            167 => {
+                // Term<"Any"> = "(", Seq<"Open">, ")" => ActionFn(170);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action170::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22Any_22_3e(__nt), __end));
+                65
+            }
+            168 => {
+                // Term<"NoNum"> = Float => ActionFn(385);
+                let __sym0 = __pop_NtFloat(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action385::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            169 => {
+                // Term<"NoNum"> = Str => ActionFn(386);
+                let __sym0 = __pop_NtStr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action386::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            170 => {
+                // Term<"NoNum"> = Char => ActionFn(387);
+                let __sym0 = __pop_NtChar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action387::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            171 => {
+                // Term<"NoNum"> = Id => ActionFn(388);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action388::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            172 => {
+                // Term<"NoNum"> = Bool => ActionFn(389);
+                let __sym0 = __pop_NtBool(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action389::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            173 => {
+                // Term<"NoNum"> = "(", Seq<"Open">, ("," <Seq<"Open">>)+, ")" => ActionFn(390);
+                let __sym3 = __pop_Term_22_29_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action390::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            174 => {
+                // Term<"NoNum"> = "{", FieldInit, "}" => ActionFn(391);
+                let __sym2 = __pop_Term_22_7d_22(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action391::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            175 => {
+                // Term<"NoNum"> = "{", FieldInit, ("," <FieldInit>)+, "}" => ActionFn(392);
+                let __sym3 = __pop_Term_22_7d_22(__symbols);
+                let __sym2 = __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__symbols);
+                let __sym1 = __pop_NtFieldInit(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action392::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            176 => {
+                // Term<"NoNum"> = "{", "}" => ActionFn(393);
+                let __sym1 = __pop_Term_22_7d_22(__symbols);
+                let __sym0 = __pop_Term_22_7b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action393::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            177 => {
+                // Term<"NoNum"> = "(", Seq<"Open">, ":", SpannedType, ")" => ActionFn(394);
+                let __sym4 = __pop_Term_22_29_22(__symbols);
+                let __sym3 = __pop_NtSpannedType(__symbols);
+                let __sym2 = __pop_Term_22_3a_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action394::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            178 => {
+                // Term<"NoNum"> = "(", ")" => ActionFn(395);
+                let __sym1 = __pop_Term_22_29_22(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action395::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            179 => {
+                // Term<"NoNum"> = "!", Term<"Any"> => ActionFn(396);
+                let __sym1 = __pop_NtTerm_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_21_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action396::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            180 => {
+                // Term<"NoNum"> = "(", Sym, ")" => ActionFn(397);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSym(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action397::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            181 => {
+                // Term<"NoNum"> = "(", Seq<"Open">, ")" => ActionFn(185);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtSeq_3c_22Open_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action185::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm_3c_22NoNum_22_3e(__nt), __end));
+                66
+            }
+            182 => {
+                // TupleType = AppType, ("*" <AppType>)+ => ActionFn(25);
+                let __sym1 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym0 = __pop_NtAppType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action25::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTupleType(__nt), __end));
+                67
+            }
+            183 => {
+                // TupleType = AppType => ActionFn(26);
+                let __sym0 = __pop_NtAppType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action26::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTupleType(__nt), __end));
+                67
+            }
+            184 => {
+                // Type = TupleType, "->", Type => ActionFn(23);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_2d_3e_22(__symbols);
+                let __sym0 = __pop_NtTupleType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action23::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtType(__nt), __end));
+                68
+            }
+            185 => {
+                // Type = TupleType => ActionFn(24);
+                let __sym0 = __pop_NtTupleType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action24::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtType(__nt), __end));
+                68
+            }
+            186 => {
+                // TypeAlias = Id, "=", Type => ActionFn(404);
+                let __sym2 = __pop_NtType(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action404::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTypeAlias(__nt), __end));
+                69
+            }
+            187 => {
+                // TypeAlias = Id, TypeVar+, "=", Type => ActionFn(405);
+                let __sym3 = __pop_NtType(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action405::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTypeAlias(__nt), __end));
+                69
+            }
+            188 => {
+                // TypeDecl = Id, "=", "|", CtorDecl => ActionFn(406);
+                let __sym3 = __pop_NtCtorDecl(__symbols);
+                let __sym2 = __pop_Term_22_7c_22(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action406::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            189 => {
+                // TypeDecl = Id, TypeVar+, "=", "|", CtorDecl => ActionFn(407);
+                let __sym4 = __pop_NtCtorDecl(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action407::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            190 => {
+                // TypeDecl = Id, "=", "|", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(408);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtCtorDecl(__symbols);
+                let __sym2 = __pop_Term_22_7c_22(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action408::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            191 => {
+                // TypeDecl = Id, TypeVar+, "=", "|", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(409);
+                let __sym5 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtCtorDecl(__symbols);
+                let __sym3 = __pop_Term_22_7c_22(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action409::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            192 => {
+                // TypeDecl = Id, "=", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(410);
+                let __sym3 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym2 = __pop_NtCtorDecl(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym3.2.clone();
+                let __nt = super::__action410::<>(input, __sym0, __sym1, __sym2, __sym3);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 4);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            193 => {
+                // TypeDecl = Id, TypeVar+, "=", CtorDecl, ("|" <CtorDecl>)+ => ActionFn(411);
+                let __sym4 = __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__symbols);
+                let __sym3 = __pop_NtCtorDecl(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action411::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            194 => {
+                // TypeDecl = Id, "=", Id, "of", AppType => ActionFn(412);
+                let __sym4 = __pop_NtAppType(__symbols);
+                let __sym3 = __pop_Term_22of_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = super::__action412::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 5);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            195 => {
+                // TypeDecl = Id, TypeVar+, "=", Id, "of", AppType => ActionFn(413);
+                let __sym5 = __pop_NtAppType(__symbols);
+                let __sym4 = __pop_Term_22of_22(__symbols);
+                let __sym3 = __pop_NtId(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action413::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            196 => {
+                // TypeDecl = Id, "=", Id, "of", AppType, ("*" <AppType>)+ => ActionFn(414);
+                let __sym5 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym4 = __pop_NtAppType(__symbols);
+                let __sym3 = __pop_Term_22of_22(__symbols);
+                let __sym2 = __pop_NtId(__symbols);
+                let __sym1 = __pop_Term_22_3d_22(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym5.2.clone();
+                let __nt = super::__action414::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 6);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            197 => {
+                // TypeDecl = Id, TypeVar+, "=", Id, "of", AppType, ("*" <AppType>)+ => ActionFn(415);
+                let __sym6 = __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__symbols);
+                let __sym5 = __pop_NtAppType(__symbols);
+                let __sym4 = __pop_Term_22of_22(__symbols);
+                let __sym3 = __pop_NtId(__symbols);
+                let __sym2 = __pop_Term_22_3d_22(__symbols);
+                let __sym1 = __pop_NtTypeVar_2b(__symbols);
+                let __sym0 = __pop_NtId(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym6.2.clone();
+                let __nt = super::__action415::<>(input, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 7);
+                __symbols.push((__start, __Symbol::NtTypeDecl(__nt), __end));
+                70
+            }
+            198 => {
+                // TypeVar = r#"'[a-z][a-zA-Z0-9_]*"# => ActionFn(62);
+                let __sym0 = __pop_Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action62::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTypeVar(__nt), __end));
+                71
+            }
+            199 => {
+                // TypeVar* =  => ActionFn(85);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action85::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::NtTypeVar_2a(__nt), __end));
+                72
+            }
+            200 => {
+                // TypeVar* = TypeVar+ => ActionFn(86);
+                let __sym0 = __pop_NtTypeVar_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action86::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTypeVar_2a(__nt), __end));
+                72
+            }
+            201 => {
+                // TypeVar+ = TypeVar => ActionFn(118);
+                let __sym0 = __pop_NtTypeVar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action118::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTypeVar_2b(__nt), __end));
+                73
+            }
+            202 => {
+                // TypeVar+ = TypeVar+, TypeVar => ActionFn(119);
+                let __sym1 = __pop_NtTypeVar(__symbols);
+                let __sym0 = __pop_NtTypeVar_2b(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action119::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtTypeVar_2b(__nt), __end));
+                73
+            }
+            203 => {
+                // Unary<"Any"> = "-", Digits => ActionFn(398);
+                let __sym1 = __pop_NtDigits(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action398::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            204 => {
+                // Unary<"Any"> = "-", Unary<"NoNum"> => ActionFn(399);
+                let __sym1 = __pop_NtUnary_3c_22NoNum_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action399::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            205 => {
+                // Unary<"Any"> = "not", Unary<"Any"> => ActionFn(400);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action400::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            206 => {
+                // Unary<"Any"> = App<"Any"> => ActionFn(71);
+                let __sym0 = __pop_NtApp_3c_22Any_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action71::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22Any_22_3e(__nt), __end));
+                74
+            }
+            207 => {
+                // Unary<"NoNum"> = "-", Digits => ActionFn(401);
+                let __sym1 = __pop_NtDigits(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = match super::__action401::<>(input, __sym0, __sym1) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            208 => {
+                // Unary<"NoNum"> = "-", Unary<"NoNum"> => ActionFn(402);
+                let __sym1 = __pop_NtUnary_3c_22NoNum_22_3e(__symbols);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action402::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            209 => {
+                // Unary<"NoNum"> = "not", Unary<"Any"> => ActionFn(403);
+                let __sym1 = __pop_NtUnary_3c_22Any_22_3e(__symbols);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action403::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            210 => {
+                // Unary<"NoNum"> = App<"NoNum"> => ActionFn(128);
+                let __sym0 = __pop_NtApp_3c_22NoNum_22_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action128::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUnary_3c_22NoNum_22_3e(__nt), __end));
+                75
+            }
+            211 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____Expr(__nt), __end));
+                76
+            }
+            212 => {
+                // __Type = Type => ActionFn(1);
+                let __sym0 = __pop_NtType(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 78 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Term_22_21_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_21_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_26_26_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_26_26_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2f_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3a_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3a_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_5e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_5e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22and_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22and_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22else_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22else_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22exception_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22exception_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22false_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22false_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22fun_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22fun_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22if_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22if_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22in_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22in_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22infix_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22infix_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22infixl_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22infixl_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22infixr_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22infixr_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22let_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22letrec_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22letrec_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22match_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22match_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22mod_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22mod_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22not_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22not_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22of_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22of_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22raise_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22raise_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22then_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22then_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22true_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22true_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22try_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22try_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22type_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22type_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22with_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22with_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7c_7c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7c_7c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_7d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_7d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5c_22_28_5c_5c_5c_5c_2e_7c_5b_5e_5c_22_5c_5c_5c_5c_5d_29_2a_5c_22_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_28_5c_5c_5c_5c_2e_7c_5b_5e_27_5c_5c_5c_5c_5d_29_27_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_5ba_2dz_5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b_2d_2b_2a_2f_3d_3c_7c_26_5e_40_24_25_7e_3f_5d_5b_2d_2b_2a_2f_3d_3c_3e_7c_26_5e_40_24_25_7e_3f_21_2e_3a_5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_5c_5c_2e_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_22_7c_22_3f<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::option::Option<&'input str>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_22_7c_22_3f(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2a_22_20_3cAppType_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2a_22_20_3cAppType_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2a_22_20_3cAppType_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cFieldInit_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cId_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cId_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cId_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cPattern_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<Pattern>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cPattern_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Spanned<Pattern>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cPattern_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cSeq_3c_22Open_22_3e_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cType_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cType_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_2c_22_20_3cType_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_2c_22_20_3cType_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22and_22_20_3cRecBinding_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22and_22_20_3cRecBinding_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22and_22_20_3cRecBinding_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cArm_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<Pattern>, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cArm_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cArm_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (String, Vec<TypeExpr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28_22_7c_22_20_3cCtorDecl_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<String>, Box<Spanned<Expr>>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_28Operator_20Unary_3c_22Any_22_3e_29_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAccess_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAccess_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAccess_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAccess_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtApp_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtApp_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtApp_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtApp_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAppType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAppType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtArm<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, (Spanned<Pattern>, Spanned<Expr>), usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtArm(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAssignExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAssignExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAssoc<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Assoc, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAssoc(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAtomPattern<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<Pattern>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAtomPattern(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtAtomPattern_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<Spanned<Pattern>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtAtomPattern_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBool<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, bool, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBool(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBuiltinOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'static str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBuiltinOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtChar<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, char, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtChar(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtCtorDecl<
+      'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, (String, Vec<TypeExpr>), usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtCtorDecl(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_29_22<
+    fn __pop_NtDigits<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtDigits(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2a_22<
+    fn __pop_NtExpr<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2b_22<
+    fn __pop_NtFieldInit<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, (String, Spanned<Expr>), usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtFieldInit(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2d_22<
+    fn __pop_NtFixityDecl<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, FixityDecl, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtFixityDecl(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2d_3e_22<
+    fn __pop_NtFloat<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, f64, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2d_3e_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtFloat(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2f_22<
+    fn __pop_NtId<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, String, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtId(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3c_22<
+    fn __pop_NtIf_3c_22Closed_22_3e<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtIf_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3d_22<
+    fn __pop_NtIf_3c_22Open_22_3e<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3d_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtIf_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3e_22<
+    fn __pop_NtInfixExpr<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtInfixExpr(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22else_22<
+    fn __pop_NtLambda_3c_22Closed_22_3e<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22else_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtLambda_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22false_22<
+    fn __pop_NtLambda_3c_22Open_22_3e<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22false_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtLambda_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22fun_22<
+    fn __pop_NtLet_3c_22Closed_22_3e<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22fun_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtLet_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22if_22<
+    fn __pop_NtLet_3c_22Open_22_3e<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22if_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtLet_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22in_22<
+    fn __pop_NtName<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, String, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22in_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtName(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22let_22<
+    fn __pop_NtNum<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, i32, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22let_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22letrec_22<
+    fn __pop_NtOperator<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Spanned<String>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22letrec_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtOperator(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22then_22<
+    fn __pop_NtPattern<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, Spanned<Pattern>, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22then_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtPattern(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22true_22<
+    fn __pop_NtRecBinding<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, (String, Spanned<Expr>), usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22true_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::NtRecBinding(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSeq_3c_22Closed_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSeq_3c_22Closed_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSeq_3c_22Open_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSeq_3c_22Open_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSpannedType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Spanned<TypeExpr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSpannedType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSym<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSym(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTupleType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTupleType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtType<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtType(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeAlias<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeAlias, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeAlias(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeDecl<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeDecl, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeDecl(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeVar<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeVar(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeVar_2a<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeVar_2a(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTypeVar_2b<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, ::std::vec::Vec<String>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTypeVar_2b(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtUnary_3c_22Any_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtUnary_3c_22Any_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtUnary_3c_22NoNum_22_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtUnary_3c_22NoNum_22_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Spanned<Expr>>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Type<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, TypeExpr, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Type(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_7c_7c_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_7c_7c_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
+}
+pub use self::__parse__Type::parse_Type;
+mod __intern_token {
+    #![allow(unused_imports)]
+    use std::str::FromStr;
+    use lalrpop_util::ParseError;
+    use ast::{unescape, Assoc, Expr, ExceptionDecl, FixityDecl, UnOp, Pattern, Spanned, TypeExpr, TypeDecl, TypeAlias};
+    use fixity;
+    extern crate lalrpop_util as __lalrpop_util;
+    extern crate regex as __regex;
+    pub struct __Matcher<'input> {
+        text: &'input str,
+        consumed: usize,
+        regex_set: __regex::RegexSet,
+        regex_vec: Vec<__regex::Regex>,
+    }
+
+    impl<'input> __Matcher<'input> {
+        pub fn new(s: &'input str) -> __Matcher<'input> {
+            let __strs: &[&str] = &[
+                "^(?u:\")((?u:\\\\)(?u:.)|(?u:[\0-!\\#-\\[\\]-\u{10ffff}]))*(?u:\")",
+                "^(?u:')((?u:\\\\)(?u:.)|(?u:[\0-\\&\\(-\\[\\]-\u{10ffff}]))(?u:')",
+                "^(?u:')(?u:[a-z])(?u:[0-9A-Z_-_a-z])*",
+                "^(?u:[-\\$-\\&\\*-\\+/-/<-=\\?-@\\^-\\^\\|-\\|\\~-\\~])(?u:[-!-!\\$-\\&\\*-\\+\\.-/:-:<-@\\^-\\^\\|-\\|\\~-\\~])*",
+                "^(?u:[0-9])+",
+                "^(?u:[0-9])+(?u:\\.)(?u:[0-9])+",
+                "^(?u:[A-Z_-_a-z])(?u:[0-9A-Z_-_a-z])*",
+                "^(?u:!)",
+                "^(?u:!=)",
+                "^(?u:\\&\\&)",
+                "^(?u:\\()",
+                "^(?u:\\))",
+                "^(?u:\\*)",
+                "^(?u:\\+)",
+                "^(?u:,)",
+                "^(?u:\\-)",
+                "^(?u:\\->)",
+                "^(?u:\\.)",
+                "^(?u:/)",
+                "^(?u::)",
+                "^(?u::=)",
+                "^(?u:;)",
+                "^(?u:<)",
+                "^(?u:<=)",
+                "^(?u:=)",
+                "^(?u:>)",
+                "^(?u:>=)",
+                "^(?u:\\^)",
+                "^(?u:and)",
+                "^(?u:else)",
+                "^(?u:exception)",
+                "^(?u:false)",
+                "^(?u:fun)",
+                "^(?u:if)",
+                "^(?u:in)",
+                "^(?u:infix)",
+                "^(?u:infixl)",
+                "^(?u:infixr)",
+                "^(?u:let)",
+                "^(?u:letrec)",
+                "^(?u:match)",
+                "^(?u:mod)",
+                "^(?u:not)",
+                "^(?u:of)",
+                "^(?u:raise)",
+                "^(?u:then)",
+                "^(?u:true)",
+                "^(?u:try)",
+                "^(?u:type)",
+                "^(?u:with)",
+                "^(?u:\\{)",
+                "^(?u:\\|)",
+                "^(?u:\\|\\|)",
+                "^(?u:\\})",
+            ];
+            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
+            let __regex_vec = vec![
+                __regex::Regex::new("^(?u:\")((?u:\\\\)(?u:.)|(?u:[\0-!\\#-\\[\\]-\u{10ffff}]))*(?u:\")").unwrap(),
+                __regex::Regex::new("^(?u:')((?u:\\\\)(?u:.)|(?u:[\0-\\&\\(-\\[\\]-\u{10ffff}]))(?u:')").unwrap(),
+                __regex::Regex::new("^(?u:')(?u:[a-z])(?u:[0-9A-Z_-_a-z])*").unwrap(),
+                __regex::Regex::new("^(?u:[-\\$-\\&\\*-\\+/-/<-=\\?-@\\^-\\^\\|-\\|\\~-\\~])(?u:[-!-!\\$-\\&\\*-\\+\\.-/:-:<-@\\^-\\^\\|-\\|\\~-\\~])*").unwrap(),
+                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:[0-9])+(?u:\\.)(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:[A-Z_-_a-z])(?u:[0-9A-Z_-_a-z])*").unwrap(),
+                __regex::Regex::new("^(?u:!)").unwrap(),
+                __regex::Regex::new("^(?u:!=)").unwrap(),
+                __regex::Regex::new("^(?u:\\&\\&)").unwrap(),
+                __regex::Regex::new("^(?u:\\()").unwrap(),
+                __regex::Regex::new("^(?u:\\))").unwrap(),
+                __regex::Regex::new("^(?u:\\*)").unwrap(),
+                __regex::Regex::new("^(?u:\\+)").unwrap(),
+                __regex::Regex::new("^(?u:,)").unwrap(),
+                __regex::Regex::new("^(?u:\\-)").unwrap(),
+                __regex::Regex::new("^(?u:\\->)").unwrap(),
+                __regex::Regex::new("^(?u:\\.)").unwrap(),
+                __regex::Regex::new("^(?u:/)").unwrap(),
+                __regex::Regex::new("^(?u::)").unwrap(),
+                __regex::Regex::new("^(?u::=)").unwrap(),
+                __regex::Regex::new("^(?u:;)").unwrap(),
+                __regex::Regex::new("^(?u:<)").unwrap(),
+                __regex::Regex::new("^(?u:<=)").unwrap(),
+                __regex::Regex::new("^(?u:=)").unwrap(),
+                __regex::Regex::new("^(?u:>)").unwrap(),
+                __regex::Regex::new("^(?u:>=)").unwrap(),
+                __regex::Regex::new("^(?u:\\^)").unwrap(),
+                __regex::Regex::new("^(?u:and)").unwrap(),
+                __regex::Regex::new("^(?u:else)").unwrap(),
+                __regex::Regex::new("^(?u:exception)").unwrap(),
+                __regex::Regex::new("^(?u:false)").unwrap(),
+                __regex::Regex::new("^(?u:fun)").unwrap(),
+                __regex::Regex::new("^(?u:if)").unwrap(),
+                __regex::Regex::new("^(?u:in)").unwrap(),
+                __regex::Regex::new("^(?u:infix)").unwrap(),
+                __regex::Regex::new("^(?u:infixl)").unwrap(),
+                __regex::Regex::new("^(?u:infixr)").unwrap(),
+                __regex::Regex::new("^(?u:let)").unwrap(),
+                __regex::Regex::new("^(?u:letrec)").unwrap(),
+                __regex::Regex::new("^(?u:match)").unwrap(),
+                __regex::Regex::new("^(?u:mod)").unwrap(),
+                __regex::Regex::new("^(?u:not)").unwrap(),
+                __regex::Regex::new("^(?u:of)").unwrap(),
+                __regex::Regex::new("^(?u:raise)").unwrap(),
+                __regex::Regex::new("^(?u:then)").unwrap(),
+                __regex::Regex::new("^(?u:true)").unwrap(),
+                __regex::Regex::new("^(?u:try)").unwrap(),
+                __regex::Regex::new("^(?u:type)").unwrap(),
+                __regex::Regex::new("^(?u:with)").unwrap(),
+                __regex::Regex::new("^(?u:\\{)").unwrap(),
+                __regex::Regex::new("^(?u:\\|)").unwrap(),
+                __regex::Regex::new("^(?u:\\|\\|)").unwrap(),
+                __regex::Regex::new("^(?u:\\})").unwrap(),
+            ];
+            __Matcher {
+                text: s,
+                consumed: 0,
+                regex_set: __regex_set,
+                regex_vec: __regex_vec,
+            }
+        }
+    }
+
+    impl<'input> Iterator for __Matcher<'input> {
+        type Item = Result<(usize, (usize, &'input str), usize), __lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            let __text = self.text.trim_left();
+            let __whitespace = self.text.len() - __text.len();
+            let __start_offset = self.consumed + __whitespace;
+            if __text.is_empty() {
+                self.text = __text;
+                self.consumed = __start_offset;
+                None
+            } else {
+                let __matches = self.regex_set.matches(__text);
+                if !__matches.matched_any() {
+                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
+                        location: __start_offset,
+                    }))
+                } else {
+                    let mut __longest_match = 0;
+                    let mut __index = 0;
+                    for __i in 0 .. 54 {
+                        if __matches.matched(__i) {
+                            let __match = self.regex_vec[__i].find(__text).unwrap();
+                            let __len = __match.end();
+                            if __len >= __longest_match {
+                                __longest_match = __len;
+                                __index = __i;
+                            }
+                        }
+                    }
+                    let __result = &__text[..__longest_match];
+                    let __remaining = &__text[__longest_match..];
+                    let __end_offset = __start_offset + __longest_match;
+                    self.text = __remaining;
+                    self.consumed = __end_offset;
+                    Some(Ok((__start_offset, (__index, __result), __end_offset)))
+                }
+            }
         }
     }
-    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action0<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> TypeExpr
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'input,
+>(
+    input: &'input str,
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    fixity::resolve(*e).map(Box::new).map_err(|error| ParseError::User { error })
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'input,
+>(
+    input: &'input str,
+    (_, assoc, _): (usize, Assoc, usize),
+    (_, prec, _): (usize, i32, usize),
+    (_, op, _): (usize, Spanned<String>, usize),
+) -> FixityDecl
+{
+    FixityDecl { assoc, prec, op: op.node }
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Assoc
+{
+    Assoc::Left
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Assoc
+{
+    Assoc::Right
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Assoc
+{
+    Assoc::NonAssoc
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, p, _): (usize, Spanned<Pattern>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+) -> (Spanned<Pattern>, Spanned<Expr>)
+{
+    (p, *e)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, c, _): (usize, String, usize),
+    (_, args, _): (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    Spanned::new(Pattern::Con(c, args), (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<Pattern>, usize),
+) -> Spanned<Pattern>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    Spanned::new(if x == "_" { Pattern::Wildcard } else { Pattern::Var(x) }, (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, i32, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    Spanned::new(Pattern::Num(n), (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Result<Spanned<Pattern>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    i32::from_str(&format!("-{}", n))
+        .map(|n| Spanned::new(Pattern::Num(n), (l, r)))
+        .map_err(|_| ParseError::User { error: fixity::Error::IntOutOfRange { span: (l, r) } })
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    Spanned::new(Pattern::Bool(b), (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, p, _): (usize, Spanned<Pattern>, usize),
+    (_, ps, _): (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    {
+        let mut elems = vec![p];
+        elems.extend(ps);
+        Spanned::new(Pattern::Tuple(elems), (l, r))
     }
-    fn __pop_Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5ba_2dzA_2dZ___5d_5ba_2dzA_2dZ0_2d9___5d_2a_22_23(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Spanned<Pattern>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Spanned<Pattern>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'input,
+>(
+    input: &'input str,
+    (_, name, _): (usize, String, usize),
+    (_, params, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, c, _): (usize, (String, Vec<TypeExpr>), usize),
+    (_, cs, _): (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
+{
+    {
+        let mut ctors = vec![c];
+        ctors.extend(cs);
+        TypeDecl { name, params, ctors }
     }
-    fn __pop_NtAddExpr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtAddExpr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'input,
+>(
+    input: &'input str,
+    (_, name, _): (usize, String, usize),
+    (_, params, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, c, _): (usize, (String, Vec<TypeExpr>), usize),
+    (_, cs, _): (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
+{
+    {
+        let mut ctors = vec![c];
+        ctors.extend(cs);
+        TypeDecl { name, params, ctors }
     }
-    fn __pop_NtAddOp<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Op, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtAddOp(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    (_, name, _): (usize, String, usize),
+    (_, params, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, c, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, TypeExpr, usize),
+    (_, ts, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> TypeDecl
+{
+    {
+        let mut args = vec![t];
+        args.extend(ts);
+        TypeDecl { name, params, ctors: vec![(c, args)] }
     }
-    fn __pop_NtApp<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtApp(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    (_, name, _): (usize, String, usize),
+    (_, params, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, typ, _): (usize, TypeExpr, usize),
+) -> TypeAlias
+{
+    TypeAlias { name, params, typ }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'input,
+>(
+    input: &'input str,
+    (_, c, _): (usize, String, usize),
+) -> (String, Vec<TypeExpr>)
+{
+    (c, vec![])
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'input,
+>(
+    input: &'input str,
+    (_, c, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, TypeExpr, usize),
+    (_, ts, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> (String, Vec<TypeExpr>)
+{
+    {
+        let mut args = vec![t];
+        args.extend(ts);
+        (c, args)
     }
-    fn __pop_NtBool<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, bool, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtBool(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, t, _): (usize, TypeExpr, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<TypeExpr>
+{
+    Spanned::new(t, (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'input,
+>(
+    input: &'input str,
+    (_, a, _): (usize, TypeExpr, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, TypeExpr, usize),
+) -> TypeExpr
+{
+    TypeExpr::Fun(Box::new(a), Box::new(r))
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> TypeExpr
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'input,
+>(
+    input: &'input str,
+    (_, t, _): (usize, TypeExpr, usize),
+    (_, ts, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> TypeExpr
+{
+    {
+        let mut elems = vec![t];
+        elems.extend(ts);
+        TypeExpr::Tuple(elems)
     }
-    fn __pop_NtExpr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> TypeExpr
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'input,
+>(
+    input: &'input str,
+    (_, n, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, TypeExpr, usize),
+    (_, ts, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> TypeExpr
+{
+    {
+        let mut params = vec![t];
+        params.extend(ts);
+        TypeExpr::Con(n, params)
     }
-    fn __pop_NtFactor<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> TypeExpr
+{
+    TypeExpr::Var(__0)
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> TypeExpr
+{
+    TypeExpr::Con(__0, vec![])
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, TypeExpr, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> TypeExpr
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'input,
+>(
+    input: &'input str,
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+) -> (String, Spanned<Expr>)
+{
+    (x, *e)
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, a, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, b, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Assign(a, b), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut operands = vec![*e];
+        let mut ops = vec![];
+        for (op, e) in rest {
+            ops.push(op);
+            operands.push(*e);
         }
+        Box::new(Spanned::new(Expr::Infix(operands, ops), (l, r)))
     }
-    fn __pop_NtFactorOp<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Op, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtFactorOp(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, op, _): (usize, &'static str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<String>
+{
+    Spanned::new(op.to_owned(), (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, op, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Spanned<String>
+{
+    Spanned::new(op, (l, r))
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "||"
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "&&"
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    ">"
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "<"
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "="
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    ">="
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "<="
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "!="
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "+"
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "-"
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "^"
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "*"
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "/"
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'static str
+{
+    "mod"
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'input,
+>(
+    input: &'input str,
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+) -> (String, Spanned<Expr>)
+{
+    (x, *e)
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    i32::from_str(n).map_err(|_| ParseError::User { error: fixity::Error::IntOutOfRange { span: (l, r) } })
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> &'input str
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> f64
+{
+    f64::from_str(__0).unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    unescape(&__0[1..__0.len() - 1])
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> char
+{
+    unescape(&__0[1..__0.len() - 1]).chars().next().unwrap()
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    (__0).to_owned()
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    (__0).to_owned()
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> String
+{
+    (__0).to_owned()
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> bool
+{
+    true
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> bool
+{
+    false
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (Spanned<String>, Box<Spanned<Expr>>), usize),
+) -> ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize),
+    (_, e, _): (usize, (Spanned<String>, Box<Spanned<Expr>>), usize),
+) -> ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<String>, usize),
+    (_, __1, _): (usize, Box<Spanned<Expr>>, usize),
+) -> (Spanned<String>, Box<Spanned<Expr>>)
+{
+    (__0, __1)
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    i32::from_str(&format!("-{}", n))
+        .map(|n| Box::new(Spanned::new(Expr::Num(n), (l, r))))
+        .map_err(|_| ParseError::User { error: fixity::Error::IntOutOfRange { span: (l, r) } })
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(match e.node {
+        Expr::Num(n) => Expr::Num(n.wrapping_neg()),
+        Expr::Float(n) => Expr::Float(-n),
+        _ => Expr::UnOp(UnOp::Neg, e),
+    }, (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::UnOp(UnOp::Not, e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<TypeExpr>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> TypeExpr
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+    (_, e, _): (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<TypeExpr>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> TypeExpr
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (String, Vec<TypeExpr>), usize),
+) -> ::std::vec::Vec<(String, Vec<TypeExpr>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+    (_, e, _): (usize, (String, Vec<TypeExpr>), usize),
+) -> ::std::vec::Vec<(String, Vec<TypeExpr>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<(String, Vec<TypeExpr>)>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> ::std::vec::Vec<(String, Vec<TypeExpr>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, (String, Vec<TypeExpr>), usize),
+) -> (String, Vec<TypeExpr>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<String>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<String>, usize),
+) -> ::std::vec::Vec<String>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<Pattern>, usize),
+) -> ::std::vec::Vec<Spanned<Pattern>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action88<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    (_, e, _): (usize, Spanned<Pattern>, usize),
+) -> ::std::vec::Vec<Spanned<Pattern>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Spanned<Pattern>, usize),
+) -> Spanned<Pattern>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action91<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Spanned<Pattern>, usize),
+) -> ::std::vec::Vec<Spanned<Pattern>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    (_, e, _): (usize, Spanned<Pattern>, usize),
+) -> ::std::vec::Vec<Spanned<Pattern>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action94<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Seq(e1, e2), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Seq(e1, e2), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Let(x, e1, e2), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, xs, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut ids = vec![x];
+        ids.extend(xs);
+        Box::new(Spanned::new(Expr::LetTuple(ids, e1, e2), (l, r)))
     }
-    fn __pop_NtId<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, String, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtId(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::LetRec(x, e1, e2), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, b, _): (usize, (String, Spanned<Expr>), usize),
+    (_, bs, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut bindings = vec![b];
+        bindings.extend(bs);
+        Box::new(Spanned::new(Expr::LetRecGroup(bindings, e2), (l, r)))
     }
-    fn __pop_NtIf<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtIf(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, d, _): (usize, TypeDecl, usize),
+    (_, dr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::TypeDef(Spanned::new(d, (l, dr)), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action103<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, a, _): (usize, TypeAlias, usize),
+    (_, ar, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::TypeAlias(Spanned::new(a, (l, ar)), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action104<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, d, _): (usize, (String, Vec<TypeExpr>), usize),
+    (_, dr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let decl = ExceptionDecl { name: d.0, args: d.1 };
+        Box::new(Spanned::new(Expr::Exception(Spanned::new(decl, (l, dr)), e), (l, r)))
     }
-    fn __pop_NtLambda<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtLambda(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action105<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, ::std::option::Option<&'input str>, usize),
+    (_, a, _): (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    (_, arms, _): (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut arms = arms;
+        arms.insert(0, a);
+        Box::new(Spanned::new(Expr::Match(e, arms), (l, r)))
     }
-    fn __pop_NtLet<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtLet(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action106<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, ::std::option::Option<&'input str>, usize),
+    (_, a, _): (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    (_, arms, _): (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut arms = arms;
+        arms.insert(0, a);
+        Box::new(Spanned::new(Expr::Try(e, arms), (l, r)))
     }
-    fn __pop_NtLogicExpr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtLogicExpr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, d, _): (usize, FixityDecl, usize),
+    (_, dr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Fixity(Spanned::new(d, (l, dr)), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action108<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Let(x, e1, e2), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action110<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, xs, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut ids = vec![x];
+        ids.extend(xs);
+        Box::new(Spanned::new(Expr::LetTuple(ids, e1, e2), (l, r)))
     }
-    fn __pop_NtLogicOp<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Op, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtLogicOp(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action111<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e1, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::LetRec(x, e1, e2), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action112<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, b, _): (usize, (String, Spanned<Expr>), usize),
+    (_, bs, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e2, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut bindings = vec![b];
+        bindings.extend(bs);
+        Box::new(Spanned::new(Expr::LetRecGroup(bindings, e2), (l, r)))
     }
-    fn __pop_NtNum<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, i32, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, d, _): (usize, TypeDecl, usize),
+    (_, dr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::TypeDef(Spanned::new(d, (l, dr)), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, a, _): (usize, TypeAlias, usize),
+    (_, ar, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::TypeAlias(Spanned::new(a, (l, ar)), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, d, _): (usize, (String, Vec<TypeExpr>), usize),
+    (_, dr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let decl = ExceptionDecl { name: d.0, args: d.1 };
+        Box::new(Spanned::new(Expr::Exception(Spanned::new(decl, (l, dr)), e), (l, r)))
     }
-    fn __pop_NtRelExpr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtRelExpr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, d, _): (usize, FixityDecl, usize),
+    (_, dr, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Fixity(Spanned::new(d, (l, dr)), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action121<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<TypeExpr>, usize),
+    (_, e, _): (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action122<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, f, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, a, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::App(f, a), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action123<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Raise(e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action124<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action125<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, n, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    i32::from_str(&format!("-{}", n))
+        .map(|n| Box::new(Spanned::new(Expr::Num(n), (l, r))))
+        .map_err(|_| ParseError::User { error: fixity::Error::IntOutOfRange { span: (l, r) } })
+}
+
+#[allow(unused_variables)]
+fn __action126<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(match e.node {
+        Expr::Num(n) => Expr::Num(n.wrapping_neg()),
+        Expr::Float(n) => Expr::Float(-n),
+        _ => Expr::UnOp(UnOp::Neg, e),
+    }, (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action127<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::UnOp(UnOp::Not, e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action128<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action129<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, f, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, a, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::App(f, a), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action130<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Raise(e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action131<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action132<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Field(e, x), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action133<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action134<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, p, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::If(p, t, e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action135<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action136<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, p, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::If(p, t, e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action137<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action138<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action139<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+) -> ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action140<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> (Spanned<Pattern>, Spanned<Expr>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action141<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> ::std::option::Option<&'input str>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action142<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<&'input str>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action143<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action144<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    (_, e, _): (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action145<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, (String, Spanned<Expr>), usize),
+) -> (String, Spanned<Expr>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action146<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action147<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<String>, usize),
+    (_, e, _): (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action148<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, String, usize),
+) -> String
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action149<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action150<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    (_, e, _): (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action151<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Fun(x, None, e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action152<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Spanned<TypeExpr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Fun(x, Some(t), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action153<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action154<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Fun(x, None, e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action155<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Spanned<TypeExpr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Fun(x, Some(t), e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action156<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action157<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, i32, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Num(n), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action158<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, f64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Float(n), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action159<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Str(s), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action160<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, c, _): (usize, char, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Char(c), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action161<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Var(x), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action162<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Bool(b), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action163<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, es, _): (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut elems = vec![*e];
+        elems.extend(es.into_iter().map(|e| *e));
+        Box::new(Spanned::new(Expr::Tuple(elems), (l, r)))
     }
-    fn __pop_NtRelOp<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Op, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtRelOp(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action164<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, f, _): (usize, (String, Spanned<Expr>), usize),
+    (_, fs, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut fields = vec![f];
+        fields.extend(fs);
+        Box::new(Spanned::new(Expr::Record(fields), (l, r)))
     }
-    fn __pop_NtTerm<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action165<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Record(vec![]), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action166<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Spanned<TypeExpr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Annot(e, t), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action167<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Unit, (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action168<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Deref(e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action169<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, op, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Var(op), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action170<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action171<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Field(e, x), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action172<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action173<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, n, _): (usize, f64, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Float(n), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action174<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, s, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Str(s), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action175<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, c, _): (usize, char, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Char(c), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action176<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, x, _): (usize, String, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Var(x), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action177<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, b, _): (usize, bool, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Bool(b), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action178<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, es, _): (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut elems = vec![*e];
+        elems.extend(es.into_iter().map(|e| *e));
+        Box::new(Spanned::new(Expr::Tuple(elems), (l, r)))
     }
-    fn __pop_Nt____Expr<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, Box<Expr>, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+}
+
+#[allow(unused_variables)]
+fn __action179<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, f, _): (usize, (String, Spanned<Expr>), usize),
+    (_, fs, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    {
+        let mut fields = vec![f];
+        fields.extend(fs);
+        Box::new(Spanned::new(Expr::Record(fields), (l, r)))
     }
 }
-pub use self::__parse__Expr::parse_Expr;
-mod __intern_token {
-    #![allow(unused_imports)]
-    use std::str::FromStr;
-    use ast::{Expr, Op};
-    extern crate lalrpop_util as __lalrpop_util;
-    extern crate regex as __regex;
-    pub struct __Matcher<'input> {
-        text: &'input str,
-        consumed: usize,
-        regex_set: __regex::RegexSet,
-        regex_vec: Vec<__regex::Regex>,
-    }
 
-    impl<'input> __Matcher<'input> {
-        pub fn new(s: &'input str) -> __Matcher<'input> {
-            let __strs: &[&str] = &[
-                "^(?u:[0-9])+",
-                "^(?u:[A-Z_-_a-z])(?u:[0-9A-Z_-_a-z])*",
-                "^(?u:\\&\\&)",
-                "^(?u:\\()",
-                "^(?u:\\))",
-                "^(?u:\\*)",
-                "^(?u:\\+)",
-                "^(?u:\\-)",
-                "^(?u:\\->)",
-                "^(?u:/)",
-                "^(?u:<)",
-                "^(?u:=)",
-                "^(?u:>)",
-                "^(?u:else)",
-                "^(?u:false)",
-                "^(?u:fun)",
-                "^(?u:if)",
-                "^(?u:in)",
-                "^(?u:let)",
-                "^(?u:letrec)",
-                "^(?u:then)",
-                "^(?u:true)",
-                "^(?u:\\|\\|)",
-            ];
-            let __regex_set = __regex::RegexSet::new(__strs).unwrap();
-            let __regex_vec = vec![
-                __regex::Regex::new("^(?u:[0-9])+").unwrap(),
-                __regex::Regex::new("^(?u:[A-Z_-_a-z])(?u:[0-9A-Z_-_a-z])*").unwrap(),
-                __regex::Regex::new("^(?u:\\&\\&)").unwrap(),
-                __regex::Regex::new("^(?u:\\()").unwrap(),
-                __regex::Regex::new("^(?u:\\))").unwrap(),
-                __regex::Regex::new("^(?u:\\*)").unwrap(),
-                __regex::Regex::new("^(?u:\\+)").unwrap(),
-                __regex::Regex::new("^(?u:\\-)").unwrap(),
-                __regex::Regex::new("^(?u:\\->)").unwrap(),
-                __regex::Regex::new("^(?u:/)").unwrap(),
-                __regex::Regex::new("^(?u:<)").unwrap(),
-                __regex::Regex::new("^(?u:=)").unwrap(),
-                __regex::Regex::new("^(?u:>)").unwrap(),
-                __regex::Regex::new("^(?u:else)").unwrap(),
-                __regex::Regex::new("^(?u:false)").unwrap(),
-                __regex::Regex::new("^(?u:fun)").unwrap(),
-                __regex::Regex::new("^(?u:if)").unwrap(),
-                __regex::Regex::new("^(?u:in)").unwrap(),
-                __regex::Regex::new("^(?u:let)").unwrap(),
-                __regex::Regex::new("^(?u:letrec)").unwrap(),
-                __regex::Regex::new("^(?u:then)").unwrap(),
-                __regex::Regex::new("^(?u:true)").unwrap(),
-                __regex::Regex::new("^(?u:\\|\\|)").unwrap(),
-            ];
-            __Matcher {
-                text: s,
-                consumed: 0,
-                regex_set: __regex_set,
-                regex_vec: __regex_vec,
-            }
-        }
-    }
+#[allow(unused_variables)]
+fn __action180<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Record(vec![]), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action181<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, t, _): (usize, Spanned<TypeExpr>, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Annot(e, t), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action182<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Unit, (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action183<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Deref(e), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action184<
+    'input,
+>(
+    input: &'input str,
+    (_, l, _): (usize, usize, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, op, _): (usize, String, usize),
+    (_, _, _): (usize, &'input str, usize),
+    (_, r, _): (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    Box::new(Spanned::new(Expr::Var(op), (l, r)))
+}
+
+#[allow(unused_variables)]
+fn __action185<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+    (_, _, _): (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action186<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    vec![]
+}
+
+#[allow(unused_variables)]
+fn __action187<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action188<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, (String, Spanned<Expr>), usize),
+) -> (String, Spanned<Expr>)
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action189<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> ::std::vec::Vec<Box<Spanned<Expr>>>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action190<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    (_, e, _): (usize, Box<Spanned<Expr>>, usize),
+) -> ::std::vec::Vec<Box<Spanned<Expr>>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action191<
+    'input,
+>(
+    input: &'input str,
+    (_, _, _): (usize, &'input str, usize),
+    (_, __0, _): (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action192<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action193<
+    'input,
+>(
+    input: &'input str,
+    (_, v, _): (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    (_, e, _): (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action194<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __6: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __7: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action141(
+        input,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action105(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action195<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action142(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action105(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action196<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __6: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __7: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action141(
+        input,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action106(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action197<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action142(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action106(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action198<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action79(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action75(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action199<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<TypeExpr>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action79(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action200<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+) -> (String, Vec<TypeExpr>)
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action77(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action201<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+    __3: (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> (String, Vec<TypeExpr>)
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action78(
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action202<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, String, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, TypeExpr, usize),
+) -> TypeDecl
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action77(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action203<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, String, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, TypeExpr, usize),
+    __6: (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> TypeDecl
+{
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action78(
+        input,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action204<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action188(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action192(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action205<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action188(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action193(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action206<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Spanned<Expr>), usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action186(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action207<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Spanned<Expr>), usize),
+    __3: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action187(
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action208<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Spanned<Expr>), usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action186(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action179(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action209<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Spanned<Expr>), usize),
+    __3: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action187(
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action179(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action210<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action148(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action146(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action211<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<String>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> ::std::vec::Vec<String>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action148(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action147(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action212<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Spanned<Pattern>, usize),
+) -> ::std::vec::Vec<Spanned<Pattern>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action89(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action213<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Spanned<Pattern>, usize),
+) -> ::std::vec::Vec<Spanned<Pattern>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action89(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action88(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action214<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> ::std::vec::Vec<Box<Spanned<Expr>>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action191(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action189(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action215<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> ::std::vec::Vec<Box<Spanned<Expr>>>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action191(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action190(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action216<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action74(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action120(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action217<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<TypeExpr>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+) -> ::std::vec::Vec<TypeExpr>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action74(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action121(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action218<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+    __3: (usize, &'input str, usize),
+) -> TypeExpr
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action72(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action219<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+    __3: (usize, ::std::vec::Vec<TypeExpr>, usize),
+    __4: (usize, &'input str, usize),
+) -> TypeExpr
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action73(
+        input,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action220<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action145(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action143(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action221<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(String, Spanned<Expr>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action145(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action144(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action222<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action140(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action149(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action223<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action140(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action150(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action224<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action138(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action194(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action225<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __6: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __7: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action139(
+        input,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action194(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action226<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __5.0.clone();
+    let __temp0 = __action138(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action195(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action227<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action139(
+        input,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action195(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action228<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action138(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action196(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action229<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __6: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __7: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action139(
+        input,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action196(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action230<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __5.0.clone();
+    let __temp0 = __action138(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action197(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action231<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, usize, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action139(
+        input,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action197(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action232<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Vec<TypeExpr>), usize),
+) -> ::std::vec::Vec<(String, Vec<TypeExpr>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action84(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action80(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action233<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Vec<TypeExpr>), usize),
+) -> ::std::vec::Vec<(String, Vec<TypeExpr>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action84(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action81(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action234<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (String, Vec<TypeExpr>), usize),
+) -> TypeDecl
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action82(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action235<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (String, Vec<TypeExpr>), usize),
+    __5: (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
+{
+    let __start0 = __5.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action83(
+        input,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action236<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Spanned<String>, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action67(
+        input,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action65(
+        input,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action237<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize),
+    __1: (usize, Spanned<String>, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action67(
+        input,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action238<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action132(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action239<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action171(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action240<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action122(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action241<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action123(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action242<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action129(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action243<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action130(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action244<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action32(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action245<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action246<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, i32, usize),
+    __1: (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action247<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Result<Spanned<Pattern>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action248<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action13(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action249<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Spanned<Pattern>, usize),
+    __2: (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action250<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action134(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action251<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action136(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action252<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action34(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action253<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action154(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action254<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Spanned<TypeExpr>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Spanned<Expr>>, usize),
+    __8: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action155(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action255<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action151(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action256<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Spanned<TypeExpr>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Spanned<Expr>>, usize),
+    __8: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action152(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action257<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action109(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action258<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, ::std::vec::Vec<String>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Spanned<Expr>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Spanned<Expr>>, usize),
+    __9: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action110(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action259<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action111(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action260<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action112(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action261<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeDecl, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action113(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action262<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeAlias, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action114(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action263<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Vec<TypeExpr>), usize),
+    __2: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action115(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action264<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, FixityDecl, usize),
+    __1: (usize, usize, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action116(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action265<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action98(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action266<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, ::std::vec::Vec<String>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Spanned<Expr>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Spanned<Expr>>, usize),
+    __9: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action99(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __9,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action267<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action100(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action268<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action101(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action269<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeDecl, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action102(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action270<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeAlias, usize),
+    __2: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action103(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action271<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Vec<TypeExpr>), usize),
+    __2: (usize, usize, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action104(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action272<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action224(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action273<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action225(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action274<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action226(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action275<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __4: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action227(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action276<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action228(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action277<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __6: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action229(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action278<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action230(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action279<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __4: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action231(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action280<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, FixityDecl, usize),
+    __1: (usize, usize, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action107(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action281<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action53(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action282<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'static str, usize),
+    __1: (usize, usize, usize),
+) -> Spanned<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action36(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action283<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Spanned<String>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action37(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action284<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    __2: (usize, usize, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action285<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action94(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action286<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action96(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action287<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, TypeExpr, usize),
+    __1: (usize, usize, usize),
+) -> Spanned<TypeExpr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action288<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, i32, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action289<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, f64, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action158(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action290<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action159(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action291<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, char, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action160(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action292<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action161(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action293<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action162(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action294<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action295<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action206(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action296<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action207(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action297<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action298<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Spanned<TypeExpr>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action166(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action299<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action167(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action300<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action168(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action301<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action169(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action302<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, f64, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action173(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action303<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action304<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, char, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action175(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action305<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action176(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action306<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, bool, usize),
+    __1: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action307<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action178(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action308<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action208(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action309<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action209(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action310<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action311<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Spanned<TypeExpr>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action181(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action312<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action182(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action313<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action183(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action314<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action184(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action315<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action316<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action317<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action70(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action318<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, usize, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action125(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action319<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action320<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, usize, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action93(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action127(
+        input,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action321<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action238(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action322<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action239(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action323<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action240(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action324<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action241(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action325<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action242(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action326<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action243(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action327<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action244(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action328<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action245(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action329<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, i32, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action246(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action330<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Result<Spanned<Pattern>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action247(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action331<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, bool, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action248(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action332<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Spanned<Pattern>, usize),
+    __2: (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action249(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action333<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action250(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action334<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action251(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action335<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, ::std::vec::Vec<(Spanned<String>, Box<Spanned<Expr>>)>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action252(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action336<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action253(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action337<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Spanned<TypeExpr>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action254(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action338<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action255(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action339<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Spanned<TypeExpr>, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, &'input str, usize),
+    __7: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __7.2.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action256(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action340<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action257(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action341<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, ::std::vec::Vec<String>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Spanned<Expr>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action258(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action342<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action259(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action343<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action260(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action344<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeDecl, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action261(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action345<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeAlias, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action262(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action346<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Vec<TypeExpr>), usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action263(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action347<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, FixityDecl, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action264(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action348<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action265(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action349<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, ::std::vec::Vec<String>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, &'input str, usize),
+    __6: (usize, Box<Spanned<Expr>>, usize),
+    __7: (usize, &'input str, usize),
+    __8: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __8.2.clone();
+    let __end0 = __8.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action266(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action350<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action267(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action351<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action268(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action352<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeDecl, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action269(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action353<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, TypeAlias, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action270(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action354<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Vec<TypeExpr>), usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __start1 = __3.2.clone();
+    let __end1 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action271(
+        input,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action355<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action272(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action356<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action273(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action357<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action274(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action358<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __4: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action275(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action359<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action276(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action360<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __5: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action277(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action361<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action278(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action362<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (Spanned<Pattern>, Spanned<Expr>), usize),
+    __4: (usize, ::std::vec::Vec<(Spanned<Pattern>, Spanned<Expr>)>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action279(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action363<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, FixityDecl, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __start1 = __2.2.clone();
+    let __end1 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    let __temp1 = __action90(
+        input,
+        &__start1,
+        &__end1,
+    );
+    let __temp1 = (__start1, __temp1, __end1);
+    __action280(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __temp1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action364<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action281(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action365<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'static str, usize),
+) -> Spanned<String>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action282(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action366<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+) -> Spanned<String>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action283(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action367<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<Spanned<Pattern>>, usize),
+) -> Spanned<Pattern>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action284(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action368<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action285(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action369<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, Box<Spanned<Expr>>, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action286(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action370<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, TypeExpr, usize),
+) -> Spanned<TypeExpr>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action287(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action371<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, i32, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action288(
+        input,
+        __0,
+        __temp0,
+    )
+}
 
-    impl<'input> Iterator for __Matcher<'input> {
-        type Item = Result<(usize, (usize, &'input str), usize), __lalrpop_util::ParseError<usize,(usize, &'input str),()>>;
+#[allow(unused_variables)]
+fn __action372<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, f64, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action289(
+        input,
+        __0,
+        __temp0,
+    )
+}
 
-        fn next(&mut self) -> Option<Self::Item> {
-            let __text = self.text.trim_left();
-            let __whitespace = self.text.len() - __text.len();
-            let __start_offset = self.consumed + __whitespace;
-            if __text.is_empty() {
-                self.text = __text;
-                self.consumed = __start_offset;
-                None
-            } else {
-                let __matches = self.regex_set.matches(__text);
-                if !__matches.matched_any() {
-                    Some(Err(__lalrpop_util::ParseError::InvalidToken {
-                        location: __start_offset,
-                    }))
-                } else {
-                    let mut __longest_match = 0;
-                    let mut __index = 0;
-                    for __i in 0 .. 23 {
-                        if __matches.matched(__i) {
-                            let __match = self.regex_vec[__i].find(__text).unwrap();
-                            let __len = __match.end();
-                            if __len >= __longest_match {
-                                __longest_match = __len;
-                                __index = __i;
-                            }
-                        }
-                    }
-                    let __result = &__text[..__longest_match];
-                    let __remaining = &__text[__longest_match..];
-                    let __end_offset = __start_offset + __longest_match;
-                    self.text = __remaining;
-                    self.consumed = __end_offset;
-                    Some(Ok((__start_offset, (__index, __result), __end_offset)))
-                }
-            }
-        }
-    }
+#[allow(unused_variables)]
+fn __action373<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+) -> Box<Spanned<Expr>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action290(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action0<
+fn __action374<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, char, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action291(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action1<
+fn __action375<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, String, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action292(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action2<
+fn __action376<
     'input,
 >(
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, bool, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Let(__0, __1, __2))
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action293(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action3<
+fn __action377<
     'input,
 >(
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::LetRec(__0, __1, __2))
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action294(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action4<
+fn __action378<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action295(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action5<
+fn __action379<
     'input,
 >(
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::If(__0, __1, __2))
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action296(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action6<
+fn __action380<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action297(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action381<
     'input,
 >(
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, String, usize),
-    (_, _, _): (usize, &'input str, usize),
-    (_, __1, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Spanned<TypeExpr>, usize),
+    __4: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Fun(__0, __1))
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action298(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action382<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action299(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action383<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Op, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::BinOp(__0, __1, __2))
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action300(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action384<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action301(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action385<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, f64, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::And
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action302(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action386<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, String, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Or
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action303(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action387<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Op, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, char, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::BinOp(__0, __1, __2))
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action304(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action388<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, String, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action305(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action389<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, bool, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Gt
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action306(
+        input,
+        __0,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action390<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, ::std::vec::Vec<Box<Spanned<Expr>>>, usize),
+    __3: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Lt
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action307(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action391<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Equal
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action308(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action392<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Op, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, (String, Spanned<Expr>), usize),
+    __2: (usize, ::std::vec::Vec<(String, Spanned<Expr>)>, usize),
+    __3: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::BinOp(__0, __1, __2))
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action309(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action393<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action310(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action394<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, Spanned<TypeExpr>, usize),
+    __4: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Add
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action311(
+        input,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action395<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Sub
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action312(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action396<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Op, usize),
-    (_, __2, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::BinOp(__0, __1, __2))
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action313(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action397<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, String, usize),
+    __2: (usize, &'input str, usize),
+) -> Box<Spanned<Expr>>
 {
-    (__0)
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action314(
+        input,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action398<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
 {
-    Op::Mul
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action315(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action399<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> Op
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
 {
-    Op::Div
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action316(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action400<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, __1, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::App(__0, __1))
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action317(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action401<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, Box<Expr>, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, &'input str, usize),
+) -> Result<Box<Spanned<Expr>>,__lalrpop_util::ParseError<usize,(usize, &'input str),fixity::Error>>
 {
-    (__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action318(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action402<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, i32, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Num(__0))
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action319(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action403<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, String, usize),
-) -> Box<Expr>
+    __0: (usize, &'input str, usize),
+    __1: (usize, Box<Spanned<Expr>>, usize),
+) -> Box<Spanned<Expr>>
 {
-    Box::new(Expr::Var(__0))
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action90(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action320(
+        input,
+        __0,
+        __1,
+        __temp0,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action404<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, bool, usize),
-) -> Box<Expr>
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, TypeExpr, usize),
+) -> TypeAlias
 {
-    Box::new(Expr::Bool(__0))
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action405<
     'input,
 >(
     input: &'input str,
-    (_, _, _): (usize, &'input str, usize),
-    (_, __0, _): (usize, Box<Expr>, usize),
-    (_, _, _): (usize, &'input str, usize),
-) -> Box<Expr>
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, TypeExpr, usize),
+) -> TypeAlias
 {
-    (__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action86(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action406<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> i32
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (String, Vec<TypeExpr>), usize),
+) -> TypeDecl
 {
-    i32::from_str(__0).unwrap()
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action234(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action407<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> String
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (String, Vec<TypeExpr>), usize),
+) -> TypeDecl
 {
-    (__0).to_owned()
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action86(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action234(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action408<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (String, Vec<TypeExpr>), usize),
+    __4: (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
 {
-    true
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action235(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action409<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
-) -> bool
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, (String, Vec<TypeExpr>), usize),
+    __5: (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
 {
-    false
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action86(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action235(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action410<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, (String, Vec<TypeExpr>), usize),
+    __3: (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action411<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, (String, Vec<TypeExpr>), usize),
+    __4: (usize, ::std::vec::Vec<(String, Vec<TypeExpr>)>, usize),
+) -> TypeDecl
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action86(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action412<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, TypeExpr, usize),
+) -> TypeDecl
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action202(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action413<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, String, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, TypeExpr, usize),
+) -> TypeDecl
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action86(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action202(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action414<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, &'input str, usize),
+    __2: (usize, String, usize),
+    __3: (usize, &'input str, usize),
+    __4: (usize, TypeExpr, usize),
+    __5: (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> TypeDecl
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action203(
+        input,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action415<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, String, usize),
+    __1: (usize, ::std::vec::Vec<String>, usize),
+    __2: (usize, &'input str, usize),
+    __3: (usize, String, usize),
+    __4: (usize, &'input str, usize),
+    __5: (usize, TypeExpr, usize),
+    __6: (usize, ::std::vec::Vec<TypeExpr>, usize),
+) -> TypeDecl
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action86(
+        input,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action203(
+        input,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
 }
 
 pub trait __ToTriple<'input, > {
@@ -1983,14 +20311,14 @@ pub trait __ToTriple<'input, > {
 }
 
 impl<'input, > __ToTriple<'input, > for (usize, (usize, &'input str), usize) {
-    type Error = ();
-    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),()> {
+    type Error = fixity::Error;
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),fixity::Error> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, (usize, &'input str), usize),()> {
-    type Error = ();
-    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),()> {
+impl<'input, > __ToTriple<'input, > for Result<(usize, (usize, &'input str), usize),fixity::Error> {
+    type Error = fixity::Error;
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),fixity::Error> {
         value
     }
 }
@@ -1,109 +1,461 @@
 use std::fmt::Display;
 use std::fmt;
-use std::collections::HashSet;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::rc::Rc;
 use ast::*;
+use im_rc;
+use lalrpop_util::ParseError;
+use exhaustive;
+use fixity;
+use grammar;
+use stack;
 
-/// An enum of errors that can occur
-#[derive(Debug, Clone)]
+/// An enum of errors that can occur, each carrying the span of the offending subexpression
+#[derive(Debug, Clone, PartialEq)]
 pub enum Error {
-    UndefinedName(String),
-    TypeError(String),
+    /// Two types that should have been equal were not. `path` describes where the mismatch
+    /// sits inside the types being unified, outermost first
+    Mismatch { expected: Box<PrimitiveType>, found: Box<PrimitiveType>, path: Vec<String>, span: Span },
+    /// A type variable would have to contain itself
+    OccursCheck { var: u32, typ: Box<PrimitiveType>, span: Span },
+    /// A type constructor was used with the wrong number of arguments
+    ArityMismatch { con: String, expected: usize, found: usize, span: Span },
+    /// A name was used without being bound, along with similarly spelled names that are bound
+    UndefinedName { name: String, suggestions: Vec<String>, span: Span },
+    /// A value that is not a function was applied to an argument
+    NotAFunction { typ: Box<PrimitiveType>, span: Span },
+    /// A type name or type variable was used without being declared
+    UndefinedType { name: String, span: Span },
+    /// A record was used at a type that needs a field it does not have
+    MissingField { field: String, record: Box<PrimitiveType>, span: Span },
+    /// A record literal gives the same field twice
+    DuplicateField { field: String, span: Span },
+    /// A type declaration gives the same constructor twice
+    DuplicateConstructor { name: String, span: Span },
+    /// A `letrec` binds a name to something other than a function, whose value could need
+    /// itself before it is built
+    RecursiveValue { name: String, span: Span },
+    /// An annotation claimed a type more polymorphic than the expression it annotates
+    AnnotationTooGeneral { annotation: Box<TypeExpr>, inferred: Box<PrimitiveType>, span: Span },
+    /// A type was used where a class constraint requires an instance it does not have,
+    /// such as comparing two functions
+    NoInstance { class: String, typ: Box<PrimitiveType>, span: Span },
+    /// An error with a secondary label pointing at related source text
+    Labeled { error: Box<Error>, span: Span, label: String },
+}
+impl Error {
+    /// The span of source text the error was reported at
+    pub fn span(&self) -> Span {
+        match *self {
+            Error::Mismatch { span, .. } |
+            Error::OccursCheck { span, .. } |
+            Error::ArityMismatch { span, .. } |
+            Error::UndefinedName { span, .. } |
+            Error::NotAFunction { span, .. } |
+            Error::UndefinedType { span, .. } |
+            Error::MissingField { span, .. } |
+            Error::DuplicateField { span, .. } |
+            Error::DuplicateConstructor { span, .. } |
+            Error::RecursiveValue { span, .. } |
+            Error::AnnotationTooGeneral { span, .. } |
+            Error::NoInstance { span, .. } => span,
+            Error::Labeled { ref error, .. } => error.span(),
+        }
+    }
+    /// A stable code identifying the kind of error
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Error::Mismatch { .. } => "E0001",
+            Error::OccursCheck { .. } => "E0002",
+            Error::ArityMismatch { .. } => "E0003",
+            Error::UndefinedName { .. } => "E0004",
+            Error::NotAFunction { .. } => "E0005",
+            Error::UndefinedType { .. } => "E0006",
+            Error::MissingField { .. } => "E0007",
+            Error::DuplicateField { .. } => "E0008",
+            Error::AnnotationTooGeneral { .. } => "E0009",
+            Error::NoInstance { .. } => "E0010",
+            Error::DuplicateConstructor { .. } => "E0011",
+            Error::RecursiveValue { .. } => "E0012",
+            Error::Labeled { ref error, .. } => error.code(),
+        }
+    }
+    /// The error with any secondary labels stripped off
+    pub fn primary(&self) -> &Error {
+        match *self {
+            Error::Labeled { ref error, .. } => error.primary(),
+            ref err => err,
+        }
+    }
+    /// Secondary labels attached to the error, innermost first
+    pub fn labels(&self) -> Vec<(Span, String)> {
+        match *self {
+            Error::Labeled { ref error, span, ref label } => {
+                let mut labels = error.labels();
+                labels.push((span, label.clone()));
+                labels
+            },
+            _ => vec![],
+        }
+    }
+    fn with_label(self, span: Span, label: String) -> Error {
+        Error::Labeled { error: Box::new(self), span, label }
+    }
+    /// Records that a mismatch occurred inside the part of a type described by `part`
+    fn within(self, part: String) -> Error {
+        match self {
+            Error::Mismatch { expected, found, mut path, span } => {
+                path.insert(0, part);
+                Error::Mismatch { expected, found, path, span }
+            },
+            err => err,
+        }
+    }
 }
 impl Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", match *self {
-            Error::UndefinedName(ref name) => format!("{} is undefined", name),
-            Error::TypeError(ref msg) => format!("Type error: {}", msg),
-        })
+        let (l, r) = self.span();
+        let msg = match *self.primary() {
+            Error::Mismatch { ref expected, ref found, ref path, .. } => {
+                let (expected, found) = (expected.to_string(), found.to_string());
+                let mut msg = if expected == found {
+                    format!("Type error: expected {}, found a different type also written {}", expected, found)
+                } else {
+                    format!("Type error: expected {}, found {}", expected, found)
+                };
+                for part in path.iter().rev() {
+                    msg = format!("{} in {}", msg, part);
+                }
+                msg
+            },
+            Error::OccursCheck { var, ref typ, .. } => format!("Type error: cannot construct infinite type '{} = {}", var, typ),
+            Error::ArityMismatch { ref con, expected, found, .. } => {
+                format!("Type error: {} expects {} type argument(s), found {}", con, expected, found)
+            },
+            Error::UndefinedName { ref name, ref suggestions, .. } => match suggestions.first() {
+                Some(s) => format!("{} is undefined, did you mean {}?", name, s),
+                None => format!("{} is undefined", name),
+            },
+            Error::NotAFunction { ref typ, .. } => format!("Type error: {} is not a function and cannot be applied", typ),
+            Error::UndefinedType { ref name, .. } => format!("Type error: type {} is undefined", name),
+            Error::MissingField { ref field, ref record, .. } => format!("Type error: {} has no field {}", record, field),
+            Error::DuplicateField { ref field, .. } => format!("field {} is given more than once", field),
+            Error::DuplicateConstructor { ref name, .. } => format!("constructor {} is given more than once", name),
+            Error::RecursiveValue { ref name, .. } => format!("letrec can only define functions, but {} is not one", name),
+            Error::AnnotationTooGeneral { ref annotation, ref inferred, .. } => {
+                format!("Type error: the annotation {} is more general than the inferred type {}", annotation, inferred)
+            },
+            Error::NoInstance { ref typ, .. } if is_function(typ) => format!("Type error: functions like {} cannot be compared", typ),
+            Error::NoInstance { ref class, ref typ, .. } => format!("Type error: {} is not an instance of {}", typ, class),
+            Error::Labeled { .. } => unreachable!(),
+        };
+        write!(f, "[{}] {} (at {}..{})", self.code(), msg, l, r)
+    }
+}
+
+/// Whether `typ` is a function type, looking through aliases
+pub fn is_function(typ: &PrimitiveType) -> bool {
+    matches!(*typ.unalias(), PrimitiveType::Fun(..))
+}
+
+/// Problems that do not stop a program from type checking but probably indicate a mistake
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// A match does not cover every value. `missing` is an example of a value it misses
+    NonExhaustive { missing: String, span: Span },
+    /// A match arm can never be reached because earlier arms match everything it does
+    Redundant { span: Span },
+    /// A value other than `()` is thrown away on the left of a `;`
+    DiscardedValue { typ: Box<PrimitiveType>, span: Span },
+}
+impl Warning {
+    pub fn span(&self) -> Span {
+        match *self {
+            Warning::NonExhaustive { span, .. } | Warning::Redundant { span } | Warning::DiscardedValue { span, .. } => span,
+        }
+    }
+    /// A stable code identifying the kind of warning
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Warning::NonExhaustive { .. } => "W0001",
+            Warning::Redundant { .. } => "W0002",
+            Warning::DiscardedValue { .. } => "W0003",
+        }
+    }
+}
+impl Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (l, r) = self.span();
+        let msg = match *self {
+            Warning::NonExhaustive { ref missing, .. } => format!("non-exhaustive match, {} is not covered", missing),
+            Warning::Redundant { .. } => "unreachable match arm".to_owned(),
+            Warning::DiscardedValue { ref typ, .. } => format!("a value of type {} is discarded", typ),
+        };
+        write!(f, "[{}] {} (at {}..{})", self.code(), msg, l, r)
     }
 }
 
 /// A type allias to make type definitions shorter
 pub type Result<T> = ::std::result::Result<T, Error>;
 
-/// A mapping between names and their types
+/// A user or builtin type definition: its parameters and the value constructors that build it
 #[derive(Debug, Clone)]
-pub enum Enviroment {
-    Empty,
-    Frame(String, TypeScheme, Box<Enviroment>),
+pub struct TypeDef {
+    pub params: Vec<u32>,
+    /// Constructor names and argument types. Empty for builtin types like `int` that have none
+    pub ctors: Vec<(String, Vec<PrimitiveType>)>,
+    /// The type this name abbreviates, in terms of `params`, if it is an alias
+    pub alias: Option<PrimitiveType>,
+    /// Tells apart declarations of the same name. 0 for builtin types and aliases
+    pub stamp: u32,
+}
+
+/// A type class, such as `Ord`, along with the classes every instance of it is also in
+#[derive(Debug, Clone)]
+pub struct ClassDef {
+    pub superclasses: Vec<String>,
+}
+
+/// A declaration that the type constructor `con` is in `class`, provided each of its type
+/// arguments is in every class in `requires`. Eg. `Eq 'a => Eq List<'a>`
+#[derive(Debug, Clone)]
+pub struct Instance {
+    pub class: String,
+    pub con: String,
+    pub requires: Vec<String>,
+}
+
+/// A mapping between names and their types, between type names and their definitions,
+/// and the type classes and instances in scope. Each kind of binding lives in a persistent
+/// map, so lookups take logarithmic time and extending an enviroment shares all of it with
+/// the new one rather than copying it
+#[derive(Debug, Clone, Default)]
+pub struct Enviroment {
+    names: im_rc::HashMap<String, TypeScheme>,
+    types: im_rc::HashMap<String, Rc<TypeDef>>,
+    /// The type each value constructor belongs to, and its definition
+    ctors: im_rc::HashMap<String, (String, Rc<TypeDef>)>,
+    classes: im_rc::HashMap<String, ClassDef>,
+    /// Instances by class and type constructor
+    instances: im_rc::HashMap<(String, String), Instance>,
+    /// The type variables free in any binding made so far, including shadowed ones, as of when
+    /// it was made. Kept up to date as bindings are added so generalizing never walks `names`
+    free: im_rc::HashSet<u32>,
 }
 impl Enviroment {
     pub fn empty() -> Enviroment {
-        Enviroment::Empty
+        Enviroment::default()
     }
+    /// Binds a value, hiding any constructor of the same name as the backends do
     pub fn extend(&self, n: String, t: TypeScheme) -> Enviroment {
-        Enviroment::Frame(n, t, Box::new(self.clone()))
+        let mut env = self.extend_constructor(n.clone(), t);
+        env.ctors.remove(&n);
+        env
+    }
+    /// Binds the function or value of a constructor that `extend_type` has registered
+    pub fn extend_constructor(&self, n: String, t: TypeScheme) -> Enviroment {
+        let mut env = self.clone();
+        env.free.extend(t.type_vars());
+        env.names.insert(n, t);
+        env
+    }
+    pub fn extend_type(&self, n: String, def: TypeDef) -> Enviroment {
+        let mut env = self.clone();
+        let def = Rc::new(def);
+        for c in &def.ctors {
+            env.ctors.insert(c.0.clone(), (n.clone(), def.clone()));
+        }
+        env.types.insert(n, def);
+        env
+    }
+    pub fn extend_class(&self, n: String, def: ClassDef) -> Enviroment {
+        let mut env = self.clone();
+        env.classes.insert(n, def);
+        env
+    }
+    pub fn extend_instance(&self, inst: Instance) -> Enviroment {
+        let mut env = self.clone();
+        env.instances.insert((inst.class.clone(), inst.con.clone()), inst);
+        env
     }
     pub fn lookup(&self, key: &str) -> Option<TypeScheme> {
-        match *self {
-            Enviroment::Empty => None,
-            Enviroment::Frame(ref n, ref s, ref p) => {
-                if n == key {
-                    Some(s.clone())
-                } else {
-                    p.lookup(key)
-                }
+        self.names.get(key).cloned()
+    }
+    pub fn lookup_type(&self, key: &str) -> Option<TypeDef> {
+        self.types.get(key).map(|def| (**def).clone())
+    }
+    /// Finds the type a value constructor belongs to, returning the type's name and definition
+    pub fn lookup_constructor(&self, ctor: &str) -> Option<(String, TypeDef)> {
+        self.ctors.get(ctor).map(|(n, def)| (n.clone(), (**def).clone()))
+    }
+    pub fn lookup_class(&self, key: &str) -> Option<ClassDef> {
+        self.classes.get(key).cloned()
+    }
+    /// Finds the instance of `class` for the type constructor `con`
+    pub fn lookup_instance(&self, class: &str, con: &str) -> Option<Instance> {
+        self.instances.get(&(class.to_owned(), con.to_owned())).cloned()
+    }
+    /// Whether every instance of `class` is also an instance of `other`
+    pub fn implies(&self, class: &str, other: &str) -> bool {
+        class == other || self.lookup_class(class).is_some_and(|def| {
+            def.superclasses.iter().any(|c| self.implies(c, other))
+        })
+    }
+    /// Bound names within a small edit distance of `name`, closest first
+    pub fn similar_names(&self, name: &str) -> Vec<String> {
+        let mut names = vec![];
+        for n in self.names.keys() {
+            let dist = edit_distance(n, name);
+            if dist <= 2 && dist < name.len() {
+                names.push((dist, n.clone()));
             }
         }
+        names.sort();
+        names.into_iter().map(|(_, n)| n).collect()
     }
     pub fn schemes(&self) -> Vec<TypeScheme> {
-        let mut schemes = vec![];
-        let mut s = self;
-        loop {
-            match *s {
-                Enviroment::Empty => return schemes,
-                Enviroment::Frame(_, ref scm, ref p) => {
-                    schemes.push(scm.clone());
-                    s = p;
-                }
-            }
-        }
+        self.names.values().cloned().collect()
     }
     pub fn type_vars(&self) -> HashSet<u32> {
-        let mut vars = HashSet::new();
-        for scm in &self.schemes() {
-            vars = vars.union(&scm.type_vars()).cloned().collect();
+        self.free.iter().cloned().collect()
+    }
+}
+
+/// The Levenshtein distance between two names
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..b.len() + 1).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let cur = row[j + 1];
+            row[j + 1] = if ca == cb { prev } else { 1 + prev.min(cur).min(row[j]) };
+            prev = cur;
         }
-        vars
     }
+    row[b.len()]
 }
 
+/// The types bound to type variables so far. Copies of a substitution share one mutable
+/// store, like the sets of a union-find structure: extending any of them binds the variable in
+/// all of them, and applying one shortens the chains of bindings it follows. Inference never
+/// backtracks, so only the speed of this is observable
 #[derive(Debug, Clone)]
 pub struct Subst {
-    prev: Option<Box<Subst>>,
-    x: Option<PrimitiveType>,
-    t: Option<PrimitiveType>,
+    bindings: Rc<RefCell<HashMap<u32, PrimitiveType>>>,
 }
 impl Subst {
     pub fn empty() -> Subst {
-        Subst {
-            prev: None,
-            x: None,
-            t: None,
-        }
+        Subst { bindings: Rc::new(RefCell::new(HashMap::new())) }
     }
     pub fn extend(&self, x: PrimitiveType, t: PrimitiveType) -> Subst {
-        Subst {
-            prev: Some(Box::new(self.clone())),
-            x: Some(x),
-            t: Some(t),
+        match x {
+            PrimitiveType::Var(a) => {
+                self.bindings.borrow_mut().insert(a, t);
+                self.clone()
+            },
+            x => panic!("cannot bind {}, which is not a type variable", x),
         }
     }
     pub fn lookup(&self, y: &PrimitiveType) -> PrimitiveType {
-        if let Some(ref prev) = self.prev {
-            if self.x == Some(y.clone()) { self.t.clone().unwrap() } else {prev.lookup(y)}
-        } else {
-            y.clone()
+        match *y {
+            PrimitiveType::Var(a) => self.bindings.borrow().get(&a).cloned().unwrap_or_else(|| y.clone()),
+            _ => y.clone(),
         }
     }
     pub fn apply(&self, t: &PrimitiveType) -> PrimitiveType {
+        stack::guard(|| self.apply_inner(t))
+    }
+    fn apply_inner(&self, t: &PrimitiveType) -> PrimitiveType {
         match *t {
-            ref tv @ PrimitiveType::Var(_) => {
-                let u = self.lookup(tv);
-                if *t == u { t.clone() } else { self.apply(&u) }
+            PrimitiveType::Var(a) => {
+                let mut chain = vec![a];
+                let mut bound = match self.bindings.borrow().get(&a) {
+                    Some(u) => u.clone(),
+                    None => return t.clone(),
+                };
+                while let PrimitiveType::Var(b) = bound {
+                    match self.bindings.borrow().get(&b) {
+                        Some(u) => {
+                            chain.push(b);
+                            bound = u.clone();
+                        },
+                        None => break,
+                    }
+                }
+                // Point every variable on the chain at its end, so that the next lookup skips
+                // the chain. Binding them to the applied type instead would copy a deep type
+                // once per variable inside it
+                if chain.len() > 1 {
+                    let mut bindings = self.bindings.borrow_mut();
+                    for v in chain {
+                        bindings.insert(v, bound.clone());
+                    }
+                }
+                self.apply(&bound)
             },
             PrimitiveType::Fun(ref a, ref r) => PrimitiveType::Fun(Box::new(self.apply(a)), Box::new(self.apply(r))),
-            PrimitiveType::Con(ref name, ref typs) => PrimitiveType::Con(name.clone(), typs.iter().map(|t| self.apply(t)).collect())
+            PrimitiveType::Con(ref name, ref typs) => PrimitiveType::Con(name.clone(), typs.iter().map(|t| self.apply(t)).collect()),
+            PrimitiveType::Row(ref fields, ref rest) => {
+                let fields = fields.iter().map(|(n, t)| (n.clone(), self.apply(t))).collect();
+                record_type(fields, rest.as_ref().map(|r| self.apply(r)))
+            },
+            PrimitiveType::Alias(ref name, ref args, ref typ) => {
+                PrimitiveType::Alias(name.clone(), args.iter().map(|t| self.apply(t)).collect(), Box::new(self.apply(typ)))
+            }
+        }
+    }
+    /// The enviroment with the substitution applied to the variables free in its bindings, so
+    /// that generalizing against it sees the variables the enviroment really mentions. This
+    /// touches only those variables; the bindings themselves are shared unchanged
+    pub fn apply_env(&self, env: &Enviroment) -> Enviroment {
+        let mut free = im_rc::HashSet::new();
+        for &a in &env.free {
+            free.extend(self.apply(&PrimitiveType::Var(a)).type_vars());
+        }
+        Enviroment { free, ..env.clone() }
+    }
+}
+
+/// A class constraint on a type, such as `Num 'a`, and the span of the expression that needs it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pred {
+    pub class: String,
+    pub typ: PrimitiveType,
+    pub span: Span,
+}
+impl Pred {
+    pub fn new(class: &str, typ: PrimitiveType, span: Span) -> Pred {
+        Pred { class: class.to_owned(), typ, span }
+    }
+    fn apply(&self, s: &Subst) -> Pred {
+        Pred { class: self.class.clone(), typ: s.apply(&self.typ), span: self.span }
+    }
+}
+impl Display for Pred {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.class, self.typ)
+    }
+}
+
+/// A type along with the class constraints on its variables: `Num 'a => ('a -> 'a)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qualified {
+    pub preds: Vec<Pred>,
+    pub typ: PrimitiveType,
+}
+impl Display for Qualified {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.preds.len() {
+            0 => write!(f, "{}", self.typ),
+            1 => write!(f, "{} => {}", self.preds[0], self.typ),
+            _ => {
+                let preds: Vec<String> = self.preds.iter().map(|p| p.to_string()).collect();
+                write!(f, "({}) => {}", preds.join(", "), self.typ)
+            }
         }
     }
 }
@@ -112,119 +464,776 @@ impl Subst {
 pub struct TypeScheme {
     typ: PrimitiveType,
     vars: HashSet<u32>,
+    /// Constraints on the quantified variables
+    preds: Vec<Pred>,
 }
 impl TypeScheme {
     pub fn new(typ: PrimitiveType, vars: HashSet<u32>) -> TypeScheme {
-        TypeScheme{typ, vars}
+        TypeScheme{typ, vars, preds: vec![]}
     }
     pub fn new_instance(&self, var_gen: &mut VarGenerator) -> PrimitiveType {
-        self.vars.iter().fold(Subst::empty(), |sub, var| sub.extend(PrimitiveType::Var(*var), 
-                                                                    var_gen.next_typevar())).apply(&self.typ)
+        self.instantiate(var_gen).0
+    }
+    /// A fresh instance of the scheme along with the constraints it places on that instance
+    pub fn instantiate(&self, var_gen: &mut VarGenerator) -> (PrimitiveType, Vec<Pred>) {
+        let s = self.vars.iter().fold(Subst::empty(), |sub, var| sub.extend(PrimitiveType::Var(*var), 
+                                                                           var_gen.next_typevar()));
+        (s.apply(&self.typ), self.preds.iter().map(|p| p.apply(&s)).collect())
     }
     pub fn type_vars(&self) -> HashSet<u32> {
         self.typ.type_vars().difference(&self.vars).cloned().collect()
     }
-    pub fn from_type(t: &PrimitiveType, env: &Enviroment) -> TypeScheme {
-        TypeScheme::new(t.clone(), t.type_vars().difference(&env.type_vars()).cloned().collect())
+    /// Generalizes `t` over the variables not free in `env`, or over none if it is not the type
+    /// of a value. The constraints in `preds` are simplified using the instances in `env`; those
+    /// on generalized variables become part of the scheme, and those on other variables the type
+    /// or `env` mentions are returned to be solved later
+    pub fn from_type(t: &PrimitiveType, preds: Vec<Pred>, value: bool, env: &Enviroment) -> Result<(TypeScheme, Vec<Pred>)> {
+        let mut env_vars = env.type_vars();
+        if !value {
+            env_vars.extend(t.type_vars());
+        }
+        let vars: HashSet<u32> = t.type_vars().difference(&env_vars).cloned().collect();
+        let mut kept = vec![];
+        let mut deferred = vec![];
+        for p in simplify(preds, env)? {
+            match p.typ {
+                PrimitiveType::Var(v) if env_vars.contains(&v) => deferred.push(p),
+                PrimitiveType::Var(v) if vars.contains(&v) => kept.push(p),
+                // A constraint on a variable that appears nowhere is ambiguous but harmless
+                _ => {},
+            }
+        }
+        Ok((TypeScheme { typ: t.clone(), vars, preds: kept }, deferred))
+    }
+}
+
+/// Reduces constraints on constructed types to constraints on type variables using the
+/// instances in `env`, failing if a type is in no instance of the class. Constraints implied
+/// by another through a superclass are dropped
+fn simplify(preds: Vec<Pred>, env: &Enviroment) -> Result<Vec<Pred>> {
+    let mut work = preds;
+    let mut reduced: Vec<Pred> = vec![];
+    while let Some(p) = work.pop() {
+        let inst = match *p.typ.unalias() {
+            PrimitiveType::Var(_) => {
+                if !reduced.iter().any(|q| q.class == p.class && q.typ == p.typ) {
+                    reduced.push(p);
+                }
+                continue;
+            },
+            PrimitiveType::Con(ref con, _) => env.lookup_instance(&p.class, con),
+            _ => None,
+        };
+        match (inst, p.typ.unalias()) {
+            (Some(inst), PrimitiveType::Con(_, args)) => {
+                for a in args {
+                    work.extend(inst.requires.iter().map(|c| Pred::new(c, a.clone(), p.span)));
+                }
+            },
+            _ => return Err(Error::NoInstance { class: p.class.clone(), typ: Box::new(p.typ.clone()), span: p.span }),
+        }
     }
+    let mut preds: Vec<Pred> = reduced.iter().filter(|p| {
+        !reduced.iter().any(|q| q.typ == p.typ && q.class != p.class && env.implies(&q.class, &p.class))
+    }).cloned().collect();
+    preds.sort_by(|a, b| (a.typ.to_string(), &a.class).cmp(&(b.typ.to_string(), &b.class)));
+    Ok(preds)
+}
+
+/// Unifies the expected type `t` with the found type `u`, reporting any failure at `span`
+fn mgu(t: &PrimitiveType, u: &PrimitiveType, s: &Subst, var_gen: &mut VarGenerator, span: Span) -> Result<Subst> {
+    stack::guard(|| mgu_inner(t, u, s, var_gen, span))
 }
 
-fn mgu(t: &PrimitiveType, u: &PrimitiveType, s: &Subst) -> Result<Subst> {
+fn mgu_inner(t: &PrimitiveType, u: &PrimitiveType, s: &Subst, var_gen: &mut VarGenerator, span: Span) -> Result<Subst> {
     use ast::PrimitiveType::*;
     let (w, r) = (s.apply(t), s.apply(u));
-    match (w, r.clone()) {
+    match (w, r) {
         (Var(a), Var(b)) if a == b => Ok(s.clone()),
-        (Var(a), _) if !r.type_vars().contains(&a) => Ok(s.extend(Var(a), u.clone())),
-        (_, Var(_)) => mgu(u, t, s),
-        (Fun(ref t1, ref t2), Fun(ref u1, ref u2)) => mgu(t1, u1, &mgu(t2, u2, s)?),
-        (Con(ref n1, ref ts), Con(ref n2, ref us)) if n1 == n2 => {
-            Ok(ts.iter().zip(us).fold(s.clone(), |ref acc, (ref a, ref b)| mgu(a, b, acc).unwrap())) // FIXME
+        (Var(a), typ) | (typ, Var(a)) if *typ.unalias() != Var(a) => {
+            if typ.type_vars().contains(&a) {
+                Err(Error::OccursCheck { var: a, typ: Box::new(typ), span })
+            } else {
+                Ok(s.extend(Var(a), typ))
+            }
         },
-        (ref a, ref b) => Err(Error::TypeError(format!("cannot unify {} with {}", a, b)))
+        (Alias(_, _, ref t1), ref u1) | (ref u1, Alias(_, _, ref t1)) => mgu(t1, u1, s, var_gen, span),
+        (Fun(ref t1, ref t2), Fun(ref u1, ref u2)) => {
+            let s1 = mgu(t2, u2, s, var_gen, span).map_err(|e| e.within("the return type of a function".to_owned()))?;
+            mgu(t1, u1, &s1, var_gen, span).map_err(|e| e.within("the argument type of a function".to_owned()))
+        },
+        (Con(ref n1, ref ts), Con(ref n2, ref us)) if n1 == n2 && (n1 != TUPLE || ts.len() == us.len()) => {
+            if ts.len() != us.len() {
+                return Err(Error::ArityMismatch { con: declared_name(n1).to_owned(), expected: ts.len(), found: us.len(), span });
+            }
+            let mut s = s.clone();
+            for (i, (a, b)) in ts.iter().zip(us).enumerate() {
+                s = mgu(a, b, &s, var_gen, span).map_err(|e| e.within(describe_type_arg(n1, i, ts.len())))?;
+            }
+            Ok(s)
+        },
+        (Row(ref fs, ref r1), Row(ref gs, ref r2)) => {
+            let only1: Vec<(String, PrimitiveType)> = fs.iter().filter(|f| !gs.iter().any(|g| g.0 == f.0)).cloned().collect();
+            let only2: Vec<(String, PrimitiveType)> = gs.iter().filter(|g| !fs.iter().any(|f| f.0 == g.0)).cloned().collect();
+            let missing = |fields: &[(String, PrimitiveType)], record: &PrimitiveType| Error::MissingField {
+                field: fields[0].0.clone(), record: Box::new(record.clone()), span
+            };
+            // Each side's row variable has to supply the fields only the other side has
+            let mut s1 = match (r1.as_ref(), r2.as_ref()) {
+                (Some(r1), Some(r2)) if r1 == r2 => {
+                    if !only1.is_empty() || !only2.is_empty() {
+                        return Err(Error::Mismatch { expected: Box::new(s.apply(t)), found: Box::new(s.apply(u)), path: vec![], span });
+                    }
+                    s.clone()
+                },
+                (Some(r1), Some(r2)) if only1.is_empty() => mgu(r1, &record_type(only2, Some((**r2).clone())), s, var_gen, span)?,
+                (Some(r1), Some(r2)) if only2.is_empty() => mgu(r2, &record_type(only1, Some((**r1).clone())), s, var_gen, span)?,
+                (Some(r1), Some(r2)) => {
+                    let r3 = var_gen.next_typevar();
+                    let s1 = mgu(r1, &record_type(only2, Some(r3.clone())), s, var_gen, span)?;
+                    mgu(r2, &record_type(only1, Some(r3)), &s1, var_gen, span)?
+                },
+                (Some(r1), None) if only1.is_empty() => mgu(r1, &record_type(only2, None), s, var_gen, span)?,
+                (None, Some(r2)) if only2.is_empty() => mgu(r2, &record_type(only1, None), s, var_gen, span)?,
+                (None, None) if only1.is_empty() && only2.is_empty() => s.clone(),
+                _ if !only1.is_empty() => return Err(missing(&only1, &s.apply(u))),
+                _ => return Err(missing(&only2, &s.apply(t))),
+            };
+            for (name, a) in fs {
+                if let Some((_, b)) = gs.iter().find(|g| g.0 == *name) {
+                    s1 = mgu(a, b, &s1, var_gen, span).map_err(|e| e.within(format!("the field `{}` of a record", name)))?;
+                }
+            }
+            Ok(s1)
+        },
+        (a, b) => Err(Error::Mismatch { expected: Box::new(a), found: Box::new(b), path: vec![], span })
+    }
+}
+
+/// Describes argument `i` of an `n`-ary type constructor for mismatch paths
+fn describe_type_arg(con: &str, i: usize, n: usize) -> String {
+    if con == TUPLE {
+        format!("component {} of a tuple", i + 1)
+    } else if n == 1 {
+        format!("the element type of {}", declared_name(con))
+    } else {
+        format!("type argument {} of {}", i + 1, declared_name(con))
     }
 }
 
-fn tp(exp: &Expr, t: &PrimitiveType, env: &Enviroment, s: &Subst, var_gen: &mut VarGenerator) -> Result<Subst> {
-    match *exp {
+fn tp(exp: &Spanned<Expr>, t: &PrimitiveType, env: &Enviroment, s: &Subst, var_gen: &mut VarGenerator,
+      warnings: &mut Vec<Warning>, preds: &mut Vec<Pred>) -> Result<Subst> {
+    stack::guard(|| tp_inner(exp, t, env, s, var_gen, warnings, preds))
+}
+
+fn tp_inner(exp: &Spanned<Expr>, t: &PrimitiveType, env: &Enviroment, s: &Subst, var_gen: &mut VarGenerator,
+            warnings: &mut Vec<Warning>, preds: &mut Vec<Pred>) -> Result<Subst> {
+    let span = exp.span;
+    match exp.node {
         Expr::Var(ref n) => {
             if let Some(scm) = env.lookup(n) {
-                mgu(&scm.new_instance(var_gen), t, s)
+                let (typ, ps) = scm.instantiate(var_gen);
+                preds.extend(ps.into_iter().map(|p| Pred { span, ..p }));
+                mgu(t, &typ, s, var_gen, span)
             } else {
-                Err(Error::UndefinedName(format!("{} is undefined!", n)))
+                Err(Error::UndefinedName { name: n.clone(), suggestions: env.similar_names(n), span })
             }
         },
-        Expr::Fun(ref arg, ref body) => {
-            let a = var_gen.next_typevar();
+        Expr::Fun(ref arg, ref annot, ref body) => {
+            let (a, annot_vars) = match *annot {
+                Some(ref texp) => annotation_type(texp, env, var_gen)?,
+                None => (var_gen.next_typevar(), vec![]),
+            };
             let b = var_gen.next_typevar();
-            let s1 = mgu(t, &PrimitiveType::Fun(Box::new(a.clone()), Box::new(b.clone())), s)?;
+            let s1 = mgu(t, &PrimitiveType::Fun(Box::new(a.clone()), Box::new(b.clone())), s, var_gen, span)?;
             let env1 = env.extend(arg.clone(), TypeScheme::new(a.clone(), HashSet::new()));
-            tp(body, &b, &env1, &s1, var_gen)
+            let s2 = tp(body, &b, &env1, &s1, var_gen, warnings, preds)?;
+            if let Some(ref texp) = *annot {
+                check_annotation(texp, &a, &annot_vars, &s2)?;
+            }
+            Ok(s2)
+        },
+        Expr::Tuple(ref elems) => {
+            let vars: Vec<PrimitiveType> = elems.iter().map(|_| var_gen.next_typevar()).collect();
+            let mut s1 = mgu(t, &tuple_type(vars.clone()), s, var_gen, span)?;
+            for (e, a) in elems.iter().zip(&vars) {
+                s1 = tp(e, a, env, &s1, var_gen, warnings, preds)?;
+            }
+            Ok(s1)
         },
         Expr::App(ref e1, ref e2) => {
+            let f = var_gen.next_typevar();
+            let s1 = tp(e1, &f, env, s, var_gen, warnings, preds)?;
+            let typ = s1.apply(&f);
+            match *typ.unalias() {
+                PrimitiveType::Fun(..) | PrimitiveType::Var(_) => {},
+                _ => return Err(Error::NotAFunction { typ: Box::new(typ), span: e1.span }),
+            }
             let a = var_gen.next_typevar();
-            let s1 = tp(e1, &PrimitiveType::Fun(Box::new(a.clone()), Box::new(t.clone())), env, s, var_gen)?;
-            tp(e2, &a, env, &s1, var_gen)
+            let s1 = mgu(&PrimitiveType::Fun(Box::new(a.clone()), Box::new(t.clone())), &f, &s1, var_gen, span)?;
+            tp(e2, &a, env, &s1, var_gen, warnings, preds).map_err(|err| {
+                label_mismatch(err, e2.span, e1.span, format!("this function takes an argument of type {}", s1.apply(&a)))
+            })
         },
         Expr::Let(ref x, ref e1, ref e2) => {
             let a = var_gen.next_typevar();
-            let s1 = tp(e1, &a.clone(), env, s, var_gen)?;
-            let env2 = env.extend(x.clone(), TypeScheme::from_type(&s1.apply(&a), env));
-            tp(e2, t, &env2, &s1, var_gen)
+            let mark = preds.len();
+            let s1 = tp(e1, &a.clone(), env, s, var_gen, warnings, preds)?;
+            let scm = generalize(&[a], is_value(e1, env), &s1, env, preds, mark)?.remove(0);
+            let env2 = env.extend(x.clone(), scm);
+            tp(e2, t, &env2, &s1, var_gen, warnings, preds)
+        },
+        Expr::LetTuple(ref xs, ref e1, ref e2) => {
+            let vars: Vec<PrimitiveType> = xs.iter().map(|_| var_gen.next_typevar()).collect();
+            let mark = preds.len();
+            let s1 = tp(e1, &tuple_type(vars.clone()), env, s, var_gen, warnings, preds)?;
+            let schemes = generalize(&vars, is_value(e1, env), &s1, env, preds, mark)?;
+            let env2 = xs.iter().zip(schemes).fold(env.clone(), |env, (x, scm)| env.extend(x.clone(), scm));
+            tp(e2, t, &env2, &s1, var_gen, warnings, preds)
         },
         Expr::LetRec(ref x, ref e1, ref e2) => {
+            check_recursive(x, e1)?;
+            // The name is monomorphic inside its own definition and only generalized afterwards
+            let a = var_gen.next_typevar();
+            let env1 = env.extend(x.clone(), TypeScheme::new(a.clone(), HashSet::new()));
+            let mark = preds.len();
+            let s1 = tp(e1, &a.clone(), &env1, s, var_gen, warnings, preds)?;
+            let scm = generalize(&[a], is_value(e1, env), &s1, env, preds, mark)?.remove(0);
+            let env2 = env.extend(x.clone(), scm);
+            tp(e2, t, &env2, &s1, var_gen, warnings, preds)
+        },
+        Expr::LetRecGroup(ref bindings, ref e2) => {
+            for (x, e1) in bindings {
+                check_recursive(x, e1)?;
+            }
+            // Every name is monomorphic while the group is solved, then all are generalized together
+            let vars: Vec<PrimitiveType> = bindings.iter().map(|_| var_gen.next_typevar()).collect();
+            let env1 = bindings.iter().zip(&vars).fold(env.clone(), |env, ((x, _), a)| {
+                env.extend(x.clone(), TypeScheme::new(a.clone(), HashSet::new()))
+            });
+            let mark = preds.len();
+            let mut s1 = s.clone();
+            for ((_, e1), a) in bindings.iter().zip(&vars) {
+                s1 = tp(e1, a, &env1, &s1, var_gen, warnings, preds)?;
+            }
+            let values = bindings.iter().all(|(_, e1)| is_value(e1, env));
+            let schemes = generalize(&vars, values, &s1, env, preds, mark)?;
+            let env2 = bindings.iter().zip(schemes).fold(env.clone(), |env, ((x, _), scm)| env.extend(x.clone(), scm));
+            tp(e2, t, &env2, &s1, var_gen, warnings, preds)
+        },
+        Expr::TypeDef(ref decl, ref e) => {
+            let env1 = define_type(&decl.node, env, var_gen, decl.span)?;
+            let con = con_name(&decl.node.name, env1.lookup_type(&decl.node.name).unwrap().stamp);
+            tp_in_type_scope(e, t, &env1, s, var_gen, warnings, preds).map_err(|err| label_declaration(err, &con, decl.span))
+        },
+        Expr::TypeAlias(ref alias, ref e) => {
+            let env1 = define_alias(&alias.node, env, var_gen, alias.span)?;
+            tp_in_type_scope(e, t, &env1, s, var_gen, warnings, preds)
+        },
+        Expr::Fixity(_, ref e) => tp(e, t, env, s, var_gen, warnings, preds),
+        Expr::Infix(..) => unreachable!("operator chains are resolved by the parser"),
+        Expr::Exception(ref decl, ref e) => {
+            let env1 = define_exception(&decl.node, env, decl.span)?;
+            tp(e, t, &env1, s, var_gen, warnings, preds)
+        },
+        Expr::Raise(ref e) => tp(e, &exn_type(), env, s, var_gen, warnings, preds),
+        Expr::Try(ref e, ref arms) => {
+            let mut s1 = tp(e, t, env, s, var_gen, warnings, preds)?;
+            for (pat, body) in arms {
+                let mut bindings = vec![];
+                s1 = tp_pattern(pat, &exn_type(), env, &s1, var_gen, &mut bindings)?;
+                let env1 = bindings.into_iter().fold(env.clone(), |env, (x, typ)| {
+                    env.extend(x, TypeScheme::new(typ, HashSet::new()))
+                });
+                s1 = tp(body, t, &env1, &s1, var_gen, warnings, preds)?;
+            }
+            // Exceptions no arm handles are raised again, so only unreachable arms are reported
+            let pats: Vec<&Spanned<Pattern>> = arms.iter().map(|arm| &arm.0).collect();
+            warnings.extend(exhaustive::check_match(&pats, env, span).into_iter().filter(|w| {
+                !matches!(*w, Warning::NonExhaustive { .. })
+            }));
+            Ok(s1)
+        },
+        Expr::Match(ref e, ref arms) => {
             let a = var_gen.next_typevar();
-            let env1 = env.extend(x.clone(), TypeScheme::from_type(&a, env));
-            let s1 = tp(e1, &a.clone(), &env1, s, var_gen)?;
-            let env2 = env.extend(x.clone(), TypeScheme::from_type(&s1.apply(&a), env));
-            tp(e2, t, &env2, &s1, var_gen)
+            let mut s1 = tp(e, &a, env, s, var_gen, warnings, preds)?;
+            for (pat, body) in arms {
+                let mut bindings = vec![];
+                s1 = tp_pattern(pat, &a, env, &s1, var_gen, &mut bindings)?;
+                let env1 = bindings.into_iter().fold(env.clone(), |env, (x, typ)| {
+                    env.extend(x, TypeScheme::new(typ, HashSet::new()))
+                });
+                s1 = tp(body, t, &env1, &s1, var_gen, warnings, preds)?;
+            }
+            let pats: Vec<&Spanned<Pattern>> = arms.iter().map(|arm| &arm.0).collect();
+            warnings.extend(exhaustive::check_match(&pats, env, span));
+            Ok(s1)
+        },
+        Expr::Record(ref fields) => {
+            for (i, (name, e)) in fields.iter().enumerate() {
+                if fields[..i].iter().any(|f| f.0 == *name) {
+                    return Err(Error::DuplicateField { field: name.clone(), span: e.span });
+                }
+            }
+            let vars: Vec<PrimitiveType> = fields.iter().map(|_| var_gen.next_typevar()).collect();
+            let typ = record_type(fields.iter().map(|f| f.0.clone()).zip(vars.iter().cloned()).collect(), None);
+            let mut s1 = mgu(t, &typ, s, var_gen, span)?;
+            for ((_, e), a) in fields.iter().zip(&vars) {
+                s1 = tp(e, a, env, &s1, var_gen, warnings, preds)?;
+            }
+            Ok(s1)
+        },
+        Expr::Annot(ref e, ref texp) => {
+            let (a, annot_vars) = annotation_type(texp, env, var_gen)?;
+            let s1 = tp(e, &a, env, s, var_gen, warnings, preds)?;
+            check_annotation(texp, &a, &annot_vars, &s1)?;
+            mgu(t, &a, &s1, var_gen, span)
+        },
+        Expr::Field(ref e, ref name) => {
+            // Any record with at least this field will do
+            let a = var_gen.next_typevar();
+            let rest = var_gen.next_typevar();
+            let s1 = tp(e, &record_type(vec![(name.clone(), a.clone())], Some(rest)), env, s, var_gen, warnings, preds)?;
+            mgu(t, &a, &s1, var_gen, span)
+        },
+        Expr::Num(_) => mgu(t, &int_type(), s, var_gen, span),
+        Expr::Float(_) => mgu(t, &float_type(), s, var_gen, span),
+        Expr::Str(_) => mgu(t, &string_type(), s, var_gen, span),
+        Expr::Char(_) => mgu(t, &char_type(), s, var_gen, span),
+        Expr::Bool(_) => mgu(t, &bool_type(), s, var_gen, span),
+        Expr::Unit => mgu(t, &unit_type(), s, var_gen, span),
+        Expr::Deref(ref e) => tp(e, &ref_type(t.clone()), env, s, var_gen, warnings, preds),
+        Expr::Assign(ref r, ref v) => {
+            let a = var_gen.next_typevar();
+            let s1 = tp(r, &ref_type(a.clone()), env, s, var_gen, warnings, preds)?;
+            let s2 = tp(v, &a, env, &s1, var_gen, warnings, preds).map_err(|err| {
+                label_mismatch(err, v.span, r.span, format!("this reference holds {}", s1.apply(&a)))
+            })?;
+            mgu(t, &unit_type(), &s2, var_gen, span)
+        },
+        Expr::Seq(ref e1, ref e2) => {
+            let a = var_gen.next_typevar();
+            let s1 = tp(e1, &a, env, s, var_gen, warnings, preds)?;
+            let s2 = tp(e2, t, env, &s1, var_gen, warnings, preds)?;
+            let discarded = s2.apply(&a);
+            match *discarded.unalias() {
+                PrimitiveType::Var(_) => {},
+                ref typ if *typ == unit_type() => {},
+                _ => warnings.push(Warning::DiscardedValue { typ: Box::new(discarded), span: e1.span }),
+            }
+            Ok(s2)
         },
-        Expr::Num(_) => mgu(t, &int_type(), s),
-        Expr::Bool(_) => mgu(t, &bool_type(), s),
         Expr::BinOp(ref l, ref op, ref r) => {
             let (expected_l_type, op_type, expected_r_type) = match *op {
-                Op::Add | Op::Sub | Op::Div | Op::Mul => (int_type(), int_type(), int_type()),
+                Op::Add | Op::Sub | Op::Div | Op::Mul | Op::Mod => {
+                    let a = var_gen.next_typevar();
+                    preds.push(Pred::new("Num", a.clone(), span));
+                    (a.clone(), a.clone(), a)
+                },
                 Op::And | Op::Or => (bool_type(), bool_type(), bool_type()),
-                Op::Lt | Op::Gt | Op::Equal => {
+                Op::Concat => (string_type(), string_type(), string_type()),
+                Op::Lt | Op::Gt | Op::Le | Op::Ge | Op::Equal | Op::NotEqual => {
                     let a = var_gen.next_typevar();
-                    (a.clone(), bool_type(), a.clone())
+                    let class = if matches!(*op, Op::Equal | Op::NotEqual) { "Eq" } else { "Ord" };
+                    preds.push(Pred::new(class, a.clone(), span));
+                    (a.clone(), bool_type(), a)
                 },
             };
-            let s1 = tp(l, &expected_l_type, env, s, var_gen)?;
-            let s2 = tp(r, &expected_r_type, env, &s1, var_gen)?;
-            mgu(t, &op_type, &s2)
+            let s1 = tp(l, &expected_l_type, env, s, var_gen, warnings, preds)?;
+            let s2 = tp(r, &expected_r_type, env, &s1, var_gen, warnings, preds).map_err(|err| {
+                label_mismatch(err, r.span, l.span, format!("this operand was inferred as {} here", s1.apply(&expected_l_type)))
+            })?;
+            mgu(t, &op_type, &s2, var_gen, span)
+        },
+        Expr::UnOp(UnOp::Not, ref e) => {
+            let s1 = tp(e, &bool_type(), env, s, var_gen, warnings, preds)?;
+            mgu(t, &bool_type(), &s1, var_gen, span)
+        },
+        Expr::UnOp(UnOp::Neg, ref e) => {
+            preds.push(Pred::new("Num", t.clone(), span));
+            tp(e, t, env, s, var_gen, warnings, preds)
         },
         Expr::If(ref pred, ref then, ref otherwise) => {
-            let s1 = tp(pred, &bool_type(), env, s, var_gen)?;
-            let s2 = tp(then, t, env, &s1, var_gen)?;
-            tp(otherwise, t, env, &s2, var_gen)
+            let s1 = tp(pred, &bool_type(), env, s, var_gen, warnings, preds)?;
+            let s2 = tp(then, t, env, &s1, var_gen, warnings, preds)?;
+            tp(otherwise, t, env, &s2, var_gen, warnings, preds).map_err(|err| {
+                label_mismatch(err, otherwise.span, then.span, format!("this branch was inferred as {} here", s2.apply(t)))
+            })
+        }
+    }
+}
+
+/// Checks `e` in `env`, which declares a new type. Constraints on the type have to be solved
+/// while its instances are in scope
+fn tp_in_type_scope(e: &Spanned<Expr>, t: &PrimitiveType, env: &Enviroment, s: &Subst, var_gen: &mut VarGenerator,
+                    warnings: &mut Vec<Warning>, preds: &mut Vec<Pred>) -> Result<Subst> {
+    let mark = preds.len();
+    let s1 = tp(e, t, env, s, var_gen, warnings, preds)?;
+    let new = preds.drain(mark..).map(|p| p.apply(&s1)).collect();
+    preds.extend(simplify(new, env)?);
+    Ok(s1)
+}
+
+/// Generalizes each of `typs` under `s`, solving the constraints gathered since `mark` and
+/// leaving in `preds` those that have to wait for an enclosing binding. Nothing is generalized
+/// unless the bound expression is a value
+fn generalize(typs: &[PrimitiveType], value: bool, s: &Subst, env: &Enviroment, preds: &mut Vec<Pred>,
+              mark: usize) -> Result<Vec<TypeScheme>> {
+    let env = s.apply_env(env);
+    let new: Vec<Pred> = preds.drain(mark..).map(|p| p.apply(s)).collect();
+    let mut schemes = vec![];
+    let mut deferred = vec![];
+    for t in typs {
+        let (scm, rest) = TypeScheme::from_type(&s.apply(t), new.clone(), value, &env)?;
+        schemes.push(scm);
+        for p in rest {
+            if !deferred.contains(&p) {
+                deferred.push(p);
+            }
         }
     }
+    preds.extend(deferred);
+    Ok(schemes)
 }
 
-pub fn type_of(expr: &Expr, env: &Enviroment, var_gen: &mut VarGenerator) -> Result<PrimitiveType> {
+/// Rejects a `letrec` binding that is not a function. Running a function's body waits
+/// until the function is called, by which time every name in the group is defined
+fn check_recursive(x: &str, e: &Spanned<Expr>) -> Result<()> {
+    let mut e = e;
+    while let Expr::Annot(ref inner, _) = e.node {
+        e = inner;
+    }
+    match e.node {
+        Expr::Fun(..) => Ok(()),
+        _ => Err(Error::RecursiveValue { name: x.to_owned(), span: e.span }),
+    }
+}
+
+/// Whether evaluating `e` certainly allocates no references, so that its type can be safely
+/// generalized. This is the value restriction: functions, literals, variables, and tuples,
+/// records and constructors applied to values are values, while other applications are not
+fn is_value(e: &Spanned<Expr>, env: &Enviroment) -> bool {
+    stack::guard(|| match e.node {
+        Expr::Num(_) | Expr::Float(_) | Expr::Str(_) | Expr::Char(_) | Expr::Bool(_) | Expr::Unit |
+        Expr::Var(_) | Expr::Fun(..) => true,
+        Expr::Tuple(ref elems) => elems.iter().all(|e| is_value(e, env)),
+        Expr::Record(ref fields) => fields.iter().all(|f| is_value(&f.1, env)),
+        Expr::Field(ref e, _) | Expr::Annot(ref e, _) => is_value(e, env),
+        Expr::Let(_, ref e1, ref e2) => is_value(e1, env) && is_value(e2, env),
+        Expr::App(ref f, ref arg) => {
+            let mut head = f;
+            while let Expr::App(ref f, _) = head.node {
+                head = f;
+            }
+            let is_ctor = match head.node {
+                Expr::Var(ref c) => env.lookup_constructor(c).is_some(),
+                _ => false,
+            };
+            is_ctor && is_value(arg, env) && is_value(f, env)
+        },
+        _ => false,
+    })
+}
+
+/// Attaches `label` at `at` to a type error reported directly on the subexpression at `span`
+fn label_mismatch(err: Error, span: Span, at: Span, label: String) -> Error {
+    match err {
+        Error::Mismatch { span: err_span, .. } if err_span == span => err.with_label(at, label),
+        err => err,
+    }
+}
+
+/// Points a mismatch between two types that are written the same, such as two declarations
+/// of `t`, at the declaration of `con` if it is one of them
+fn label_declaration(err: Error, con: &str, span: Span) -> Error {
+    let which = match *err.primary() {
+        Error::Mismatch { ref expected, ref found, .. } => match (expected.unalias(), found.unalias()) {
+            (PrimitiveType::Con(a, _), PrimitiveType::Con(b, _)) if a != b && declared_name(a) == declared_name(b) => {
+                if a == con {
+                    "expected"
+                } else if b == con {
+                    "found"
+                } else {
+                    return err;
+                }
+            },
+            _ => return err,
+        },
+        _ => return err,
+    };
+    err.with_label(span, format!("the {} {} is declared here", which, declared_name(con)))
+}
+
+/// Checks that `pat` matches values of type `t`, collecting the variables it binds
+fn tp_pattern(pat: &Spanned<Pattern>, t: &PrimitiveType, env: &Enviroment, s: &Subst, var_gen: &mut VarGenerator,
+              bindings: &mut Vec<(String, PrimitiveType)>) -> Result<Subst> {
+    let span = pat.span;
+    let (c, args): (&str, &[Spanned<Pattern>]) = match pat.node {
+        Pattern::Wildcard => return Ok(s.clone()),
+        Pattern::Var(ref x) if env.lookup_constructor(x).is_none() => {
+            bindings.push((x.clone(), t.clone()));
+            return Ok(s.clone());
+        },
+        Pattern::Var(ref c) => (c, &[]),
+        Pattern::Num(_) => return mgu(t, &int_type(), s, var_gen, span),
+        Pattern::Bool(_) => return mgu(t, &bool_type(), s, var_gen, span),
+        Pattern::Tuple(ref elems) => {
+            let vars: Vec<PrimitiveType> = elems.iter().map(|_| var_gen.next_typevar()).collect();
+            let mut s1 = mgu(t, &tuple_type(vars.clone()), s, var_gen, span)?;
+            for (p, a) in elems.iter().zip(&vars) {
+                s1 = tp_pattern(p, a, env, &s1, var_gen, bindings)?;
+            }
+            return Ok(s1);
+        },
+        Pattern::Con(ref c, ref args) => (c, args),
+    };
+    let (name, def) = match env.lookup_constructor(c) {
+        Some(found) => found,
+        None => return Err(Error::UndefinedName { name: c.to_owned(), suggestions: env.similar_names(c), span }),
+    };
+    let ctor_args = &def.ctors.iter().find(|ctor| ctor.0 == c).unwrap().1;
+    if ctor_args.len() != args.len() {
+        return Err(Error::ArityMismatch { con: c.to_owned(), expected: ctor_args.len(), found: args.len(), span });
+    }
+    // The constructor's types come from its type's definition, instantiated afresh
+    let params: Vec<PrimitiveType> = def.params.iter().map(|_| var_gen.next_typevar()).collect();
+    let inst = def.params.iter().zip(&params).fold(Subst::empty(), |s, (&p, a)| s.extend(PrimitiveType::Var(p), a.clone()));
+    let mut s1 = s.clone();
+    for (arg, a) in args.iter().zip(ctor_args) {
+        s1 = tp_pattern(arg, &inst.apply(a), env, &s1, var_gen, bindings)?;
+    }
+    mgu(t, &PrimitiveType::Con(con_name(&name, def.stamp), params), &s1, var_gen, span)
+}
+
+/// Resolves the names in a written type against the type definitions in `env`,
+/// mapping type variables through `vars`
+fn convert_type(texp: &TypeExpr, env: &Enviroment, vars: &HashMap<String, PrimitiveType>, span: Span) -> Result<PrimitiveType> {
+    match *texp {
+        TypeExpr::Var(ref v) => vars.get(v).cloned().ok_or_else(|| Error::UndefinedType { name: v.clone(), span }),
+        TypeExpr::Con(ref name, ref args) => {
+            let def = env.lookup_type(name).ok_or_else(|| Error::UndefinedType { name: name.clone(), span })?;
+            if def.params.len() != args.len() {
+                return Err(Error::ArityMismatch { con: name.clone(), expected: def.params.len(), found: args.len(), span });
+            }
+            let args: Vec<PrimitiveType> = args.iter().map(|a| convert_type(a, env, vars, span)).collect::<Result<_>>()?;
+            match def.alias {
+                Some(ref typ) => {
+                    let s = def.params.iter().zip(&args).fold(Subst::empty(), |s, (&p, a)| s.extend(PrimitiveType::Var(p), a.clone()));
+                    Ok(PrimitiveType::Alias(name.clone(), args, Box::new(s.apply(typ))))
+                },
+                None => Ok(PrimitiveType::Con(con_name(name, def.stamp), args)),
+            }
+        },
+        TypeExpr::Fun(ref a, ref r) => {
+            Ok(PrimitiveType::Fun(Box::new(convert_type(a, env, vars, span)?), Box::new(convert_type(r, env, vars, span)?)))
+        },
+        TypeExpr::Tuple(ref ts) => {
+            Ok(tuple_type(ts.iter().map(|t| convert_type(t, env, vars, span)).collect::<Result<_>>()?))
+        },
+    }
+}
+
+/// Converts a type annotation to a type, giving each type variable it names a fresh variable.
+/// Returns the type along with those variables
+fn annotation_type(texp: &Spanned<TypeExpr>, env: &Enviroment, var_gen: &mut VarGenerator) -> Result<(PrimitiveType, Vec<PrimitiveType>)> {
+    let mut names = vec![];
+    type_var_names(&texp.node, &mut names);
+    let vars: HashMap<String, PrimitiveType> = names.into_iter().map(|n| (n, var_gen.next_typevar())).collect();
+    let typ = convert_type(&texp.node, env, &vars, texp.span)?;
+    Ok((typ, vars.into_values().collect()))
+}
+
+/// Collects the names of the type variables in a written type
+fn type_var_names(texp: &TypeExpr, names: &mut Vec<String>) {
+    match *texp {
+        TypeExpr::Var(ref v) => if !names.contains(v) { names.push(v.clone()) },
+        TypeExpr::Con(_, ref ts) | TypeExpr::Tuple(ref ts) => for t in ts { type_var_names(t, names) },
+        TypeExpr::Fun(ref a, ref r) => {
+            type_var_names(a, names);
+            type_var_names(r, names);
+        },
+    }
+}
+
+/// Checks that an annotated expression really is as polymorphic as its annotation claims,
+/// i.e. that `s` left the annotation's type variables distinct and unconstrained
+fn check_annotation(texp: &Spanned<TypeExpr>, typ: &PrimitiveType, vars: &[PrimitiveType], s: &Subst) -> Result<()> {
+    let mut seen = HashSet::new();
+    for v in vars {
+        match s.apply(v) {
+            PrimitiveType::Var(n) if seen.insert(n) => {},
+            _ => return Err(Error::AnnotationTooGeneral {
+                annotation: Box::new(texp.node.clone()), inferred: Box::new(s.apply(typ)), span: texp.span
+            }),
+        }
+    }
+    Ok(())
+}
+
+/// The name of the type constructor for a declaration of `name`. Builtin types keep their
+/// name, while declared types are told apart by their stamp, so that a value of a type is
+/// never mistaken for one of a later type of the same name
+fn con_name(name: &str, stamp: u32) -> String {
+    match stamp {
+        0 => name.to_owned(),
+        _ => format!("{}/{}", name, stamp),
+    }
+}
+
+/// Registers a declared type, and a constructor function for each of its variants
+fn define_type(decl: &TypeDecl, env: &Enviroment, var_gen: &mut VarGenerator, span: Span) -> Result<Enviroment> {
+    for (i, (c, _)) in decl.ctors.iter().enumerate() {
+        if decl.ctors[..i].iter().any(|d| d.0 == *c) {
+            return Err(Error::DuplicateConstructor { name: c.clone(), span });
+        }
+    }
+    let stamp = var_gen.next_var();
+    let params: Vec<u32> = decl.params.iter().map(|_| var_gen.next_var()).collect();
+    let vars: HashMap<String, PrimitiveType> = decl.params.iter().cloned()
+        .zip(params.iter().map(|&v| PrimitiveType::Var(v)))
+        .collect();
+    // The type is visible in its own constructors so that it can be recursive
+    let env1 = env.extend_type(decl.name.clone(), TypeDef { params: params.clone(), ctors: vec![], alias: None, stamp });
+    let mut ctors = vec![];
+    for (c, args) in &decl.ctors {
+        let args = args.iter().map(|a| convert_type(a, &env1, &vars, span)).collect::<Result<Vec<_>>>()?;
+        ctors.push((c.clone(), args));
+    }
+    let con = con_name(&decl.name, stamp);
+    let result = PrimitiveType::Con(con.clone(), params.iter().map(|&v| PrimitiveType::Var(v)).collect());
+    let mut env2 = env.extend_type(decl.name.clone(), TypeDef { params: params.clone(), ctors: ctors.clone(), alias: None, stamp });
+    // Declared types can be compared whenever all of their constructors' arguments can
+    for class in &["Eq", "Ord"] {
+        let inst = Instance { class: class.to_string(), con: con.clone(), requires: vec![class.to_string()] };
+        let env3 = env2.extend_instance(inst);
+        let arg_preds = ctors.iter().flat_map(|c| c.1.iter()).map(|a| Pred::new(class, a.clone(), span)).collect();
+        if simplify(arg_preds, &env3).is_ok() {
+            env2 = env3;
+        }
+    }
+    for (c, args) in ctors {
+        let typ = args.into_iter().rev().fold(result.clone(), |r, a| PrimitiveType::Fun(Box::new(a), Box::new(r)));
+        env2 = env2.extend_constructor(c, TypeScheme::new(typ, params.iter().cloned().collect()));
+    }
+    Ok(env2)
+}
+
+/// Registers a type alias
+fn define_alias(alias: &TypeAlias, env: &Enviroment, var_gen: &mut VarGenerator, span: Span) -> Result<Enviroment> {
+    let params: Vec<u32> = alias.params.iter().map(|_| var_gen.next_var()).collect();
+    let vars: HashMap<String, PrimitiveType> = alias.params.iter().cloned()
+        .zip(params.iter().map(|&v| PrimitiveType::Var(v)))
+        .collect();
+    // The alias is not in scope in its own definition, so it cannot be recursive
+    let typ = convert_type(&alias.typ, env, &vars, span)?;
+    Ok(env.extend_type(alias.name.clone(), TypeDef { params, ctors: vec![], alias: Some(typ), stamp: 0 }))
+}
+
+/// Registers an exception by adding its constructor to `exn`. Exceptions cannot be
+/// polymorphic, so their arguments may not mention type variables
+fn define_exception(decl: &ExceptionDecl, env: &Enviroment, span: Span) -> Result<Enviroment> {
+    let args = decl.args.iter().map(|a| convert_type(a, env, &HashMap::new(), span)).collect::<Result<Vec<_>>>()?;
+    let mut def = env.lookup_type(EXN).unwrap();
+    def.ctors.retain(|c| c.0 != decl.name);
+    def.ctors.push((decl.name.clone(), args.clone()));
+    let typ = args.into_iter().rev().fold(exn_type(), |r, a| PrimitiveType::Fun(Box::new(a), Box::new(r)));
+    Ok(env.extend_type(EXN.to_owned(), def).extend_constructor(decl.name.clone(), TypeScheme::new(typ, HashSet::new())))
+}
+
+pub fn type_of(expr: &Spanned<Expr>, env: &Enviroment, var_gen: &mut VarGenerator) -> Result<PrimitiveType> {
+    check(expr, env, var_gen).map(|(q, _)| q.typ)
+}
+
+/// Infers the type of `expr` and the constraints on it, along with any warnings about it
+pub fn check(expr: &Spanned<Expr>, env: &Enviroment, var_gen: &mut VarGenerator) -> Result<(Qualified, Vec<Warning>)> {
     let a = var_gen.next_typevar();
-    let s = tp(expr, &a, env, &Subst::empty(), var_gen)?;
-    Ok(s.apply(&a))
+    let mut warnings = vec![];
+    let mut preds = vec![];
+    let s = tp(expr, &a, env, &Subst::empty(), var_gen, &mut warnings, &mut preds)?;
+    let typ = s.apply(&a);
+    let vars = typ.type_vars();
+    let preds = simplify(preds.iter().map(|p| p.apply(&s)).collect(), env)?.into_iter().filter(|p| {
+        p.typ.type_vars().is_subset(&vars)
+    }).collect();
+    Ok((Qualified { preds, typ }, warnings))
 }
 
 pub fn top_level_env(var_gen: &mut VarGenerator) -> Enviroment {
-    let a = var_gen.next_typevar();
-    let abstract_list = list_type(a);
-    let env = Enviroment::Empty;
-    let env = env.extend("nil".to_owned(), TypeScheme::from_type(&abstract_list.clone(), &env));
-    let a = var_gen.next_typevar();
-    let pair = PrimitiveType::Fun(Box::new(a.clone()), Box::new(PrimitiveType::Fun(Box::new(list_type(a.clone())), Box::new(list_type(a)))));
-    let env = env.extend("pair".to_owned(), TypeScheme::from_type(&pair, &env));
-    let a = var_gen.next_typevar();
-    let first = PrimitiveType::Fun(Box::new(list_type(a.clone())), Box::new(a.clone()));
-    let env = env.extend("first".to_owned(), TypeScheme::from_type(&first, &env));
-    let a = var_gen.next_typevar();
-    let rest = PrimitiveType::Fun(Box::new(list_type(a.clone())), Box::new(list_type(a.clone())));
-    let env = env.extend("rest".to_owned(), TypeScheme::from_type(&rest, &env));
-    let a = var_gen.next_typevar();
-    let is_nil = PrimitiveType::Fun(Box::new(list_type(a.clone())), Box::new(bool_type()));
-    let env = env.extend("is_nil".to_owned(), TypeScheme::from_type(&is_nil, &env));
-    env
+    let env = Enviroment::empty();
+    let env = env.extend_type("int".to_owned(), TypeDef { params: vec![], ctors: vec![], alias: None, stamp: 0 });
+    let env = env.extend_type("bool".to_owned(), TypeDef { params: vec![], ctors: vec![], alias: None, stamp: 0 });
+    let a = var_gen.next_var();
+    let list_ctors = vec![
+        ("nil".to_owned(), vec![]),
+        ("pair".to_owned(), vec![PrimitiveType::Var(a), list_type(PrimitiveType::Var(a))]),
+    ];
+    let env = env.extend_type("List".to_owned(), TypeDef { params: vec![a], ctors: list_ctors, alias: None, stamp: 0 });
+    let env = ["float", "string", "char", "unit"].iter().fold(env, |env, name| {
+        env.extend_type(name.to_string(), TypeDef { params: vec![], ctors: vec![], alias: None, stamp: 0 })
+    });
+    let a = var_gen.next_var();
+    let env = env.extend_type("Ref".to_owned(), TypeDef { params: vec![a], ctors: vec![], alias: None, stamp: 0 });
+    let env = env.extend_type(EXN.to_owned(), TypeDef { params: vec![], ctors: vec![], alias: None, stamp: 0 });
+    let env = env.extend_class("Eq".to_owned(), ClassDef { superclasses: vec![] });
+    let env = env.extend_class("Ord".to_owned(), ClassDef { superclasses: vec!["Eq".to_owned()] });
+    let env = env.extend_class("Num".to_owned(), ClassDef { superclasses: vec![] });
+    let instances = [
+        ("Eq", "int", None), ("Ord", "int", None), ("Num", "int", None),
+        ("Eq", "float", None), ("Ord", "float", None), ("Num", "float", None),
+        ("Eq", "bool", None), ("Ord", "bool", None),
+        ("Eq", "string", None), ("Ord", "string", None),
+        ("Eq", "char", None), ("Ord", "char", None),
+        ("Eq", "unit", None), ("Ord", "unit", None),
+        ("Eq", "List", Some("Eq")), ("Ord", "List", Some("Ord")),
+        ("Eq", TUPLE, Some("Eq")), ("Ord", TUPLE, Some("Ord")),
+    ];
+    let env = instances.iter().fold(env, |env, &(class, con, requires)| {
+        env.extend_instance(Instance { class: class.to_owned(), con: con.to_owned(), requires: requires.into_iter().map(|c| c.to_owned()).collect() })
+    });
+    // Every builtin is polymorphic in all of its type variables, so they can share them
+    let (a, b) = (var_gen.next_typevar(), var_gen.next_typevar());
+    let fun = |a: PrimitiveType, r: PrimitiveType| PrimitiveType::Fun(Box::new(a), Box::new(r));
+    let builtins = [
+        ("nil", list_type(a.clone())),
+        ("pair", fun(a.clone(), fun(list_type(a.clone()), list_type(a.clone())))),
+        ("first", fun(list_type(a.clone()), a.clone())),
+        ("rest", fun(list_type(a.clone()), list_type(a.clone()))),
+        ("is_nil", fun(list_type(a.clone()), bool_type())),
+        ("fst", fun(tuple_type(vec![a.clone(), b.clone()]), a.clone())),
+        ("snd", fun(tuple_type(vec![a.clone(), b.clone()]), b)),
+        ("length", fun(string_type(), int_type())),
+        ("to_string", fun(a.clone(), string_type())),
+        ("ref", fun(a.clone(), ref_type(a))),
+    ];
+    builtins.iter().fold(env, |env, (name, typ)| {
+        let scheme = TypeScheme::new(typ.clone(), typ.type_vars());
+        match env.lookup_constructor(name) {
+            Some(_) => env.extend_constructor(name.to_string(), scheme),
+            None => env.extend(name.to_string(), scheme),
+        }
+    })
+}
+
+/// Parses a type written as in the source language, such as `'a -> List<'a>`, numbering its
+/// type variables from 1 in order of appearance. Names are not checked against any
+/// enviroment, so aliases are not expanded
+pub fn parse_type(src: &str) -> ::std::result::Result<PrimitiveType, ParseError<usize, (usize, &str), fixity::Error>> {
+    let mut var_gen = VarGenerator::new();
+    let texp = grammar::parse_Type(src)?;
+    let mut names = vec![];
+    type_var_names(&texp, &mut names);
+    let vars = names.into_iter().map(|n| (n, var_gen.next_typevar())).collect();
+    Ok(resolve_type(&texp, &vars))
+}
+
+/// Converts a written type without consulting an enviroment
+fn resolve_type(texp: &TypeExpr, vars: &HashMap<String, PrimitiveType>) -> PrimitiveType {
+    match *texp {
+        TypeExpr::Var(ref v) => vars[v].clone(),
+        TypeExpr::Con(ref name, ref args) => PrimitiveType::Con(name.clone(), args.iter().map(|a| resolve_type(a, vars)).collect()),
+        TypeExpr::Fun(ref a, ref r) => PrimitiveType::Fun(Box::new(resolve_type(a, vars)), Box::new(resolve_type(r, vars))),
+        TypeExpr::Tuple(ref ts) => tuple_type(ts.iter().map(|t| resolve_type(t, vars)).collect()),
+    }
 }
 
 pub fn int_type() -> PrimitiveType {
@@ -233,11 +1242,57 @@ pub fn int_type() -> PrimitiveType {
 pub fn bool_type() -> PrimitiveType {
     PrimitiveType::Con("bool".to_owned(), vec![])
 }
+pub fn unit_type() -> PrimitiveType {
+    PrimitiveType::Con("unit".to_owned(), vec![])
+}
+pub fn float_type() -> PrimitiveType {
+    PrimitiveType::Con("float".to_owned(), vec![])
+}
+pub fn string_type() -> PrimitiveType {
+    PrimitiveType::Con("string".to_owned(), vec![])
+}
+pub fn char_type() -> PrimitiveType {
+    PrimitiveType::Con("char".to_owned(), vec![])
+}
 pub fn list_type(t: PrimitiveType) -> PrimitiveType {
     PrimitiveType::Con("List".to_owned(), vec![t])
 }
+pub fn ref_type(t: PrimitiveType) -> PrimitiveType {
+    PrimitiveType::Con("Ref".to_owned(), vec![t])
+}
+/// The name of the type of exceptions, which `exception` declarations add constructors to
+pub const EXN: &str = "exn";
+/// The names of the types `top_level_env` defines, which the parser needs to tell an alias
+/// to one of them from the declaration of a constructor
+pub const BUILTIN_TYPES: &[&str] = &["int", "bool", "float", "string", "char", "unit", "List", "Ref", EXN];
+pub fn exn_type() -> PrimitiveType {
+    PrimitiveType::Con(EXN.to_owned(), vec![])
+}
+/// The name of the type constructor for tuples, printed as `(a * b)`
+pub const TUPLE: &str = "*";
+pub fn tuple_type(ts: Vec<PrimitiveType>) -> PrimitiveType {
+    PrimitiveType::Con(TUPLE.to_owned(), ts)
+}
+/// Builds a record type from fields in any order, merging in the fields of `rest` if it is
+/// itself a record
+pub fn record_type(mut fields: Vec<(String, PrimitiveType)>, mut rest: Option<PrimitiveType>) -> PrimitiveType {
+    let rest = match rest {
+        Some(PrimitiveType::Row(ref mut more, ref mut rest)) => {
+            fields.append(more);
+            rest.take().map(|mut r| mem::replace(&mut *r, PrimitiveType::Var(0)))
+        },
+        rest => rest,
+    };
+    match rest {
+        Some(rest) if fields.is_empty() => rest,
+        rest => {
+            fields.sort_by(|a, b| a.0.cmp(&b.0));
+            PrimitiveType::Row(fields, rest.map(Box::new))
+        },
+    }
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct VarGenerator {
     next_var: u32
 }
@@ -246,7 +1301,10 @@ impl VarGenerator {
         VarGenerator { next_var: 0 }
     }
     pub fn next_typevar(&mut self) -> PrimitiveType {
+        PrimitiveType::Var(self.next_var())
+    }
+    pub fn next_var(&mut self) -> u32 {
         self.next_var += 1;
-        PrimitiveType::Var(self.next_var)
+        self.next_var
     }
 }
\ No newline at end of file
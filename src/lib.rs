@@ -1,4 +1,16 @@
+extern crate lalrpop_util;
+extern crate stacker;
+extern crate im_rc;
+
 pub use self::grammar as parser;
 pub mod infer;
 pub mod ast;
+pub mod diagnostic;
+pub mod eval;
+pub mod exhaustive;
+pub mod fixity;
+pub mod bytecode;
+pub mod vm;
+pub mod stack;
+#[allow(warnings, clippy::all)]
 pub mod grammar;
\ No newline at end of file
@@ -3,21 +3,73 @@
 // but I plan to refactor the code to be more Rust-y and add more features soon.
 //
 // TODO:
-// * Fix https://github.com/prakhar1989/type-inference/issues/5
 // * Add typing for more features. 
 
 extern crate type_inference;
-use type_inference::infer::*;
+use type_inference::ast::{Expr, Spanned};
+use type_inference::infer::{top_level_env, Qualified, VarGenerator};
+use type_inference::infer;
+use type_inference::{bytecode, eval, vm};
 use type_inference::parser;
+use type_inference::diagnostic::Diagnostic;
 
+use std::env;
+use std::fs::File;
 use std::io;
-use std::io::Write;
+use std::io::{Read, Write};
+use std::process;
+
+/// How programs are run once they type check
+#[derive(Clone, Copy)]
+enum Backend {
+    /// Walk the syntax tree with `eval`
+    Interpreter,
+    /// Compile to bytecode and run it with `vm`
+    Vm,
+}
 
 fn main() {
-    repl();
+    let (flags, paths): (Vec<String>, Vec<String>) = env::args().skip(1).partition(|a| a.starts_with("--"));
+    let mut backend = Backend::Interpreter;
+    for flag in flags {
+        match &*flag {
+            "--vm" => backend = Backend::Vm,
+            _ => {
+                eprintln!("error: unknown option {}", flag);
+                process::exit(2);
+            },
+        }
+    }
+    match paths.first() {
+        Some(path) => batch(path, backend),
+        None => repl(backend),
+    }
+}
+
+/// Type checks and runs the contents of a file, exiting with a failure status on error
+fn batch(path: &str, backend: Backend) {
+    let mut input = String::new();
+    if let Err(e) = File::open(path).and_then(|mut f| f.read_to_string(&mut input)) {
+        eprintln!("error: could not read {}: {}", path, e);
+        process::exit(2);
+    }
+    let result = parse(&input).and_then(|expr| {
+        let (typ, warnings) = check_expr(&expr)?;
+        for warning in warnings {
+            eprint!("{}", warning.render(&input));
+        }
+        Ok(format!("- : {} = {}", typ, run(&expr, backend)?))
+    });
+    match result {
+        Ok(output) => println!("{}", output),
+        Err(diag) => {
+            eprint!("{}", diag.render(&input));
+            process::exit(1);
+        }
+    }
 }
 
-fn repl() {
+fn repl(backend: Backend) {
     println!("Welcome to the type inference REPL");
     println!("Hit ^C to quit ");
     let mut stdout = io::stdout();
@@ -26,19 +78,40 @@ fn repl() {
         print!("> ");
         stdout.flush().unwrap();
         let mut input = String::new();
-        stdin.read_line(&mut input).unwrap();
-        let expr = match parser::parse_Expr(&input) {
-            Ok(expr) => expr,
-            Err(_) => {
-                println!("syntax error");
-                continue;
+        if stdin.read_line(&mut input).unwrap() == 0 {
+            println!();
+            return;
+        }
+        let result = parse(&input).and_then(|expr| {
+            let (typ, warnings) = check_expr(&expr)?;
+            for warning in warnings {
+                print!("{}", warning.render(&input));
             }
-        };
-        let mut var_gen = VarGenerator::new();
-        let env = top_level_env(&mut var_gen);
-        match type_of(&expr, &env, &mut var_gen) {
-            Ok(typ) => println!("{}", typ),
-            Err(e) => println!("{:?}", e),
+            Ok(format!("- : {} = {}", typ, run(&expr, backend)?))
+        });
+        match result {
+            Ok(output) => println!("{}", output),
+            Err(diag) => print!("{}", diag.render(&input)),
         }
     }
-}
\ No newline at end of file
+}
+
+fn parse(input: &str) -> Result<Box<Spanned<Expr>>, Box<Diagnostic>> {
+    parser::parse_Expr(input).map_err(|e| Box::new(Diagnostic::from_parse_error(input, &e)))
+}
+
+fn check_expr(expr: &Spanned<Expr>) -> Result<(Qualified, Vec<Diagnostic>), Box<Diagnostic>> {
+    let mut var_gen = VarGenerator::new();
+    let env = top_level_env(&mut var_gen);
+    let (typ, warnings) = infer::check(expr, &env, &mut var_gen).map_err(|e| Box::new(Diagnostic::from_type_error(&e)))?;
+    Ok((typ, warnings.iter().map(Diagnostic::from_warning).collect()))
+}
+
+/// Runs a type checked expression, returning its value as it is printed
+fn run(expr: &Spanned<Expr>, backend: Backend) -> Result<String, Box<Diagnostic>> {
+    let error = |e: &eval::Error| Box::new(Diagnostic::from_eval_error(e));
+    match backend {
+        Backend::Interpreter => eval::eval(expr).map(|v| v.to_string()).map_err(|e| error(&e)),
+        Backend::Vm => vm::run(&bytecode::compile(expr)).map(|v| v.to_string()).map_err(|e| error(&e)),
+    }
+}
@@ -0,0 +1,16 @@
+//! Keeps the recursive passes over programs from overflowing the native stack. Each of them
+//! calls `guard` once per level, which moves to a freshly allocated stack segment whenever
+//! the current one is close to running out, so nesting is limited only by memory.
+
+use stacker;
+
+/// How much stack a pass may use between two calls to `guard`
+const RED_ZONE: usize = 256 * 1024;
+
+/// The size of each new stack segment
+const SEGMENT_SIZE: usize = 4 * 1024 * 1024;
+
+/// Runs `f`, first switching to a new stack segment if the current one is nearly full
+pub fn guard<R, F: FnOnce() -> R>(f: F) -> R {
+    stacker::maybe_grow(RED_ZONE, SEGMENT_SIZE, f)
+}
@@ -0,0 +1,245 @@
+//! Runs the bytecode `bytecode::compile` produces. Calls push frames onto a vector instead of
+//! recursing, and values are the same as the tree-walking interpreter's, so builtins and
+//! errors are shared with `eval`.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use ast::UnOp;
+use bytecode::{Capture, Instr, Pat, Program, Proto};
+use eval;
+use eval::{Error, Function, Value};
+
+/// A call in progress
+struct Frame {
+    proto: Rc<Proto>,
+    /// The closure being run, which holds the captured variables
+    closure: Rc<Function<'static>>,
+    pc: usize,
+    /// Where the call's slots start on the stack
+    base: usize,
+}
+impl Frame {
+    fn captured(&self, i: usize) -> Value<'static> {
+        match *self.closure {
+            Function::Compiled { ref captured, .. } => captured[i].clone(),
+            _ => unreachable!("only compiled closures have frames"),
+        }
+    }
+}
+
+/// A `try` in progress: the state to return to when its body raises an exception
+struct Handler {
+    frames: usize,
+    stack: usize,
+    pc: usize,
+}
+
+/// Runs a compiled program
+pub fn run(program: &Program) -> eval::Result<'static> {
+    let globals: Vec<Value> = eval::builtins().into_iter().map(|g| g.1).collect();
+    let mut stack: Vec<Value<'static>> = vec![];
+    let mut frames: Vec<Frame> = vec![];
+    let mut handlers: Vec<Handler> = vec![];
+    let mut frame = Frame {
+        proto: program.protos[0].clone(),
+        closure: Rc::new(Function::Compiled { proto: 0, captured: vec![] }),
+        pc: 0,
+        base: 0,
+    };
+    // The span the exception being handled was raised at, for when it is raised again
+    let mut raised_at = (0, 0);
+    loop {
+        let span = frame.proto.spans[frame.pc];
+        let instr = frame.proto.code[frame.pc].clone();
+        frame.pc += 1;
+        match instr {
+            Instr::Const(k) => stack.push(program.constants[k].clone()),
+            Instr::Local(slot) => {
+                let v = stack[frame.base + slot].clone();
+                stack.push(v);
+            },
+            Instr::Captured(i) => stack.push(frame.captured(i)),
+            Instr::Global(i) => stack.push(globals[i].clone()),
+            Instr::Pop => {
+                stack.pop();
+            },
+            Instr::Slide(n) => {
+                let top = stack.pop().unwrap();
+                let len = stack.len();
+                stack.truncate(len - n);
+                stack.push(top);
+            },
+            Instr::Closure(p) => {
+                let captured = program.protos[p].captures.iter().map(|c| match *c {
+                    Capture::Local(slot) => stack[frame.base + slot].clone(),
+                    Capture::Captured(i) => frame.captured(i),
+                }).collect();
+                stack.push(Value::Fun(Rc::new(Function::Compiled { proto: p, captured })));
+            },
+            Instr::Apply | Instr::TailApply => {
+                let arg = stack.pop().unwrap();
+                let f = match stack.pop().unwrap() {
+                    Value::Fun(ref f) => f.clone(),
+                    v => panic!("cannot apply {}", v),
+                };
+                let proto = match *f {
+                    Function::Compiled { proto, .. } => proto,
+                    _ => {
+                        let result = eval::apply(Value::Fun(f), arg, span)?;
+                        stack.push(result);
+                        if instr == Instr::TailApply {
+                            match ret(&mut stack, &mut frames, &mut frame) {
+                                Some(v) => return Ok(v),
+                                None => continue,
+                            }
+                        }
+                        continue;
+                    },
+                };
+                let base = if instr == Instr::TailApply {
+                    // The call replaces the current one, so the stack does not grow
+                    stack.truncate(frame.base);
+                    frame.base
+                } else {
+                    stack.len()
+                };
+                stack.push(arg);
+                let callee = Frame { proto: program.protos[proto].clone(), closure: f, pc: 0, base };
+                if instr == Instr::TailApply {
+                    frame = callee;
+                } else {
+                    frames.push(::std::mem::replace(&mut frame, callee));
+                }
+            },
+            Instr::Return => if let Some(v) = ret(&mut stack, &mut frames, &mut frame) {
+                return Ok(v);
+            },
+            Instr::Jump(pc) => frame.pc = pc,
+            Instr::JumpIfFalse(pc) => match stack.pop().unwrap() {
+                Value::Bool(true) => {},
+                Value::Bool(false) => frame.pc = pc,
+                v => panic!("if on {}", v),
+            },
+            Instr::BinOp(op) => {
+                let b = stack.pop().unwrap();
+                let a = stack.pop().unwrap();
+                stack.push(eval::binop(op, a, b, span)?);
+            },
+            Instr::UnOp(op) => {
+                let v = match (op, stack.pop().unwrap()) {
+                    (UnOp::Not, Value::Bool(b)) => Value::Bool(!b),
+                    (UnOp::Neg, Value::Int(n)) => Value::Int(n.wrapping_neg()),
+                    (UnOp::Neg, Value::Float(n)) => Value::Float(-n),
+                    (op, v) => panic!("{:?} applied to {}", op, v),
+                };
+                stack.push(v);
+            },
+            Instr::MakeTuple(n) => {
+                let len = stack.len();
+                let elems = stack.split_off(len - n);
+                stack.push(Value::Tuple(Rc::new(elems)));
+            },
+            Instr::Unpack(n) => match stack.pop().unwrap() {
+                Value::Tuple(ref elems) if elems.len() == n => stack.extend(elems.iter().cloned()),
+                v => panic!("cannot destructure {}", v),
+            },
+            Instr::MakeRecord(k) => {
+                let names = &program.field_lists[k];
+                let len = stack.len();
+                let values = stack.split_off(len - names.len());
+                let mut fields: Vec<(String, Value)> = names.iter().cloned().zip(values).collect();
+                fields.sort_by(|a, b| a.0.cmp(&b.0));
+                stack.push(Value::Record(Rc::new(fields)));
+            },
+            Instr::Field(k) => match stack.pop().unwrap() {
+                Value::Record(ref fields) => {
+                    let name = &program.names[k];
+                    stack.push(fields.iter().find(|f| f.0 == *name).unwrap().1.clone());
+                },
+                v => panic!("field {} of {}", program.names[k], v),
+            },
+            Instr::MakeCell => stack.push(Value::Ref(Rc::new(RefCell::new(Value::Unit)))),
+            Instr::SetCell(slot) => {
+                let v = stack.pop().unwrap();
+                match stack[frame.base + slot] {
+                    Value::Ref(ref cell) => *cell.borrow_mut() = v,
+                    ref v => panic!("{} is not a cell", v),
+                }
+            },
+            Instr::Deref => match stack.pop().unwrap() {
+                Value::Ref(ref cell) => stack.push(cell.borrow().clone()),
+                v => panic!("cannot dereference {}", v),
+            },
+            Instr::Assign => {
+                let v = stack.pop().unwrap();
+                match stack.pop().unwrap() {
+                    Value::Ref(ref cell) => *cell.borrow_mut() = v,
+                    r => panic!("cannot assign to {}", r),
+                }
+                stack.push(Value::Unit);
+            },
+            Instr::Match(k, next) => {
+                let mut bindings = vec![];
+                if matches(&program.patterns[k], stack.last().unwrap(), &mut bindings) {
+                    stack.extend(bindings);
+                } else {
+                    frame.pc = next;
+                }
+            },
+            Instr::MatchFailure => return Err(Error::MatchFailure { span }),
+            Instr::PushHandler(pc) => handlers.push(Handler { frames: frames.len(), stack: stack.len(), pc }),
+            Instr::PopHandler => {
+                handlers.pop();
+            },
+            Instr::Raise | Instr::Reraise => {
+                let exn = stack.pop().unwrap();
+                if instr == Instr::Raise {
+                    raised_at = span;
+                }
+                let handler = match handlers.pop() {
+                    Some(handler) => handler,
+                    None => return Err(Error::Raised { exn, span: raised_at }),
+                };
+                while frames.len() > handler.frames {
+                    frame = frames.pop().unwrap();
+                }
+                stack.truncate(handler.stack);
+                stack.push(exn);
+                frame.pc = handler.pc;
+            },
+        }
+    }
+}
+
+/// Returns from the current call to its caller, or gives the result of the program if the
+/// program itself returned
+fn ret(stack: &mut Vec<Value<'static>>, frames: &mut Vec<Frame>, frame: &mut Frame) -> Option<Value<'static>> {
+    let result = stack.pop().unwrap();
+    match frames.pop() {
+        Some(caller) => {
+            stack.truncate(frame.base);
+            stack.push(result);
+            *frame = caller;
+            None
+        },
+        None => Some(result),
+    }
+}
+
+/// Whether `pat` matches `v`, collecting the values bound to its variables
+fn matches(pat: &Pat, v: &Value<'static>, bindings: &mut Vec<Value<'static>>) -> bool {
+    match (pat, v) {
+        (&Pat::Wild, _) => true,
+        (&Pat::Bind, _) => {
+            bindings.push(v.clone());
+            true
+        },
+        (&Pat::Int(n), &Value::Int(m)) => n == m,
+        (&Pat::Bool(b), &Value::Bool(c)) => b == c,
+        (Pat::Tuple(ps), Value::Tuple(vs)) => ps.iter().zip(vs.iter()).all(|(p, v)| matches(p, v, bindings)),
+        (Pat::Con(c, tag, ps), Value::Con(d, t, vs)) => {
+            **c == **d && tag == t && ps.iter().zip(vs.iter()).all(|(p, v)| matches(p, v, bindings))
+        },
+        _ => false,
+    }
+}
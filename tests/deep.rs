@@ -0,0 +1,75 @@
+extern crate type_inference;
+
+use type_inference::bytecode::compile;
+use type_inference::infer::{check, top_level_env, VarGenerator};
+use type_inference::{eval, parser, vm};
+
+/// How deeply the programs below nest
+const DEPTH: usize = 100_000;
+
+/// Type checks `src`, printing its type
+fn type_of(src: &str) -> String {
+    let expr = parser::parse_Expr(src).unwrap();
+    let mut var_gen = VarGenerator::new();
+    check(&expr, &top_level_env(&mut var_gen), &mut var_gen).unwrap().0.to_string()
+}
+
+/// Runs `src` on both backends, which must agree
+fn run(src: &str) -> String {
+    let expr = parser::parse_Expr(src).unwrap();
+    let value = eval::eval(&expr).unwrap().to_string();
+    assert_eq!(vm::run(&compile(&expr)).unwrap().to_string(), value);
+    value
+}
+
+#[test]
+fn long_operator_chains() {
+    let chain = vec!["1"; DEPTH].join(" + ");
+    assert_eq!(type_of(&chain), "int");
+    assert_eq!(run(&chain), DEPTH.to_string());
+}
+
+#[test]
+fn deeply_nested_expressions() {
+    let parens = "(".repeat(DEPTH) + "fun x -> x" + &")".repeat(DEPTH) + " true";
+    assert_eq!(type_of(&parens), "bool");
+    assert_eq!(run(&parens), "true");
+    let lets = "let x = 0 in ".to_owned() + &"let x = x + 1 in ".repeat(DEPTH) + "x";
+    assert_eq!(type_of(&lets), "int");
+    assert_eq!(run(&lets), DEPTH.to_string());
+    let ifs = "if true then ".repeat(DEPTH) + "1" + &" else 0".repeat(DEPTH);
+    assert_eq!(run(&ifs), "1");
+}
+
+#[test]
+fn deep_recursion_in_programs() {
+    let src = format!("letrec f = fun n -> if n = 0 then 0 else 1 + f (n - 1) in f {}", DEPTH);
+    assert_eq!(type_of(&src), "int");
+    assert_eq!(run(&src), DEPTH.to_string());
+}
+
+#[test]
+fn long_lists_in_programs() {
+    let list = format!("letrec f = fun n -> if n = 0 then nil else pair n (f (n - 1)) in let l = f {} in ", DEPTH);
+    assert_eq!(run(&(list.clone() + "is_nil l")), "false");
+    let length = "letrec len = fun acc -> fun l -> if is_nil l then acc else len (acc + 1) (rest l) in len 0 l";
+    assert_eq!(run(&(list.clone() + length)), DEPTH.to_string());
+    assert_eq!(run(&(list.clone() + "(l = l, l < pair 1 l, l >= rest l)")), "(true, false, true)");
+    let printed = run(&(list + "l"));
+    assert!(printed.starts_with("[100000, 99999, ") && printed.ends_with(", 2, 1]"), "{}", &printed[..100]);
+}
+
+#[test]
+fn deeply_nested_types() {
+    let curried = "let f = ".to_owned() + &"fun x -> ".repeat(DEPTH) + "1 in 0";
+    assert_eq!(type_of(&curried), "int");
+    let tuple = "(".repeat(DEPTH) + "1" + &", 1)".repeat(DEPTH);
+    let typ = type_of(&tuple);
+    assert!(typ.starts_with(&"(".repeat(DEPTH)) && typ.ends_with(" * int)"), "{}", &typ[..100]);
+    assert_eq!(type_of(&format!("let t = {} in 0", tuple)), "int");
+    assert_eq!(run(&tuple), "(".repeat(DEPTH) + "1" + &", 1)".repeat(DEPTH));
+    let expr = parser::parse_Expr(&tuple).unwrap();
+    assert_eq!(expr.clone(), expr);
+    assert_eq!(expr.to_string().len(), tuple.len());
+    assert!(format!("{:?}", expr).starts_with("Spanned { node: Tuple(["));
+}
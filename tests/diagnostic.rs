@@ -0,0 +1,60 @@
+extern crate type_inference;
+
+use type_inference::diagnostic::Diagnostic;
+use type_inference::infer::{check, top_level_env, VarGenerator};
+use type_inference::parser;
+
+/// Renders the parse or type error `src` fails with
+fn render(src: &str) -> String {
+    let diag = match parser::parse_Expr(src) {
+        Err(e) => Diagnostic::from_parse_error(src, &e),
+        Ok(expr) => {
+            let mut var_gen = VarGenerator::new();
+            let err = check(&expr, &top_level_env(&mut var_gen), &mut var_gen).unwrap_err();
+            Diagnostic::from_type_error(&err)
+        },
+    };
+    diag.render(src)
+}
+
+#[test]
+fn errors_are_rendered_under_the_offending_source() {
+    assert_eq!(render("if true then 1 else false"), "\
+error[E0001]: type mismatch
+ --> 1:21
+  |
+1 | if true then 1 else false
+  |                     ^^^^^ expected int, found bool
+  |              - this branch was inferred as int here
+");
+}
+
+#[test]
+fn multi_line_sources_show_the_line_of_each_label() {
+    assert_eq!(render("let x = 1 in\nlet y = true in\nx + y"), "\
+error[E0001]: type mismatch
+ --> 3:5
+  |
+3 | x + y
+  |     ^ expected int, found bool
+  | - this operand was inferred as int here
+");
+}
+
+#[test]
+fn non_ascii_source_is_measured_in_characters() {
+    assert_eq!(render("1 + é"), "\
+error: invalid token
+ --> 1:5
+  |
+1 | 1 + é
+  |     ^ not recognized
+");
+    assert_eq!(render("\"é\" ^ ü"), "\
+error: invalid token
+ --> 1:7
+  |
+1 | \"é\" ^ ü
+  |       ^ not recognized
+");
+}
@@ -0,0 +1,58 @@
+extern crate type_inference;
+
+use type_inference::eval::{eval, Error};
+use type_inference::infer::{check, top_level_env, VarGenerator};
+use type_inference::parser;
+
+/// Type checks and evaluates `src`, printing the value or the error it fails with
+fn run(src: &str) -> String {
+    let expr = parser::parse_Expr(src).unwrap();
+    let mut var_gen = VarGenerator::new();
+    check(&expr, &top_level_env(&mut var_gen), &mut var_gen).unwrap();
+    let result = eval(&expr);
+    match result {
+        Ok(v) => v.to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+#[test]
+fn recursive_functions_build_lists() {
+    let map = "letrec map = fun f -> fun l -> if is_nil l then nil else pair (f (first l)) (map f (rest l)) in ";
+    assert_eq!(run(&format!("{}map (fun x -> x * 2) (pair 1 (pair 2 nil))", map)), "[2, 4]");
+    assert_eq!(run("letrec even = fun n -> if n = 0 then true else odd (n - 1) \
+                    and odd = fun n -> if n = 0 then false else even (n - 1) in (even 10, odd 10)"), "(true, false)");
+    assert_eq!(run("let add = fun x -> fun y -> x + y in let inc = add 1 in (inc 2, add 1.5 2.0 < 1.0 || true)"), "(3, true)");
+}
+
+#[test]
+fn data_types_records_and_references() {
+    assert_eq!(run("type tree = Leaf | Node of tree * int * tree in \
+                    match Node Leaf (-1) (Node Leaf 2 Leaf) with | Leaf -> Leaf | Node l n r -> r"), "Node Leaf 2 Leaf");
+    assert_eq!(run("type t = A | B of int in (B 1 < B 2, A < B 0, A = A)"), "(true, true, true)");
+    assert_eq!(run("let nan = 0.0 / 0.0 in (nan = nan, nan != nan, (1, nan) = (1, nan), 0.0 = -0.0)"), "(false, true, false, true)");
+    assert_eq!(run("let nan = 0.0 / 0.0 in (nan < 1.0, nan > 1.0, nan <= 1.0, nan >= 1.0, nan <= nan)"), "(false, false, false, false, false)");
+    assert_eq!(run("let nan = 0.0 / 0.0 in ((1, nan) < (1, 2.0), (0, nan) < (1, 2.0), -0.0 >= 0.0)"), "(false, true, true)");
+    assert_eq!(run(r#"let r = { name = "x" ^ to_string 1, n = 2.5 } in (r.name, r)"#), r#"("x1", { n = 2.5, name = "x1" })"#);
+    assert_eq!(run("let c = ref 0 in c := !c + 1; c := !c * 10; !c"), "10");
+}
+
+#[test]
+fn exceptions_unwind_to_the_nearest_handler() {
+    let head = "exception Empty in let head = fun l -> if is_nil l then raise Empty else first l in ";
+    assert_eq!(run(&format!("{}try head nil with | Empty -> 0", head)), "0");
+    assert_eq!(run(&format!("{}head (pair 3 nil)", head)), "3");
+    assert_eq!(run(r#"exception Failure of string in exception Empty in
+                      try (try raise (Failure "x") with | Empty -> "") with | Failure s -> s ^ "!""#), r#""x!""#);
+    assert_eq!(run(r#"exception Failure of string in raise (Failure "boom")"#), r#"error: uncaught exception Failure "boom""#);
+    // A later exception of the same name is a different exception
+    assert_eq!(run("exception E of int in let x = E 1 in exception E of string in \
+                    try raise x with | E s -> length s"), "error: uncaught exception E 1");
+    assert_eq!(run("exception E in let x = E in exception E in try raise x with | E -> 0"), "error: uncaught exception E");
+    let expr = parser::parse_Expr("1 + 1 / 0").unwrap();
+    let result = eval(&expr);
+    match result {
+        Err(Error::DivisionByZero { span }) => assert_eq!(span, (4, 9)),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
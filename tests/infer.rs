@@ -0,0 +1,395 @@
+extern crate lalrpop_util;
+extern crate type_inference;
+
+use type_inference::ast::PrimitiveType;
+use type_inference::infer::*;
+use type_inference::{fixity, parser};
+use lalrpop_util::ParseError;
+
+fn infer(src: &str) -> Result<PrimitiveType> {
+    let expr = parser::parse_Expr(src).unwrap();
+    let mut var_gen = VarGenerator::new();
+    let env = top_level_env(&mut var_gen);
+    type_of(&expr, &env, &mut var_gen)
+}
+
+fn warnings(src: &str) -> Vec<Warning> {
+    let expr = parser::parse_Expr(src).unwrap();
+    let mut var_gen = VarGenerator::new();
+    let env = top_level_env(&mut var_gen);
+    check(&expr, &env, &mut var_gen).unwrap().1
+}
+
+fn no_instance(src: &str) -> (String, PrimitiveType) {
+    match infer(src).unwrap_err().primary().clone() {
+        Error::NoInstance { class, typ, .. } => (class, *typ),
+        other => panic!("unexpected error {}", other),
+    }
+}
+
+fn is_occurs_check(err: &Error) -> bool {
+    matches!(*err.primary(), Error::OccursCheck { .. })
+}
+
+#[test]
+fn self_application_is_an_infinite_type() {
+    let err = infer("fun x -> x x").unwrap_err();
+    assert!(is_occurs_check(&err), "{:?}", err);
+    assert_eq!(err.span(), (11, 12));
+    assert_eq!(err.code(), "E0002");
+}
+
+#[test]
+fn self_application_of_a_parameter_is_an_infinite_type() {
+    let err = infer("fun f -> fun y -> f f y").unwrap_err();
+    assert!(is_occurs_check(&err), "{:?}", err);
+}
+
+#[test]
+fn self_application_under_let_is_an_infinite_type() {
+    let err = infer("let omega = fun x -> x x in omega omega").unwrap_err();
+    assert!(is_occurs_check(&err), "{:?}", err);
+    assert_eq!(err.span(), (23, 24));
+}
+
+#[test]
+fn consing_a_value_onto_itself_is_an_infinite_type() {
+    match *infer("fun x -> pair x x").unwrap_err().primary() {
+        Error::OccursCheck { ref typ, .. } => match **typ {
+            PrimitiveType::Con(ref name, _) => assert_eq!(name, "List"),
+            ref t => panic!("unexpected type {}", t),
+        },
+        ref err => panic!("unexpected error {:?}", err),
+    }
+}
+
+#[test]
+fn occurs_check_message_names_the_infinite_type() {
+    let msg = format!("{}", infer("fun x -> x x").unwrap_err());
+    assert!(msg.contains("cannot construct infinite type"), "{}", msg);
+}
+
+#[test]
+fn self_application_of_a_polymorphic_function_is_fine() {
+    assert_eq!(infer("let id = fun x -> x in id id 1").unwrap(), int_type());
+}
+
+#[test]
+fn mismatched_list_elements_report_the_path_instead_of_panicking() {
+    match *infer("if true then pair 1 nil else pair true nil").unwrap_err().primary() {
+        Error::Mismatch { ref expected, ref found, ref path, .. } => {
+            assert_eq!((&**expected, &**found), (&int_type(), &bool_type()));
+            assert!(path.iter().any(|p| p == "the element type of List"), "{:?}", path);
+        },
+        ref err => panic!("unexpected error {:?}", err),
+    }
+}
+
+#[test]
+fn recursive_functions_still_type_check() {
+    assert_eq!(infer("letrec fact = fun x -> if x = 0 then 1 else (x * fact (x - 1)) in fact 5").unwrap(), int_type());
+    let map = "letrec map = fun f -> fun p -> if (is_nil p) then nil else pair (f (first p)) (map f (rest p)) in map";
+    match infer(map).unwrap() {
+        PrimitiveType::Fun(ref f, _) => match **f {
+            PrimitiveType::Fun(ref a, ref b) => assert_ne!(a, b),
+            ref t => panic!("unexpected type {}", t),
+        },
+        t => panic!("unexpected type {}", t),
+    }
+}
+
+#[test]
+fn recursive_binding_is_polymorphic_after_its_definition() {
+    assert_eq!(infer("letrec id = fun x -> x in if id true then id 1 else 0").unwrap(), int_type());
+}
+
+#[test]
+fn recursive_call_at_a_different_type_is_rejected() {
+    assert!(infer("letrec f = fun x -> if true then x else f true in f 1").is_err());
+    assert!(infer("letrec f = fun x -> (f 1) && (f true) in f").is_err());
+}
+
+#[test]
+fn recursive_function_returning_itself_is_an_infinite_type() {
+    let err = infer("letrec f = fun x -> f in f 1 2 3").unwrap_err();
+    assert!(is_occurs_check(&err), "{:?}", err);
+}
+
+#[test]
+fn let_does_not_generalize_variables_free_in_the_enviroment() {
+    assert!(infer("fun x -> let u = x 1 in if u then u + 1 else 0").is_err());
+}
+
+#[test]
+fn mutually_recursive_group_is_solved_together() {
+    let src = "letrec even = fun n -> if n = 0 then true else odd (n - 1) \
+               and odd = fun n -> if n = 0 then false else even (n - 1) in even 10";
+    assert_eq!(infer(src).unwrap(), bool_type());
+    assert!(infer("letrec f = fun x -> g x && true and g = fun y -> f y + 1 in f").is_err());
+}
+
+#[test]
+fn letrec_only_defines_functions() {
+    let err = infer("letrec x = x + 1 in x").unwrap_err();
+    assert_eq!((err.code(), err.to_string()), ("E0012", "[E0012] letrec can only define functions, but x is not one (at 11..16)".to_owned()));
+    assert!(infer("letrec l = pair 1 l in first l").is_err());
+    assert!(infer("letrec f = fun n -> n and v = f 1 in v").is_err());
+    assert_eq!(infer("letrec f = (fun n -> if n = 0 then 0 else f (n - 1) : int -> int) in f 3").unwrap(), int_type());
+}
+
+#[test]
+fn tuples_destructure_and_project() {
+    assert_eq!(infer("let (f, n) = (fun x -> x, 2) in (f true, f n)").unwrap(),
+               tuple_type(vec![bool_type(), int_type()]));
+    assert_eq!(infer("fst (1, true) + snd (true, 2)").unwrap(), int_type());
+    assert!(infer("snd (1, 2, 3)").is_err());
+}
+
+#[test]
+fn declared_types_register_their_constructors() {
+    // Declared types are told apart by a stamp, but printed by name
+    assert_eq!(infer("type option 'a = None | Some of 'a in if true then None else Some 1").unwrap().to_string(),
+               "option<int>");
+    let tree = "type tree 'a = Leaf | Node of tree<'a> * 'a * tree<'a> in Node Leaf true Leaf";
+    assert_eq!(infer(tree).unwrap().to_string(), "tree<bool>");
+    assert!(infer("type t = A of undefined in A").is_err());
+    let err = infer("type t = A | A of int in A").unwrap_err();
+    assert_eq!((err.code(), err.to_string()), ("E0011", "[E0011] constructor A is given more than once (at 0..21)".to_owned()));
+}
+
+#[test]
+fn redeclared_types_are_distinct_from_the_types_they_shadow() {
+    let err = infer("type t = A of int in let x = A 1 in type t = A of string in match x with | A s -> length s").unwrap_err();
+    assert_eq!(err.to_string(), "[E0001] Type error: expected t, found a different type also written t (at 75..78)");
+    assert_eq!(err.labels(), vec![((36, 56), "the found t is declared here".to_owned()), ((0, 17), "the expected t is declared here".to_owned())]);
+    assert!(infer("let x = (type t = A in A) in type t = A in x = A").is_err());
+    assert_eq!(infer("type t = A in type u = B of t in type t = C of u in C (B A)").unwrap().to_string(), "t");
+    assert_eq!(infer("let x = 5 in type t = x in (x, to_string x)").unwrap().to_string(), "(t * string)");
+}
+
+#[test]
+fn match_warns_about_missing_and_unreachable_arms() {
+    let option = "type option 'a = None | Some of 'a in ";
+    assert_eq!(infer(&format!("{}fun o -> match o with | Some x -> x | None -> 0", option)).unwrap().to_string(),
+               "(option<int> -> int)");
+    assert!(warnings(&format!("{}match Some (1, true) with | Some (n, _) -> n | None -> 0", option)).is_empty());
+    match warnings(&format!("{}match Some true with | Some true -> 1 | None -> 0", option))[..] {
+        [Warning::NonExhaustive { ref missing, .. }] => assert_eq!(missing, "Some false"),
+        ref other => panic!("unexpected warnings {:?}", other),
+    }
+    match warnings("match pair 1 nil with | pair x r -> x | _ -> 0 | nil -> 1")[..] {
+        [Warning::Redundant { span }] => assert_eq!(span, (49, 52)),
+        ref other => panic!("unexpected warnings {:?}", other),
+    }
+    assert!(infer("match 1 with | true -> 1 | _ -> 0").is_err());
+}
+
+#[test]
+fn patterns_follow_the_shadowing_of_constructors() {
+    match infer("let pair = 5 in match nil with | pair a b -> 1 | _ -> 0") {
+        Err(Error::UndefinedName { ref name, .. }) => assert_eq!(name, "pair"),
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(infer("let nil = 5 in match 3 with | nil -> 1").unwrap(), int_type());
+    assert!(warnings("let nil = 5 in match 3 with | nil -> 1").is_empty());
+}
+
+#[test]
+fn records_are_row_polymorphic() {
+    let get = infer("fun r -> r.name + 1").unwrap();
+    match get {
+        PrimitiveType::Fun(ref arg, ref ret) => {
+            assert_eq!(**ret, int_type());
+            match **arg {
+                PrimitiveType::Row(ref fields, Some(_)) => assert_eq!(*fields, vec![("name".to_owned(), int_type())]),
+                ref other => panic!("expected an open record, found {}", other),
+            }
+        },
+        ref other => panic!("expected a function, found {}", other),
+    }
+    assert_eq!(infer("let get = fun r -> r.x in (get {x = 1}, get {y = 2, x = true})").unwrap(),
+               tuple_type(vec![int_type(), bool_type()]));
+    assert_eq!(infer("{ ok = true, name = 1 }").unwrap(),
+               record_type(vec![("name".to_owned(), int_type()), ("ok".to_owned(), bool_type())], None));
+    match *infer("(fun r -> r.name) { ok = true }").unwrap_err().primary() {
+        Error::MissingField { ref field, .. } => assert_eq!(field, "name"),
+        ref other => panic!("unexpected error {}", other),
+    }
+    assert!(infer("fun r -> if true then r else { a = 1 }.b").is_err());
+}
+
+#[test]
+fn annotations_are_checked_against_the_inferred_type() {
+    assert_eq!(infer("(fun x -> x + 1 : int -> int)").unwrap(),
+               PrimitiveType::Fun(Box::new(int_type()), Box::new(int_type())));
+    assert_eq!(infer("fun (l : List<bool>) -> first l").unwrap(),
+               PrimitiveType::Fun(Box::new(list_type(bool_type())), Box::new(bool_type())));
+    assert_eq!(infer("let id = (fun x -> x : 'a -> 'a) in (id 1, id true)").unwrap(),
+               tuple_type(vec![int_type(), bool_type()]));
+    assert!(infer("(true : int)").is_err());
+    for src in &["(fun x -> x + 1 : 'a -> 'a)", "(fun x -> x : 'a -> 'b)", "fun (x : 'a) -> x && true"] {
+        match *infer(src).unwrap_err().primary() {
+            Error::AnnotationTooGeneral { .. } => {},
+            ref other => panic!("unexpected error {} for {}", other, src),
+        }
+    }
+}
+
+#[test]
+fn aliases_expand_but_keep_their_name() {
+    assert_eq!(parse_type("int * bool -> List<int>").unwrap(),
+               PrimitiveType::Fun(Box::new(tuple_type(vec![int_type(), bool_type()])), Box::new(list_type(int_type()))));
+    assert_eq!(parse_type("'a -> 'b -> 'a").unwrap().to_string(), "('1 -> ('2 -> '1))");
+    assert!(parse_type("int ->").is_err());
+    let pairlist = "type pairlist 'a = List<List<'a>> in ";
+    assert_eq!(infer(&format!("{}(nil : pairlist<int>)", pairlist)).unwrap().to_string(), "pairlist<int>");
+    assert_eq!(*infer(&format!("{}first (nil : pairlist<int>)", pairlist)).unwrap().unalias(), parse_type("List<int>").unwrap());
+    assert!(infer(&format!("{}(pair 1 nil : pairlist<int>)", pairlist)).is_err());
+    assert_eq!(infer("type fn = int -> int in (fun x -> x : fn) 3").unwrap(), int_type());
+    assert!(infer("type r = r in (1 : r)").is_err());
+    assert_eq!(*infer("type num = int in (1 : num) + 1").unwrap().unalias(), int_type());
+    let mut var_gen = VarGenerator::new();
+    let env = top_level_env(&mut var_gen);
+    assert!(BUILTIN_TYPES.iter().all(|t| env.lookup_type(t).is_some()));
+}
+
+#[test]
+fn operators_are_overloaded_through_type_classes() {
+    let expr = parser::parse_Expr("let add = fun x -> fun y -> x + y in add").unwrap();
+    let mut var_gen = VarGenerator::new();
+    let env = top_level_env(&mut var_gen);
+    let (q, _) = check(&expr, &env, &mut var_gen).unwrap();
+    assert_eq!(q.preds.len(), 1);
+    assert_eq!(q.preds[0].class, "Num");
+    assert_eq!(q.typ, PrimitiveType::Fun(Box::new(q.preds[0].typ.clone()),
+                                         Box::new(PrimitiveType::Fun(Box::new(q.preds[0].typ.clone()), Box::new(q.preds[0].typ.clone())))));
+    assert_eq!(infer("let add = fun x -> fun y -> x + y in add 1 2").unwrap(), int_type());
+    assert_eq!(infer("pair 1 nil = nil && (1, true) < (2, false)").unwrap(), bool_type());
+    assert_eq!(no_instance("let add = fun x -> fun y -> x + y in add true false"), ("Num".to_owned(), bool_type()));
+    assert!(no_instance("(fun x -> x) = (fun x -> x)").1.to_string().contains("->"));
+    assert_eq!(no_instance("pair (fun x -> x + 1) nil < nil").1, parse_type("int -> int").unwrap());
+    assert_eq!(infer("type option 'a = None | Some of 'a in Some 1 = None").unwrap(), bool_type());
+    assert_eq!(infer("type t = A in A = A").unwrap(), bool_type());
+}
+
+#[test]
+fn floats_strings_and_chars_have_their_own_types() {
+    assert_eq!(infer("1.5 * 2.0").unwrap(), float_type());
+    assert!(infer("1.5 + 2").is_err());
+    assert_eq!(infer(r#"length ("a\"b" ^ to_string 12)"#).unwrap(), int_type());
+    assert_eq!(infer(r"pair '\n' (pair 'x' nil)").unwrap(), list_type(char_type()));
+    assert!(infer(r#""a" + "b""#).is_err());
+    assert_eq!(infer(r#""abc" < "abd""#).unwrap(), bool_type());
+}
+
+#[test]
+fn sequencing_warns_about_discarded_values() {
+    assert_eq!(infer("(); 1").unwrap(), int_type());
+    assert_eq!(infer("let x = 1 in (); x + 1").unwrap(), int_type());
+    assert!(warnings("(); ()").is_empty());
+    match warnings("1 + 1; ()")[..] {
+        [Warning::DiscardedValue { ref typ, span }] => {
+            assert_eq!(**typ, int_type());
+            assert_eq!(span, (0, 5));
+        },
+        ref other => panic!("unexpected warnings {:?}", other),
+    }
+}
+
+#[test]
+fn only_values_are_generalized() {
+    assert!(infer("let r = ref nil in r := pair 1 nil; first !r && true").is_err());
+    assert_eq!(infer("let r = ref nil in r := pair 1 nil; first !r + 1").unwrap(), int_type());
+    assert_eq!(infer("let c = ref 0 in c := !c + 1; !c").unwrap(), int_type());
+    assert!(infer("ref 1 := true").is_err());
+    assert!(infer("let f = (fun x -> x) (fun x -> x) in (f 1, f true)").is_err());
+    assert_eq!(no_instance(r#"let (x, y) = ((fun f -> f) (fun z -> z + z), 1) in x "a""#), ("Num".to_owned(), string_type()));
+    assert_eq!(infer("let id = fun x -> x in (id 1, id true)").unwrap(), tuple_type(vec![int_type(), bool_type()]));
+    assert_eq!(infer("type option 'a = None | Some of 'a in let o = Some nil in (o = Some (pair 1 nil), o = Some (pair true nil))").unwrap(),
+               tuple_type(vec![bool_type(), bool_type()]));
+    // A constructor's name that a `let` has shadowed no longer builds values
+    assert!(infer("let cons = pair in let pair = fun x -> ref x in let r = pair nil in r := cons 1 nil; if first !r then 1 else 2").is_err());
+}
+
+#[test]
+fn exceptions_are_raised_and_handled() {
+    let head = "exception Empty in let head = fun l -> if is_nil l then raise Empty else first l in ";
+    assert_eq!(infer(&format!("{}try head nil + 1 with | Empty -> 0", head)).unwrap(), int_type());
+    assert!(infer(&format!("{}try head nil + 1 with | Empty -> true", head)).is_err());
+    assert_eq!(infer(r#"exception Failure of string in try raise (Failure "x") with Failure msg -> length msg"#).unwrap(), int_type());
+    assert!(infer("exception Empty in raise 1").is_err());
+    assert!(infer("exception Bad of 'a in 1").is_err());
+    assert!(warnings("exception Empty in try 1 with | Empty -> 0").is_empty());
+    assert!(matches!(warnings("exception Empty in match raise Empty with | Empty -> 0")[..], [Warning::NonExhaustive { .. }]));
+}
+
+#[test]
+fn unary_and_extra_binary_operators() {
+    assert_eq!(infer("-1 + 7 mod -2").unwrap(), int_type());
+    assert_eq!(infer("-(1.5 * 2.0)").unwrap(), float_type());
+    assert_eq!(infer("not (1 <= 2) || 3 >= 4 && true != false").unwrap(), bool_type());
+    assert_eq!(infer("match 3 with | -1 -> true | _ -> false").unwrap(), bool_type());
+    assert!(infer("not 1").is_err());
+    assert_eq!(no_instance("-true"), ("Num".to_owned(), bool_type()));
+    let expr = parser::parse_Expr("-x * 2 - -1").unwrap();
+    assert_eq!(expr.to_string(), "(((-x) * 2) - -1)");
+    assert_eq!(parser::parse_Expr("-2147483648 / - -1").unwrap().to_string(), "(-2147483648 / 1)");
+    assert_eq!(infer("match 1 with | -2147483648 -> 0 | n -> n").unwrap(), int_type());
+    for src in &["2147483648", "1 + 99999999999", "-2147483649", "match 0 with | -2147483649 -> 0"] {
+        match parser::parse_Expr(src) {
+            Err(ParseError::User { error: fixity::Error::IntOutOfRange { .. } }) => {},
+            other => panic!("unexpected result {:?} for {}", other, src),
+        }
+    }
+}
+
+#[test]
+fn user_defined_operators_follow_their_fixity() {
+    let expr = parser::parse_Expr("infixl 6 ++ in infixr 5 +> in a ++ b * c ++ d +> e +> f").unwrap();
+    assert_eq!(expr.to_string(), "(infixl 6 ++ in (infixr 5 +> in (((+>) (((++) (((++) a) (b * c))) d)) (((+>) e) f))))");
+    assert_eq!(parser::parse_Expr("a ** b + c").unwrap().to_string(), "((((**) a) b) + c)");
+    assert!(parser::parse_Expr("infix 4 == in a == b == c").is_err());
+    assert!(parser::parse_Expr("infixl 5 <+ in infixr 5 +> in a <+ b +> c").is_err());
+    assert!(parser::parse_Expr("infixl 10 ++ in a").is_err());
+    assert_eq!(infer("infixr 5 +> in let (+>) = fun x -> fun l -> pair x l in 1 +> 2 +> nil").unwrap(), list_type(int_type()));
+    assert_eq!(infer("let (|>) = fun x -> fun f -> f x in (|>) 1 (fun x -> x < 2)").unwrap(), bool_type());
+    assert!(infer("1 ** 2").is_err());
+}
+
+#[test]
+fn type_errors_carry_the_span_of_the_offending_subexpression() {
+    let expr = parser::parse_Expr("let x = 1 in x + 2").unwrap();
+    assert_eq!(expr.span, (0, 18));
+    assert_eq!(infer("if true then 1 else false").unwrap_err().span(), (20, 25));
+    assert_eq!(infer("let f = fun x -> x + 1 in f true").unwrap_err().span(), (28, 32));
+    assert_eq!(infer("(fun x -> y) 1").unwrap_err().span(), (10, 11));
+}
+
+#[test]
+fn errors_are_structured_and_carry_stable_codes() {
+    match infer("if true then 1 else false").unwrap_err().primary().clone() {
+        err @ Error::Mismatch { .. } => {
+            assert_eq!(err.code(), "E0001");
+            assert_eq!(err, Error::Mismatch { expected: Box::new(int_type()), found: Box::new(bool_type()), path: vec![], span: (20, 25) });
+        },
+        other => panic!("unexpected error {}", other),
+    }
+    match infer("let lengths = 1 in lenghts").unwrap_err() {
+        err @ Error::UndefinedName { .. } => {
+            assert_eq!(err.code(), "E0004");
+            assert_eq!(err.to_string(), "[E0004] lenghts is undefined, did you mean length? (at 19..26)");
+            if let Error::UndefinedName { suggestions, .. } = err {
+                assert_eq!(suggestions, vec!["length".to_owned(), "lengths".to_owned()]);
+            }
+        },
+        other => panic!("unexpected error {}", other),
+    }
+    match infer("let x = 1 in x true").unwrap_err() {
+        err @ Error::NotAFunction { .. } => {
+            assert_eq!(err.code(), "E0005");
+            assert_eq!(err.span(), (13, 14));
+        },
+        other => panic!("unexpected error {}", other),
+    }
+    assert_eq!(infer("zzz").unwrap_err(), Error::UndefinedName { name: "zzz".to_owned(), suggestions: vec![], span: (0, 3) });
+}
@@ -0,0 +1,74 @@
+extern crate type_inference;
+
+use type_inference::bytecode::compile;
+use type_inference::eval::{self, Error};
+use type_inference::infer::{check, top_level_env, VarGenerator};
+use type_inference::{parser, vm};
+
+/// Type checks `src` and runs it on both backends, which must agree, printing the value or
+/// the error it fails with
+fn run(src: &str) -> String {
+    let expr = parser::parse_Expr(src).unwrap();
+    let mut var_gen = VarGenerator::new();
+    check(&expr, &top_level_env(&mut var_gen), &mut var_gen).unwrap();
+    let show = |result: eval::Result| match result {
+        Ok(v) => v.to_string(),
+        Err(e) => format!("error: {} at {:?}", e, e.span()),
+    };
+    let interpreted = show(eval::eval(&expr));
+    let compiled = show(vm::run(&compile(&expr)));
+    assert_eq!(interpreted, compiled, "the backends disagree on {}", src);
+    compiled
+}
+
+#[test]
+fn closures_capture_their_enviroment() {
+    let map = "letrec map = fun f -> fun l -> if is_nil l then nil else pair (f (first l)) (map f (rest l)) in ";
+    assert_eq!(run(&format!("{}let k = 3 in map (fun x -> x * k) (pair 1 (pair 2 nil))", map)), "[3, 6]");
+    assert_eq!(run("letrec even = fun n -> if n = 0 then true else odd (n - 1) \
+                    and odd = fun n -> if n = 0 then false else even (n - 1) in (even 10, odd 7)"), "(true, true)");
+    assert_eq!(run("let add = fun x -> fun y -> fun z -> x + y * z in let (a, b) = (add 1, 2) in (a b 3, a 0 0 = 1 && false)"), "(7, false)");
+    assert_eq!(run("let (<+>) = fun a -> fun b -> (a, b) in infixr 5 <+> in 1 <+> 2 <+> 3"), "(1, (2, 3))");
+}
+
+#[test]
+fn data_and_control_flow_match_the_interpreter() {
+    assert_eq!(run("type tree = Leaf | Node of tree * int * tree in \
+                    letrec sum = fun t -> match t with | Leaf -> 0 | Node l n r -> sum l + n + sum r in \
+                    sum (Node (Node Leaf 1 Leaf) (-4) (Node Leaf 2 Leaf))"), "-1");
+    assert_eq!(run("match (1, (true, 2)) with | (0, _) -> 0 | (n, (false, m)) -> m | (n, (true, m)) -> n + m"), "3");
+    assert_eq!(run(r#"let r = { name = "x" ^ to_string 1, n = 2.5 } in (r.name, r)"#), r#"("x1", { n = 2.5, name = "x1" })"#);
+    assert_eq!(run("let nan = 0.0 / 0.0 in (nan = nan, nan <= 1.0, nan >= 1.0, (0, nan) < (1, 2.0))"), "(false, false, false, true)");
+    assert_eq!(run("let c = ref 0 in letrec loop = fun n -> if n > 0 then (c := !c + n; loop (n - 1)) else !c in loop 4"), "10");
+    assert_eq!(run("match 3 with | 1 -> true | 2 -> false"), "error: no match arm matches the value at (0, 37)");
+    // An alias to a bare name declares a constructor only when the name is not a type
+    assert_eq!(run("type t = A in type u = t in (match A with | A -> 1, (A : u))"), "(1, A)");
+    assert_eq!(run("let x = 5 in type t = x in (x, to_string x)"), r#"(x, "x")"#);
+    // The parser makes that decision, so the backends need no help from inference
+    let expr = parser::parse_Expr("let x = 5 in type t = x in x").unwrap();
+    assert_eq!(eval::eval(&expr).unwrap().to_string(), "x");
+    assert_eq!(vm::run(&compile(&expr)).unwrap().to_string(), "x");
+}
+
+#[test]
+fn exceptions_unwind_through_calls() {
+    let head = "exception Empty in let head = fun l -> if is_nil l then raise Empty else first l in ";
+    assert_eq!(run(&format!("{}let f = fun l -> head l + 1 in try f nil with | Empty -> 0", head)), "0");
+    assert_eq!(run(r#"exception Failure of string in exception Empty in
+                      (try (try raise (Failure "x") with | Empty -> "") with | Failure s -> s ^ "!", 1)"#), r#"("x!", 1)"#);
+    assert_eq!(run("exception Failure of int in try raise (Failure 2) with | Failure 1 -> 1"),
+               "error: uncaught exception Failure 2 at (32, 49)");
+    assert_eq!(run("exception E of int in let x = E 1 in exception E of string in \
+                    let f = fun u -> try raise x with | E s -> length s in f ()"), "error: uncaught exception E 1 at (83, 90)");
+    let expr = parser::parse_Expr("1 + 1 / 0").unwrap();
+    match vm::run(&compile(&expr)) {
+        Err(Error::DivisionByZero { span }) => assert_eq!(span, (4, 9)),
+        other => panic!("unexpected result {:?}", other),
+    }
+}
+
+#[test]
+fn tail_calls_run_in_constant_stack() {
+    let expr = parser::parse_Expr("letrec count = fun n -> fun acc -> if n = 0 then acc else count (n - 1) (acc + 1) in count 100000 0").unwrap();
+    assert_eq!(vm::run(&compile(&expr)).unwrap().to_string(), "100000");
+}